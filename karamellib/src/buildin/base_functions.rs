@@ -3,7 +3,9 @@ use crate::types::VmObject;
 use crate::buildin::{Module, Class};
 use crate::compiler::GetType;
 use crate::error::KaramelErrorType;
-use crate::{n_parameter_expected};
+use crate::buildin::class::range::RangeClass;
+use crate::compiler::KaramelPrimative;
+use crate::{n_parameter_expected, expected_parameter_type};
 use std::{cell::RefCell, collections::HashMap};
 use std::rc::Rc;
 
@@ -58,6 +60,7 @@ impl BaseFunctionsModule  {
 
         let rc_module = Rc::new(module);
         rc_module.methods.borrow_mut().insert("tür_bilgisi".to_string(), FunctionReference::native_function(Self::type_info as NativeCall, "tür_bilgisi".to_string(), rc_module.clone()));
+        rc_module.methods.borrow_mut().insert("aralık".to_string(), FunctionReference::native_function(Self::range as NativeCall, "aralık".to_string(), rc_module.clone()));
         rc_module
     }
 
@@ -71,4 +74,32 @@ impl BaseFunctionsModule  {
             None => Ok(EMPTY_OBJECT)
         }
     }
+
+    pub fn range(parameter: FunctionParameter) -> NativeCallResult {
+        if parameter.length() == 0 || parameter.length() > 3 {
+            return n_parameter_expected!("aralık".to_string(), 3, parameter.length());
+        }
+
+        let mut values = Vec::new();
+        for arg in parameter.iter() {
+            match &*arg.deref() {
+                KaramelPrimative::Number(number) => values.push(*number),
+                _ => return expected_parameter_type!("aralık".to_string(), "Sayı".to_string())
+            };
+        }
+
+        /* Single parameter is the end of range */
+        let (start, end, step) = match values[..] {
+            [end] => (0.0, end, 1.0),
+            [start, end] => (start, end, 1.0),
+            [start, end, step] => (start, end, step),
+            _ => return n_parameter_expected!("aralık".to_string(), 3, parameter.length())
+        };
+
+        if step == 0.0 {
+            return Err(KaramelErrorType::RangeStepCannotBeZero);
+        }
+
+        Ok(VmObject::native_convert(KaramelPrimative::Class(Rc::new(RangeClass::new(start, end, step)))))
+    }
 }
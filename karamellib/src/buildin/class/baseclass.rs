@@ -1,4 +1,4 @@
-use crate::{buildin::{Class, ClassProperty}, compiler::function::{IndexerGetCall, IndexerSetCall, NativeCall, FunctionFlag}, types::VmObject};
+use crate::{buildin::{Class, ClassProperty}, compiler::function::{IndexerGetCall, IndexerSetCall, IndexerSliceCall, NativeCall, FunctionFlag}, types::VmObject};
 use crate::compiler::{KaramelPrimative, function::{FunctionReference}};
 
 use std::{rc::Rc};
@@ -67,6 +67,14 @@ pub struct BasicInnerClass {
             None => None
         }
     }
+
+    fn set_slicer(&mut self, indexer: IndexerSliceCall) {
+        self.config.indexer.slice = Some(indexer);
+    }
+
+    fn get_slicer(&self) -> Option<IndexerSliceCall> {
+        self.config.indexer.slice
+    }
  }
 
 impl BasicInnerClass {
@@ -83,6 +91,10 @@ impl BasicInnerClass {
     pub fn add_class_method(&mut self, name: &str, function: NativeCall) {
         self.add_method(name, function, FunctionFlag::IN_CLASS);
     }
+
+    pub fn add_class_function(&mut self, name: &str, function: Rc<FunctionReference>) {
+        self.config.properties.insert(name.to_string(), ClassProperty::Function(function));
+    }
 }
 
 impl GetType for BasicInnerClass {
@@ -5,9 +5,10 @@ use crate::{buildin::{Class, ClassConfig, ClassProperty}, compiler::{GetType, fu
 use crate::compiler::value::EMPTY_OBJECT;
 use crate::buildin::class::baseclass::BasicInnerClass;
 use crate::compiler::value::KaramelPrimative;
+use crate::compiler::hash::HashKey;
 use crate::error::KaramelErrorType;
 use crate::types::VmObject;
-use crate::{n_parameter_expected, expected_parameter_type, arc_bool, primative_list};
+use crate::{n_parameter_expected, arc_bool, primative_list};
 
 use crate::buildin::class::PRIMATIVE_CLASS_NAMES;
 
@@ -65,7 +66,7 @@ impl DictClass {
             None => match source {
                 Some(object) => {
                     match &*object.deref() {
-                        KaramelPrimative::Dict(dict) => match dict.borrow().get(&*field.clone()) {
+                        KaramelPrimative::Dict(dict) => match dict.borrow().get(&HashKey::Text(field.clone())) {
                             Some(data) => Some(ClassProperty::Field(data.deref())),
                             None => None
                         },
@@ -116,12 +117,8 @@ fn get(parameter: FunctionParameter) -> NativeCallResult {
         return match parameter.length() {
             0 =>  n_parameter_expected!("getir".to_string(), 1),
             1 => {
-                let key = match &*parameter.iter().next().unwrap().deref() {
-                    KaramelPrimative::Text(yazi) => yazi.clone(),
-                    _ => return expected_parameter_type!("anahtar".to_string(), "Yazı".to_string())
-                };
-                
-                return match dict.borrow().get(&*key) {
+                let key = get_key(*parameter.iter().next().unwrap())?;
+                return match dict.borrow().get(&key) {
                     Some(item) => Ok(*item),
                     _ => Ok(EMPTY_OBJECT)
                 };
@@ -132,6 +129,15 @@ fn get(parameter: FunctionParameter) -> NativeCallResult {
     Ok(EMPTY_OBJECT)
 }
 
+/* Lists and dicts could change after added, they could not be used as key */
+fn get_key(key: VmObject) -> Result<HashKey, KaramelErrorType> {
+    let key = key.deref();
+    match HashKey::from_primative(&key) {
+        Some(key) => Ok(key),
+        None => Err(KaramelErrorType::ValueNotHashable(key.clone()))
+    }
+}
+
 fn set(parameter: FunctionParameter) -> NativeCallResult {
     insert_or_update(parameter, "güncelle")
 }
@@ -146,13 +152,8 @@ fn insert_or_update(parameter: FunctionParameter, function_name: &str) -> Native
             0 =>  n_parameter_expected!(function_name.to_string(), 2),
             2 => {
                 let mut iter = parameter.iter();
-                let (position_object, item) = (&*iter.next().unwrap().deref(), &*iter.next().unwrap());
-
-                let position = match position_object {
-                    KaramelPrimative::Text(text) => text.clone(),
-                    _ => return expected_parameter_type!("anahtar".to_string(), "Yazı".to_string())
-                };
-                *dict.borrow_mut().entry((&position).to_string()).or_insert(*item) = *item;
+                let (key, item) = (get_key(*iter.next().unwrap())?, *iter.next().unwrap());
+                dict.borrow_mut().insert(key, item);
                 Ok(EMPTY_OBJECT)
             },
             _ => n_parameter_expected!(function_name.to_string(), 2, parameter.length())
@@ -181,12 +182,8 @@ fn remove(parameter: FunctionParameter) -> NativeCallResult {
         return match parameter.length() {
             0 => n_parameter_expected!("sil".to_string(), 1),
             1 => {
-                let key = match &*parameter.iter().next().unwrap().deref() {
-                    KaramelPrimative::Text(text) => text.clone(),
-                    _ => return expected_parameter_type!("anahtar".to_string(), "Yazı".to_string())
-                };
-                
-                Ok(match dict.borrow_mut().remove(&key.to_string()) {
+                let key = get_key(*parameter.iter().next().unwrap())?;
+                Ok(match dict.borrow_mut().remove(&key) {
                     Some(_) => arc_bool!(true),
                     None => arc_bool!(false)
                 })
@@ -199,11 +196,7 @@ fn remove(parameter: FunctionParameter) -> NativeCallResult {
 
 fn keys(parameter: FunctionParameter) -> NativeCallResult {
     if let KaramelPrimative::Dict(dict) = &*parameter.source().unwrap().deref() {
-        let mut keys = Vec::new();
-        for key in dict.borrow().keys() {
-            keys.push(VmObject::native_convert(KaramelPrimative::Text(Rc::new(key.to_string()))));
-        }
-
+        let keys = dict.borrow().keys().map(|key| key.to_object()).collect();
         return Ok(VmObject::native_convert(primative_list!(keys)));
     }
 
@@ -215,9 +208,9 @@ fn contains(parameter: FunctionParameter) -> NativeCallResult {
         return match parameter.length() {
             0 =>  n_parameter_expected!("içeriyormu".to_string(), 1),
             1 => {
-                match &*parameter.iter().next().unwrap().deref() {
-                    KaramelPrimative::Text(search) =>  Ok(VmObject::from(dict.borrow().contains_key(&**search))),
-                    _ => expected_parameter_type!("içeriyormu".to_string(), "Yazı".to_string())
+                match HashKey::from_primative(&parameter.iter().next().unwrap().deref()) {
+                    Some(key) => Ok(arc_bool!(dict.borrow().contains_key(&key))),
+                    None => Ok(arc_bool!(false))
                 }
             },
             _ => n_parameter_expected!("içeriyormu".to_string(), 1, parameter.length())
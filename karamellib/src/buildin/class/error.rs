@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use strum::EnumMessage;
+
+use crate::buildin::{Class, ClassConfig, ClassProperty};
+use crate::compiler::function::{FunctionFlag, IndexerGetCall, IndexerSetCall, NativeCall};
+use crate::compiler::{GetType, KaramelPrimative};
+use crate::error::KaramelErrorType;
+use crate::types::VmObject;
+
+pub const ERROR_CLASS_NAME: &str = "hata";
+
+/* Catched error at 'yakala' block. Keeps error code, message and thrown value */
+pub struct ErrorClass {
+    message: String,
+    properties: HashMap<String, ClassProperty>
+}
+
+impl ErrorClass {
+    pub fn new(code: f64, message: String, value: Rc<KaramelPrimative>) -> ErrorClass {
+        let mut properties = HashMap::new();
+        properties.insert("kod".to_string(), ClassProperty::Field(Rc::new(KaramelPrimative::Number(code))));
+        properties.insert("mesaj".to_string(), ClassProperty::Field(Rc::new(KaramelPrimative::Text(Rc::new(message.to_string())))));
+        properties.insert("değer".to_string(), ClassProperty::Field(value));
+
+        ErrorClass { message, properties }
+    }
+
+    /// Error object that pushed to 'yakala' block. Thrown error objects are passed without change.
+    pub fn create_object(error: KaramelErrorType) -> VmObject {
+        let code = error.get_message().and_then(|code| code.parse::<f64>().ok()).unwrap_or_default();
+        let (message, value) = match &error {
+            KaramelErrorType::ThrownError(value) => match &**value {
+                KaramelPrimative::Class(class) if class.get_class_name() == ERROR_CLASS_NAME => return VmObject::from(value.clone()),
+                KaramelPrimative::Text(text) => (text.to_string(), value.clone()),
+                _ => (value.to_string(), value.clone())
+            },
+            _ => (error.to_string(), Rc::new(KaramelPrimative::Empty))
+        };
+
+        VmObject::from(Rc::new(KaramelPrimative::Class(Rc::new(ErrorClass::new(code, message, value)))))
+    }
+}
+
+impl Class for ErrorClass {
+    fn set_class_config(&mut self, _: ClassConfig) {}
+
+    fn get_class_name(&self) -> String {
+        ERROR_CLASS_NAME.to_string()
+    }
+
+    fn has_element(&self, _: Option<VmObject>, field: Rc<String>) -> bool {
+        self.properties.contains_key(&*field)
+    }
+
+    fn get_element(&self, _: Option<VmObject>, field: Rc<String>) -> Option<ClassProperty> {
+        self.properties.get(&*field).cloned()
+    }
+
+    fn property_count(&self) -> usize {
+        self.properties.len()
+    }
+
+    fn properties(&self) -> std::collections::hash_map::Iter<'_, String, ClassProperty> {
+        self.properties.iter()
+    }
+
+    fn add_method(&mut self, _: &str, _: NativeCall, _: FunctionFlag) {}
+
+    fn add_property(&mut self, _: &str, _: Rc<KaramelPrimative>) {}
+
+    fn set_getter(&mut self, _: IndexerGetCall) {}
+
+    fn get_getter(&self) -> Option<IndexerGetCall> {
+        None
+    }
+
+    fn set_setter(&mut self, _: IndexerSetCall) {}
+
+    fn get_setter(&self) -> Option<IndexerSetCall> {
+        None
+    }
+
+    fn to_text(&self) -> Option<String> {
+        Some(self.message.to_string())
+    }
+}
+
+impl GetType for ErrorClass {
+    fn get_type(&self) -> String {
+        ERROR_CLASS_NAME.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::buildin::ClassProperty;
+    use crate::buildin::class::error::ErrorClass;
+    use crate::compiler::KaramelPrimative;
+    use crate::error::KaramelErrorType;
+
+    fn get_field(error: KaramelErrorType, field: &str) -> Rc<KaramelPrimative> {
+        match &*ErrorClass::create_object(error).deref() {
+            KaramelPrimative::Class(class) => match class.get_element(None, Rc::new(field.to_string())) {
+                Some(ClassProperty::Field(value)) => value,
+                _ => panic!("'{}' alanı bulunamadı", field)
+            },
+            _ => panic!("hata nesnesi oluşturulamadı")
+        }
+    }
+
+    #[test]
+    fn error_object() {
+        assert_eq!(*get_field(KaramelErrorType::RangeStepCannotBeZero, "kod"), KaramelPrimative::Number(159.0));
+        assert_eq!(*get_field(KaramelErrorType::RangeStepCannotBeZero, "mesaj"), KaramelPrimative::Text(Rc::new("Aralık adımı sıfır olamaz".to_string())));
+        assert_eq!(*get_field(KaramelErrorType::RangeStepCannotBeZero, "değer"), KaramelPrimative::Empty);
+    }
+
+    #[test]
+    fn thrown_error_object() {
+        let value = Rc::new(KaramelPrimative::Text(Rc::new("dosya yok".to_string())));
+        assert_eq!(*get_field(KaramelErrorType::ThrownError(value.clone()), "kod"), KaramelPrimative::Number(166.0));
+        assert_eq!(*get_field(KaramelErrorType::ThrownError(value.clone()), "mesaj"), *value);
+        assert_eq!(*get_field(KaramelErrorType::ThrownError(value.clone()), "değer"), *value);
+    }
+}
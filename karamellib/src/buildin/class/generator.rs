@@ -0,0 +1,18 @@
+use std::rc::Rc;
+
+use crate::buildin::{Class, DummyModule};
+use crate::compiler::function::{FunctionFlag, FunctionReference, VmCall};
+use crate::buildin::class::baseclass::BasicInnerClass;
+use crate::buildin::class::PRIMATIVE_CLASS_NAMES;
+
+/* Created by calling a function that has 'üret'. Values are taken with 'sonraki' or 'her' loop */
+pub fn get_primative_class() -> Rc<dyn Class> {
+    let mut opcode = BasicInnerClass::default();
+    opcode.set_name("üreteç");
+
+    /* Generator runs in VM, native function could not continue it */
+    opcode.add_class_function("sonraki", FunctionReference::vm_function(FunctionReference::resume_function_call as VmCall, "sonraki".to_string(), FunctionFlag::IN_CLASS, Rc::new(DummyModule::new())));
+
+    PRIMATIVE_CLASS_NAMES.lock().unwrap().insert(opcode.get_class_name());
+    Rc::new(opcode)
+}
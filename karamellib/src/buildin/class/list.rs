@@ -1,3 +1,4 @@
+use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::{buildin::Class, compiler::function::{FunctionParameter, NativeCallResult}};
@@ -7,7 +8,7 @@ use crate::compiler::value::KaramelPrimative;
 use crate::error::KaramelErrorType;
 use crate::types::VmObject;
 use crate::{n_parameter_expected, expected_parameter_type, arc_bool, arc_empty};
-use crate::buildin::class::PRIMATIVE_CLASS_NAMES;
+use crate::buildin::class::{PRIMATIVE_CLASS_NAMES, get_position, get_slice_positions};
 
 pub fn get_primative_class() -> Rc<dyn Class> {
     let mut opcode = BasicInnerClass::default();
@@ -22,8 +23,11 @@ pub fn get_primative_class() -> Rc<dyn Class> {
     opcode.add_class_method("arayaekle", insert);
     opcode.add_class_method("pop", pop);
     opcode.add_class_method("sil", remove);
+    opcode.add_class_method("parçagetir", sublist);
+    opcode.add_class_method("parcagetir", sublist);
     opcode.set_getter(getter);
     opcode.set_setter(setter);
+    opcode.set_slicer(slicer);
 
     PRIMATIVE_CLASS_NAMES.lock().unwrap().insert(opcode.get_class_name());
     Rc::new(opcode)
@@ -79,45 +83,39 @@ fn set(parameter: FunctionParameter) -> NativeCallResult {
 }
 
 fn getter(source: VmObject, index: f64) -> NativeCallResult {
-    let index = match index >= 0.0 {
-        true => index as usize,
-        false =>  return Ok(EMPTY_OBJECT)
-    };
-
     if let KaramelPrimative::List(list) = &*source.deref() {
-
-        let is_in_size = index <= list.borrow().len();
-        return match is_in_size {
-            true => match list.borrow().get(index) {
-                Some(item) => Ok(*item),
-                _ => Ok(EMPTY_OBJECT)
-            },
-            false => Ok(arc_empty!())
+        let list = list.borrow();
+        return match get_position(list.len(), index) {
+            Some(position) => Ok(list[position]),
+            None => Ok(arc_empty!())
         };
     }
     Ok(EMPTY_OBJECT)
 }
 
 fn setter(source: VmObject, index: f64, item: VmObject) -> NativeCallResult {
-    let index = match index >= 0.0 {
-        true => index as usize,
-        false =>  return Ok(EMPTY_OBJECT)
-    };
-
     if let KaramelPrimative::List(list) = &*source.deref() {
-
-        let is_in_size = index <= list.borrow().len();
-        return match is_in_size {
-            true => {
-                list.borrow_mut()[index] = item; 
+        let position = get_position(list.borrow().len(), index);
+        return match position {
+            Some(position) => {
+                list.borrow_mut()[position] = item; 
                 Ok(arc_bool!(true))
             },
-            false => Ok(arc_bool!(false))
+            None => Ok(arc_bool!(false))
         };
     }
     Ok(EMPTY_OBJECT)
 }
 
+fn slicer(source: VmObject, start: Option<f64>, end: Option<f64>, step: Option<f64>) -> NativeCallResult {
+    if let KaramelPrimative::List(list) = &*source.deref() {
+        let list = list.borrow();
+        let items = get_slice_positions(list.len(), start, end, step)?.into_iter().map(|position| list[position]).collect();
+        return Ok(VmObject::native_convert(KaramelPrimative::List(RefCell::new(items))));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
 fn length(parameter: FunctionParameter) -> NativeCallResult {
     if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
         let length = list.borrow().len() as f64;
@@ -209,6 +207,33 @@ fn pop(parameter: FunctionParameter) -> NativeCallResult {
     Ok(EMPTY_OBJECT)
 }
 
+/* Items between start and end position, end position is optional */
+fn sublist(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::List(list) = &*parameter.source().unwrap().deref() {
+        let mut positions = Vec::new();
+        for position in parameter.iter() {
+            match &*position.deref() {
+                KaramelPrimative::Number(number) if *number < 0.0 => positions.push(0),
+                KaramelPrimative::Number(number) => positions.push(*number as usize),
+                _ => return expected_parameter_type!("parçagetir".to_string(), "Sayı".to_string())
+            };
+        }
+
+        let length = list.borrow().len();
+        let (start, end) = match positions[..] {
+            [start] => (start, length),
+            [start, end] => (start, end.min(length)),
+            _ => return n_parameter_expected!("parçagetir".to_string(), 2, parameter.length())
+        };
+
+        let items = match start < end {
+            true => list.borrow()[start..end].to_vec(),
+            false => Vec::new()
+        };
+        return Ok(VmObject::native_convert(KaramelPrimative::List(RefCell::new(items))));
+    }
+    Ok(EMPTY_OBJECT)
+}
 
 #[cfg(test)]
 mod tests {
@@ -232,6 +257,10 @@ mod tests {
     nativecall_test!{test_length_3, length,  primative_list!([arc_text!(""), arc_empty!(), arc_number!(123), arc_bool!(true)].to_vec()), KaramelPrimative::Number(4.0)}
 
 
+    nativecall_test_with_params!{test_sublist_1, sublist, primative_list!([arc_number!(1), arc_number!(2), arc_number!(3)].to_vec()), [arc_number!(1)], primative_list!([arc_number!(2), arc_number!(3)].to_vec())}
+    nativecall_test_with_params!{test_sublist_2, sublist, primative_list!([arc_number!(1), arc_number!(2), arc_number!(3)].to_vec()), [arc_number!(0), arc_number!(2)], primative_list!([arc_number!(1), arc_number!(2)].to_vec())}
+    nativecall_test_with_params!{test_sublist_3, sublist, primative_list!([arc_number!(1)].to_vec()), [arc_number!(3), arc_number!(10)], primative_list!(Vec::new())}
+
     nativecall_test_with_params!{test_add_1, add, primative_list!([arc_text!("")].to_vec()), [VmObject::from(8.0)], primative_number!(1)}
     nativecall_test_with_params!{test_add_2, add, primative_list!(Vec::new()), [VmObject::native_convert(KaramelPrimative::Bool(true))], primative_number!(0)}
     #[test]
@@ -1,15 +1,22 @@
 pub mod number;
 pub mod text;
 pub mod list;
+pub mod tuple;
+pub mod generator;
+pub mod set;
 pub mod dict;
 pub mod baseclass;
 pub mod proxy;
+pub mod range;
+pub mod error;
+pub mod task;
 
 use crate::buildin::class::baseclass::BasicInnerClass;
 use std::{collections::HashSet, rc::Rc};
 use lazy_static::*;
 
 use super::Class;
+use crate::error::KaramelErrorType;
 
 use std::sync::Mutex;
 
@@ -23,6 +30,49 @@ pub fn get_empty_class() -> Rc<dyn Class> {
     Rc::new(opcode)
 }
 
+/* Negative positions are counted from the end */
+pub fn get_position(length: usize, index: f64) -> Option<usize> {
+    let position = match index < 0.0 {
+        true => length as f64 + index.trunc(),
+        false => index.trunc()
+    };
+
+    match position >= 0.0 && position < length as f64 {
+        true => Some(position as usize),
+        false => None
+    }
+}
+
+/* Selected positions for 'kaynak[baş:son:adım]'. Out of range bounds are clipped and missing bounds cover the whole source */
+pub fn get_slice_positions(length: usize, start: Option<f64>, end: Option<f64>, step: Option<f64>) -> Result<Vec<usize>, KaramelErrorType> {
+    let step = step.unwrap_or(1.0).trunc() as i64;
+    if step == 0 {
+        return Err(KaramelErrorType::SliceStepCannotBeZero);
+    }
+
+    /* Step that is longer than the source selects only the start position, it is clipped to prevent overflow */
+    let length = length as i64;
+    let step = step.max(-length - 1).min(length + 1);
+    let clip = |value: f64, lowest: i64, highest: i64| {
+        let value = value.trunc() as i64;
+        let value = if value < 0 { value + length } else { value };
+        value.max(lowest).min(highest)
+    };
+
+    let (start, end) = match step > 0 {
+        true  => (start.map_or(0, |value| clip(value, 0, length)), end.map_or(length, |value| clip(value, 0, length))),
+        false => (start.map_or(length - 1, |value| clip(value, -1, length - 1)), end.map_or(-1, |value| clip(value, -1, length - 1)))
+    };
+
+    let mut positions = Vec::new();
+    let mut position = start;
+    while (step > 0 && position < end) || (step < 0 && position > end) {
+        positions.push(position as usize);
+        position += step;
+    }
+    Ok(positions)
+}
+
 
 #[macro_export]
 macro_rules! nativecall_test {
@@ -141,3 +191,32 @@ macro_rules! expected_parameter_type {
         expected: $expected_type
     }) };
 }
+
+#[cfg(test)]
+mod test {
+    use crate::buildin::class::{get_position, get_slice_positions};
+
+    #[test]
+    fn position() {
+        assert_eq!(get_position(3, 0.0), Some(0));
+        assert_eq!(get_position(3, -1.0), Some(2));
+        assert_eq!(get_position(3, -3.0), Some(0));
+        assert_eq!(get_position(3, -4.0), None);
+        assert_eq!(get_position(3, 3.0), None);
+    }
+
+    #[test]
+    fn slice_positions() {
+        assert_eq!(get_slice_positions(5, Some(1.0), Some(4.0), None).unwrap(), vec![1, 2, 3]);
+        assert_eq!(get_slice_positions(5, None, Some(3.0), None).unwrap(), vec![0, 1, 2]);
+        assert_eq!(get_slice_positions(5, Some(-2.0), None, None).unwrap(), vec![3, 4]);
+        assert_eq!(get_slice_positions(5, None, None, Some(2.0)).unwrap(), vec![0, 2, 4]);
+        assert_eq!(get_slice_positions(5, None, None, Some(-1.0)).unwrap(), vec![4, 3, 2, 1, 0]);
+        assert_eq!(get_slice_positions(5, Some(3.0), Some(0.0), Some(-1.0)).unwrap(), vec![3, 2, 1]);
+        assert_eq!(get_slice_positions(5, Some(-10.0), Some(10.0), None).unwrap(), vec![0, 1, 2, 3, 4]);
+        assert_eq!(get_slice_positions(5, Some(4.0), Some(1.0), None).unwrap(), Vec::<usize>::new());
+        assert_eq!(get_slice_positions(5, Some(1.0), None, Some(1e19)).unwrap(), vec![1]);
+        assert_eq!(get_slice_positions(5, None, None, Some(-1e19)).unwrap(), vec![4]);
+        assert!(get_slice_positions(5, None, None, Some(0.0)).is_err());
+    }
+}
@@ -4,6 +4,7 @@ use crate::buildin::class::BasicInnerClass;
 use crate::compiler::value::KaramelPrimative;
 use crate::types::VmObject;
 use crate::buildin::class::PRIMATIVE_CLASS_NAMES;
+use crate::compiler::bigint::{BigInt, is_safe_integer};
 
 use std::{mem, rc::Rc};
 
@@ -25,57 +26,73 @@ pub fn get_primative_class() -> Rc<dyn Class> {
 }
 
 fn hex(parameter: FunctionParameter) -> NativeCallResult {
-    if let KaramelPrimative::Number(number) = &*parameter.source().unwrap().deref() {
-        if number.fract() != 0.0 {
-            let as_int: u64 = unsafe { mem::transmute(*number) };
-            return Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(format!("0x{:x}", as_int)))));
-        }
-
-        return Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(format!("0x{:x}", number.trunc() as i64)))));
+    match &*parameter.source().unwrap().deref() {
+        KaramelPrimative::Number(number) => {
+            if number.fract() != 0.0 {
+                let as_int: u64 = unsafe { mem::transmute(*number) };
+                return Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(format!("0x{:x}", as_int)))));
+            }
+
+            /* Large floats are converted with their exact value */
+            match is_safe_integer(*number) {
+                true => Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(format!("0x{:x}", number.trunc() as i64))))),
+                false => Ok(BigInt::from_f64(*number).map_or(EMPTY_OBJECT, |number| VmObject::native_convert(KaramelPrimative::Text(Rc::new(number.to_hex())))))
+            }
+        },
+        KaramelPrimative::BigInt(number) => Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(number.to_hex())))),
+        _ => Ok(EMPTY_OBJECT)
     }
-    Ok(EMPTY_OBJECT)
 }
 
 fn string(parameter: FunctionParameter) -> NativeCallResult {
-    if let KaramelPrimative::Number(number) = &*parameter.source().unwrap().deref() {
-        return Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(format!("{}", number)))));
+    match &*parameter.source().unwrap().deref() {
+        KaramelPrimative::Number(number) => Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(format!("{}", number))))),
+        KaramelPrimative::BigInt(number) => Ok(VmObject::native_convert(KaramelPrimative::Text(Rc::new(number.to_string())))),
+        _ => Ok(EMPTY_OBJECT)
     }
-    Ok(EMPTY_OBJECT)
 }
 
-fn round(parameter: FunctionParameter) -> NativeCallResult {
-    if let KaramelPrimative::Number(number) = &*parameter.source().unwrap().deref() {
-        return Ok(VmObject::from(number.round()));
+/* Big integers do not have fraction, rounding functions return the same value */
+fn rounding(parameter: FunctionParameter, function: fn(f64) -> f64) -> NativeCallResult {
+    let source = parameter.source().unwrap();
+    match &*source.deref() {
+        KaramelPrimative::Number(number) => Ok(VmObject::from(function(*number))),
+        KaramelPrimative::BigInt(_) => Ok(source),
+        _ => Ok(EMPTY_OBJECT)
     }
-    Ok(EMPTY_OBJECT)
+}
+
+fn round(parameter: FunctionParameter) -> NativeCallResult {
+    rounding(parameter, f64::round)
 }
 
 fn ceil(parameter: FunctionParameter) -> NativeCallResult {
-    if let KaramelPrimative::Number(number) = &*parameter.source().unwrap().deref() {
-        return Ok(VmObject::from(number.ceil()));
-    }
-    Ok(EMPTY_OBJECT)
+    rounding(parameter, f64::ceil)
 }
 
 fn floor(parameter: FunctionParameter) -> NativeCallResult {
-    if let KaramelPrimative::Number(number) = &*parameter.source().unwrap().deref() {
-        return Ok(VmObject::from(number.floor()));
-    }
-    Ok(EMPTY_OBJECT)
+    rounding(parameter, f64::floor)
 }
 
+/* Result is exact integer, large floats are converted to big integer */
 fn trunc(parameter: FunctionParameter) -> NativeCallResult {
-    if let KaramelPrimative::Number(number) = &*parameter.source().unwrap().deref() {
-        return Ok(VmObject::from(number.trunc()));
+    let source = parameter.source().unwrap();
+    match &*source.deref() {
+        KaramelPrimative::Number(number) => match BigInt::from_f64(number.trunc()) {
+            Some(integer) => Ok(integer.to_object()),
+            None => Ok(VmObject::from(number.trunc()))
+        },
+        KaramelPrimative::BigInt(_) => Ok(source),
+        _ => Ok(EMPTY_OBJECT)
     }
-    Ok(EMPTY_OBJECT)
 }
 
 fn fract(parameter: FunctionParameter) -> NativeCallResult {
-    if let KaramelPrimative::Number(number) = &*parameter.source().unwrap().deref() {
-        return Ok(VmObject::from(number.fract()));
+    match &*parameter.source().unwrap().deref() {
+        KaramelPrimative::Number(number) => Ok(VmObject::from(number.fract())),
+        KaramelPrimative::BigInt(_) => Ok(VmObject::from(0.0)),
+        _ => Ok(EMPTY_OBJECT)
     }
-    Ok(EMPTY_OBJECT)
 }
 
 #[cfg(test)]
@@ -113,5 +130,13 @@ mod tests {
     nativecall_test!{test_tamsayi_1, trunc, KaramelPrimative::Number(-1.5), KaramelPrimative::Number(-1.0)}
     nativecall_test!{test_tamsayi_2, trunc, KaramelPrimative::Number(122.51), KaramelPrimative::Number(122.0)}
 
+    nativecall_test!{test_tamsayi_3, trunc, KaramelPrimative::Number(1e20), BigInt::from_f64(1e20).unwrap().to_primative()}
+    nativecall_test!{test_tamsayi_4, trunc, BigInt::from_f64(-1e20).unwrap().to_primative(), BigInt::from_f64(-1e20).unwrap().to_primative()}
+
+    nativecall_test!{test_hex_4, hex, KaramelPrimative::Number(1e20), KaramelPrimative::Text(Rc::new("0x56bc75e2d63100000".to_string()))}
+    nativecall_test!{test_hex_5, hex, BigInt::from_f64(-1e20).unwrap().to_primative(), KaramelPrimative::Text(Rc::new("-0x56bc75e2d63100000".to_string()))}
+
     nativecall_test!{test_kesir_1, fract, KaramelPrimative::Number(-1.5), KaramelPrimative::Number(-0.5)}
+    nativecall_test!{test_kesir_2, fract, BigInt::from_f64(1e20).unwrap().to_primative(), KaramelPrimative::Number(0.0)}
+    nativecall_test!{test_yazi_1, string, BigInt::from_f64(1e20).unwrap().to_primative(), KaramelPrimative::Text(Rc::new("100000000000000000000".to_string()))}
 }
\ No newline at end of file
@@ -1,7 +1,8 @@
 use crate::compiler::KaramelPrimative;
+use crate::compiler::value::EMPTY_OBJECT;
 use crate::{
     buildin::{Class, ClassProperty},
-    compiler::function::{IndexerGetCall, IndexerSetCall, NativeCall, FunctionFlag},
+    compiler::function::{IndexerGetCall, IndexerSetCall, IndexerSliceCall, NativeCall, NativeCallResult, FunctionFlag},
     types::VmObject,
 };
 
@@ -56,7 +57,7 @@ fn get_element(&self, source: Option<VmObject>, field: Rc<String>) -> Option<Cla
     fn set_getter(&mut self, _: IndexerGetCall) {}
 
     fn get_getter(&self) -> Option<IndexerGetCall> {
-        None
+        Some(getter)
     }
 
     fn set_setter(&mut self, _: IndexerSetCall) {}
@@ -64,6 +65,30 @@ fn get_element(&self, source: Option<VmObject>, field: Rc<String>) -> Option<Cla
     fn get_setter(&self) -> Option<IndexerSetCall> {
         None
     }
+
+    fn get_slicer(&self) -> Option<IndexerSliceCall> {
+        Some(slicer)
+    }
+}
+
+/* Indexer request forwarded to class */
+fn getter(source: VmObject, index: f64) -> NativeCallResult {
+    if let KaramelPrimative::Class(class) = &*source.deref() {
+        if let Some(function) = class.get_getter() {
+            return function(source, index);
+        }
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+/* Slice request forwarded to class */
+fn slicer(source: VmObject, start: Option<f64>, end: Option<f64>, step: Option<f64>) -> NativeCallResult {
+    if let KaramelPrimative::Class(class) = &*source.deref() {
+        if let Some(function) = class.get_slicer() {
+            return function(source, start, end, step);
+        }
+    }
+    Ok(EMPTY_OBJECT)
 }
 
 pub fn get_primative_class() -> Rc<dyn Class> {
@@ -88,6 +113,9 @@ mod test {
     use crate::buildin::Class;
     use crate::compiler::KaramelPrimative;
     use crate::compiler::GetType;
+    use crate::compiler::function::NativeCallResult;
+    use crate::buildin::class::proxy::get_primative_class;
+    use crate::types::VmObject;
     use std::rc::Rc;
 
     #[test]
@@ -119,4 +147,20 @@ mod test {
         assert_eq!(opcode_class.get_class_name(), "test_class".to_string());
         assert_eq!(opcode_class.property_count(), 2);
     }
+
+    #[test]
+    fn test_opcode_class_slicer() {
+        fn slice_length(_: VmObject, start: Option<f64>, end: Option<f64>, _: Option<f64>) -> NativeCallResult {
+            Ok(VmObject::from(end.unwrap_or(10.0) - start.unwrap_or(0.0)))
+        }
+
+        let mut opcode_class: BasicInnerClass = BasicInnerClass::default();
+        opcode_class.set_name("test_class");
+        opcode_class.set_slicer(slice_length);
+
+        /* Slice request is forwarded to class */
+        let object = VmObject::from(Rc::new(KaramelPrimative::Class(Rc::new(opcode_class))));
+        let result = get_primative_class().get_slicer().unwrap()(object, Some(2.0), None, None);
+        assert_eq!(*result.unwrap().deref(), KaramelPrimative::Number(8.0));
+    }
 }
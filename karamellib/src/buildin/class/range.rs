@@ -0,0 +1,179 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::buildin::{Class, ClassConfig, ClassProperty};
+use crate::compiler::function::{FunctionFlag, FunctionParameter, FunctionReference, IndexerGetCall, IndexerSetCall, NativeCall, NativeCallResult};
+use crate::compiler::value::EMPTY_OBJECT;
+use crate::buildin::class::get_position;
+use crate::compiler::{GetType, KaramelPrimative};
+use crate::types::VmObject;
+
+pub const RANGE_CLASS_NAME: &str = "aralık";
+
+/* Lazily evaluated number range, created with 'baz::aralık'. Last value is not included */
+pub struct RangeClass {
+    start: f64,
+    end: f64,
+    step: f64,
+    properties: HashMap<String, ClassProperty>
+}
+
+impl RangeClass {
+    pub fn new(start: f64, end: f64, step: f64) -> RangeClass {
+        let mut properties = HashMap::new();
+        properties.insert("baş".to_string(), ClassProperty::Field(Rc::new(KaramelPrimative::Number(start))));
+        properties.insert("son".to_string(), ClassProperty::Field(Rc::new(KaramelPrimative::Number(end))));
+        properties.insert("adım".to_string(), ClassProperty::Field(Rc::new(KaramelPrimative::Number(step))));
+        properties.insert("uzunluk".to_string(), ClassProperty::Function(FunctionReference::buildin_function(length as NativeCall, "uzunluk".to_string(), FunctionFlag::IN_CLASS)));
+        properties.insert("listele".to_string(), ClassProperty::Function(FunctionReference::buildin_function(to_list as NativeCall, "listele".to_string(), FunctionFlag::IN_CLASS)));
+
+        RangeClass { start, end, step, properties }
+    }
+
+    pub fn length(&self) -> usize {
+        let length = ((self.end - self.start) / self.step).ceil();
+        match length > 0.0 {
+            true => length as usize,
+            false => 0
+        }
+    }
+
+    pub fn get(&self, position: usize) -> Option<VmObject> {
+        match position < self.length() {
+            true => Some(VmObject::from(self.start + (position as f64 * self.step))),
+            false => None
+        }
+    }
+}
+
+fn length(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Class(class) = &*parameter.source().unwrap().deref() {
+        if let Some(length) = class.get_length() {
+            return Ok(VmObject::from(length as f64));
+        }
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn to_list(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Class(class) = &*parameter.source().unwrap().deref() {
+        if let Some(length) = class.get_length() {
+            let items = (0..length).filter_map(|position| class.get_item(position)).collect();
+            return Ok(VmObject::native_convert(KaramelPrimative::List(RefCell::new(items))));
+        }
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+impl Class for RangeClass {
+    fn set_class_config(&mut self, _: ClassConfig) {}
+
+    fn get_class_name(&self) -> String {
+        RANGE_CLASS_NAME.to_string()
+    }
+
+    fn has_element(&self, _: Option<VmObject>, field: Rc<String>) -> bool {
+        self.properties.contains_key(&*field)
+    }
+
+    fn get_element(&self, _: Option<VmObject>, field: Rc<String>) -> Option<ClassProperty> {
+        self.properties.get(&*field).cloned()
+    }
+
+    fn property_count(&self) -> usize {
+        self.properties.len()
+    }
+
+    fn properties(&self) -> std::collections::hash_map::Iter<'_, String, ClassProperty> {
+        self.properties.iter()
+    }
+
+    fn add_method(&mut self, _: &str, _: NativeCall, _: FunctionFlag) {}
+
+    fn add_property(&mut self, _: &str, _: Rc<KaramelPrimative>) {}
+
+    fn set_getter(&mut self, _: IndexerGetCall) {}
+
+    fn get_getter(&self) -> Option<IndexerGetCall> {
+        Some(getter)
+    }
+
+    fn set_setter(&mut self, _: IndexerSetCall) {}
+
+    fn get_setter(&self) -> Option<IndexerSetCall> {
+        None
+    }
+
+    fn get_length(&self) -> Option<usize> {
+        Some(self.length())
+    }
+
+    fn get_item(&self, position: usize) -> Option<VmObject> {
+        self.get(position)
+    }
+
+    fn to_text(&self) -> Option<String> {
+        Some(format!("aralık({}, {}, {})", self.start, self.end, self.step))
+    }
+
+    /* Calculated without visiting all items */
+    fn contains(&self, value: &KaramelPrimative) -> Option<bool> {
+        match value {
+            KaramelPrimative::Number(number) => {
+                let position = (number - self.start) / self.step;
+                Some(position >= 0.0 && position.fract() == 0.0 && position < self.length() as f64)
+            },
+            _ => Some(false)
+        }
+    }
+}
+
+impl GetType for RangeClass {
+    fn get_type(&self) -> String {
+        RANGE_CLASS_NAME.to_string()
+    }
+}
+
+fn getter(source: VmObject, index: f64) -> NativeCallResult {
+    if let KaramelPrimative::Class(class) = &*source.deref() {
+        let position = class.get_length().and_then(|length| get_position(length, index));
+        if let Some(item) = position.and_then(|position| class.get_item(position)) {
+            return Ok(item);
+        }
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::buildin::Class;
+    use crate::buildin::class::range::RangeClass;
+    use crate::compiler::KaramelPrimative;
+
+    #[test]
+    fn range_length() {
+        assert_eq!(RangeClass::new(0.0, 10.0, 1.0).length(), 10);
+        assert_eq!(RangeClass::new(0.0, 10.0, 3.0).length(), 4);
+        assert_eq!(RangeClass::new(10.0, 0.0, -2.0).length(), 5);
+        assert_eq!(RangeClass::new(10.0, 0.0, 1.0).length(), 0);
+    }
+
+    #[test]
+    fn range_get() {
+        let range = RangeClass::new(10.0, 0.0, -2.0);
+        assert_eq!(*range.get(0).unwrap().deref(), KaramelPrimative::Number(10.0));
+        assert_eq!(*range.get(4).unwrap().deref(), KaramelPrimative::Number(2.0));
+        assert!(range.get(5).is_none());
+    }
+
+    #[test]
+    fn range_contains() {
+        let range = RangeClass::new(10.0, 0.0, -2.0);
+        assert_eq!(range.contains(&KaramelPrimative::Number(4.0)), Some(true));
+        assert_eq!(range.contains(&KaramelPrimative::Number(5.0)), Some(false));
+        assert_eq!(range.contains(&KaramelPrimative::Number(0.0)), Some(false));
+        assert_eq!(range.contains(&KaramelPrimative::Number(12.0)), Some(false));
+        assert_eq!(range.contains(&KaramelPrimative::Bool(true)), Some(false));
+    }
+}
@@ -0,0 +1,156 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::{buildin::Class, compiler::function::{FunctionParameter, NativeCallResult}};
+use crate::compiler::value::EMPTY_OBJECT;
+use crate::buildin::class::baseclass::BasicInnerClass;
+use crate::compiler::value::KaramelPrimative;
+use crate::compiler::hash::{HashKey, KaramelSet};
+use crate::error::KaramelErrorType;
+use crate::types::VmObject;
+use crate::{n_parameter_expected, expected_parameter_type, arc_bool};
+use crate::buildin::class::PRIMATIVE_CLASS_NAMES;
+
+/* Unique numbers, texts and bools, created with '{1, 2, 3}'. Items are kept in insertion order */
+pub fn get_primative_class() -> Rc<dyn Class> {
+    let mut opcode = BasicInnerClass::default();
+    opcode.set_name("küme");
+
+    opcode.add_class_method("uzunluk", length);
+    opcode.add_class_method("ekle", add);
+    opcode.add_class_method("sil", remove);
+    opcode.add_class_method("içeriyormu", contains);
+    opcode.add_class_method("iceriyormu", contains);
+    opcode.add_class_method("birleşim", union);
+    opcode.add_class_method("birlesim", union);
+    opcode.add_class_method("kesişim", intersection);
+    opcode.add_class_method("kesisim", intersection);
+    opcode.add_class_method("fark", difference);
+    opcode.add_class_method("alt_kümesimi", is_subset);
+    opcode.add_class_method("alt_kumesimi", is_subset);
+    opcode.add_class_method("listele", list);
+
+    PRIMATIVE_CLASS_NAMES.lock().unwrap().insert(opcode.get_class_name());
+    Rc::new(opcode)
+}
+
+fn single_parameter(parameter: &FunctionParameter, function_name: &str) -> Result<VmObject, KaramelErrorType> {
+    match parameter.length() {
+        0 => n_parameter_expected!(function_name.to_string(), 1),
+        1 => Ok(*parameter.iter().next().unwrap()),
+        _ => n_parameter_expected!(function_name.to_string(), 1, parameter.length())
+    }
+}
+
+/* Set operations accept only sets */
+fn set_operation<F: Fn(&KaramelSet, &KaramelSet) -> VmObject>(parameter: FunctionParameter, function_name: &str, operation: F) -> NativeCallResult {
+    if let KaramelPrimative::Set(items) = &*parameter.source().unwrap().deref() {
+        let other = single_parameter(&parameter, function_name)?.deref();
+        return match &*other {
+            KaramelPrimative::Set(other) => Ok(operation(&items.borrow(), &other.borrow())),
+            _ => expected_parameter_type!(function_name.to_string(), "Küme".to_string())
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn new_set(set: KaramelSet) -> VmObject {
+    VmObject::native_convert(KaramelPrimative::Set(RefCell::new(set)))
+}
+
+fn length(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Set(items) = &*parameter.source().unwrap().deref() {
+        return Ok(VmObject::from(items.borrow().len() as f64));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn add(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Set(items) = &*parameter.source().unwrap().deref() {
+        let item = single_parameter(&parameter, "ekle")?.deref();
+        return match HashKey::from_primative(&item) {
+            Some(key) => Ok(arc_bool!(items.borrow_mut().insert(key))),
+            None => Err(KaramelErrorType::ValueNotHashable(item.clone()))
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn remove(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Set(items) = &*parameter.source().unwrap().deref() {
+        let item = single_parameter(&parameter, "sil")?.deref();
+        return match HashKey::from_primative(&item) {
+            Some(key) => Ok(arc_bool!(items.borrow_mut().remove(&key))),
+            None => Ok(arc_bool!(false))
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn contains(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Set(items) = &*parameter.source().unwrap().deref() {
+        let item = single_parameter(&parameter, "içeriyormu")?.deref();
+        return match HashKey::from_primative(&item) {
+            Some(key) => Ok(arc_bool!(items.borrow().contains(&key))),
+            None => Ok(arc_bool!(false))
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn union(parameter: FunctionParameter) -> NativeCallResult {
+    set_operation(parameter, "birleşim", |left, right| new_set(left.union(right)))
+}
+
+fn intersection(parameter: FunctionParameter) -> NativeCallResult {
+    set_operation(parameter, "kesişim", |left, right| new_set(left.intersection(right)))
+}
+
+fn difference(parameter: FunctionParameter) -> NativeCallResult {
+    set_operation(parameter, "fark", |left, right| new_set(left.difference(right)))
+}
+
+fn is_subset(parameter: FunctionParameter) -> NativeCallResult {
+    set_operation(parameter, "alt_kümesimi", |left, right| arc_bool!(left.is_subset(right)))
+}
+
+fn list(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Set(items) = &*parameter.source().unwrap().deref() {
+        let items = items.borrow().iter().map(|item| item.to_object()).collect();
+        return Ok(VmObject::native_convert(KaramelPrimative::List(RefCell::new(items))));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+    use crate::compiler::value::KaramelPrimative;
+    use super::*;
+
+    use crate::nativecall_test;
+    use crate::nativecall_test_with_params;
+    use crate::primative_list;
+    use crate::arc_number;
+
+    fn numbers(items: &[f64]) -> KaramelPrimative {
+        let mut set = KaramelSet::new();
+        for item in items {
+            set.insert(HashKey::from_primative(&KaramelPrimative::Number(*item)).unwrap());
+        }
+        KaramelPrimative::Set(RefCell::new(set))
+    }
+
+    nativecall_test!{test_length_1, length, numbers(&[1.0, 2.0]), KaramelPrimative::Number(2.0)}
+    nativecall_test!{test_length_2, length, numbers(&[]), KaramelPrimative::Number(0.0)}
+    nativecall_test!{test_list_1, list, numbers(&[2.0, 1.0]), primative_list!(vec![arc_number!(2), arc_number!(1)])}
+    nativecall_test_with_params!{test_add_1, add, numbers(&[1.0]), [arc_number!(2)], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params!{test_add_2, add, numbers(&[1.0]), [arc_number!(1)], KaramelPrimative::Bool(false)}
+    nativecall_test_with_params!{test_remove_1, remove, numbers(&[1.0]), [arc_number!(1)], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params!{test_contains_1, contains, numbers(&[1.0, 2.0]), [arc_number!(2)], KaramelPrimative::Bool(true)}
+    nativecall_test_with_params!{test_contains_2, contains, numbers(&[1.0, 2.0]), [VmObject::native_convert(KaramelPrimative::Text(Rc::new("1".to_string())))], KaramelPrimative::Bool(false)}
+    nativecall_test_with_params!{test_union_1, union, numbers(&[1.0, 2.0]), [VmObject::native_convert(numbers(&[2.0, 3.0]))], numbers(&[1.0, 2.0, 3.0])}
+    nativecall_test_with_params!{test_intersection_1, intersection, numbers(&[1.0, 2.0]), [VmObject::native_convert(numbers(&[2.0, 3.0]))], numbers(&[2.0])}
+    nativecall_test_with_params!{test_difference_1, difference, numbers(&[1.0, 2.0]), [VmObject::native_convert(numbers(&[2.0, 3.0]))], numbers(&[1.0])}
+    nativecall_test_with_params!{test_is_subset_1, is_subset, numbers(&[2.0]), [VmObject::native_convert(numbers(&[2.0, 3.0]))], KaramelPrimative::Bool(true)}
+}
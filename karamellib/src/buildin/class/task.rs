@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::buildin::{Class, ClassConfig, ClassProperty, DummyModule};
+use crate::compiler::function::{FunctionFlag, FunctionReference, IndexerGetCall, IndexerSetCall, NativeCall, VmCall};
+use crate::compiler::{GetType, KaramelPrimative};
+use crate::buildin::task::TaskModule;
+use crate::types::VmObject;
+
+pub const TASK_CLASS_NAME: &str = "görev";
+pub const CHANNEL_CLASS_NAME: &str = "kanal";
+
+/* Handle of a task or channel in the scheduler, created by 'görev' module */
+pub struct TaskClass {
+    name: &'static str,
+    id: usize,
+    properties: HashMap<String, ClassProperty>
+}
+
+impl TaskClass {
+    fn new(name: &'static str, id: usize) -> TaskClass {
+        let mut properties = HashMap::new();
+        properties.insert("kimlik".to_string(), ClassProperty::Field(Rc::new(KaramelPrimative::Number(id as f64))));
+        TaskClass { name, id, properties }
+    }
+
+    pub fn task(id: usize) -> VmObject {
+        VmObject::native_convert(KaramelPrimative::Class(Rc::new(TaskClass::new(TASK_CLASS_NAME, id))))
+    }
+
+    pub fn channel(id: usize) -> VmObject {
+        let mut class = TaskClass::new(CHANNEL_CLASS_NAME, id);
+        class.properties.insert("gönder".to_string(), ClassProperty::Function(FunctionReference::vm_function(TaskModule::send as VmCall, "gönder".to_string(), FunctionFlag::IN_CLASS, Rc::new(DummyModule::new()))));
+        class.properties.insert("al".to_string(), ClassProperty::Function(FunctionReference::vm_function(TaskModule::receive as VmCall, "al".to_string(), FunctionFlag::IN_CLASS, Rc::new(DummyModule::new()))));
+        VmObject::native_convert(KaramelPrimative::Class(Rc::new(class)))
+    }
+
+    /* Scheduler id of the handle, None if object is not a handle with given class name */
+    pub fn get_id(object: &KaramelPrimative, name: &str) -> Option<usize> {
+        match object {
+            KaramelPrimative::Class(class) if class.get_class_name() == name => match class.get_element(None, Rc::new("kimlik".to_string())) {
+                Some(ClassProperty::Field(field)) => match &*field {
+                    KaramelPrimative::Number(id) => Some(*id as usize),
+                    _ => None
+                },
+                _ => None
+            },
+            _ => None
+        }
+    }
+}
+
+impl Class for TaskClass {
+    fn set_class_config(&mut self, _: ClassConfig) {}
+
+    fn get_class_name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn has_element(&self, _: Option<VmObject>, field: Rc<String>) -> bool {
+        self.properties.contains_key(&*field)
+    }
+
+    fn get_element(&self, _: Option<VmObject>, field: Rc<String>) -> Option<ClassProperty> {
+        self.properties.get(&*field).cloned()
+    }
+
+    fn property_count(&self) -> usize {
+        self.properties.len()
+    }
+
+    fn properties(&self) -> std::collections::hash_map::Iter<'_, String, ClassProperty> {
+        self.properties.iter()
+    }
+
+    fn add_method(&mut self, _: &str, _: NativeCall, _: FunctionFlag) {}
+
+    fn add_property(&mut self, _: &str, _: Rc<KaramelPrimative>) {}
+
+    fn set_getter(&mut self, _: IndexerGetCall) {}
+
+    fn get_getter(&self) -> Option<IndexerGetCall> {
+        None
+    }
+
+    fn set_setter(&mut self, _: IndexerSetCall) {}
+
+    fn get_setter(&self) -> Option<IndexerSetCall> {
+        None
+    }
+
+    fn equals(&self, left: &KaramelPrimative, right: &KaramelPrimative) -> Option<bool> {
+        match (TaskClass::get_id(left, self.name), TaskClass::get_id(right, self.name)) {
+            (Some(left), Some(right)) => Some(left == right),
+            _ => Some(false)
+        }
+    }
+
+    fn to_text(&self) -> Option<String> {
+        Some(format!("<{}={}>", self.name, self.id))
+    }
+}
+
+impl GetType for TaskClass {
+    fn get_type(&self) -> String {
+        self.name.to_string()
+    }
+}
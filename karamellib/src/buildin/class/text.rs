@@ -5,7 +5,7 @@ use crate::compiler::value::KaramelPrimative;
 use crate::types::VmObject;
 use crate::{n_parameter_expected, expected_parameter_type, arc_text};
 use crate::primative_text;
-use crate::buildin::class::PRIMATIVE_CLASS_NAMES;
+use crate::buildin::class::{PRIMATIVE_CLASS_NAMES, get_position, get_slice_positions};
 use crate::error::KaramelErrorType;
 
 use unicode_width::UnicodeWidthStr;
@@ -42,6 +42,7 @@ pub fn get_primative_class() -> Rc<dyn Class> {
     opcode.add_class_method("sayi", number);
     opcode.set_getter(getter);
     opcode.set_setter(setter);
+    opcode.set_slicer(slicer);
 
     PRIMATIVE_CLASS_NAMES.lock().unwrap().insert(opcode.get_class_name());
     Rc::new(opcode)
@@ -49,12 +50,11 @@ pub fn get_primative_class() -> Rc<dyn Class> {
 
 
 fn getter(source: VmObject, index: f64) -> NativeCallResult {
-    let index = match index >= 0.0 {
-        true => index as usize,
-        false =>  return Ok(EMPTY_OBJECT)
-    };
-    
     if let KaramelPrimative::Text(text) = &*source.deref() {
+        let index = match get_position(text.chars().count(), index) {
+            Some(index) => index,
+            None => return Ok(EMPTY_OBJECT)
+        };
 
         return match text.chars().nth(index) {
             Some(item) => Ok(arc_text!(item.to_string())),
@@ -64,13 +64,22 @@ fn getter(source: VmObject, index: f64) -> NativeCallResult {
     Ok(EMPTY_OBJECT)
 }
 
-fn setter(source: VmObject, index: f64, item: VmObject) -> NativeCallResult {
-    let index = match index >= 0.0 {
-        true => index as usize,
-        false =>  return Ok(EMPTY_OBJECT)
-    };
+fn slicer(source: VmObject, start: Option<f64>, end: Option<f64>, step: Option<f64>) -> NativeCallResult {
+    if let KaramelPrimative::Text(text) = &*source.deref() {
+        let chars: Vec<char> = text.chars().collect();
+        let sliced: String = get_slice_positions(chars.len(), start, end, step)?.into_iter().map(|position| chars[position]).collect();
+        return Ok(arc_text!(sliced));
+    }
+    Ok(EMPTY_OBJECT)
+}
 
+fn setter(source: VmObject, index: f64, item: VmObject) -> NativeCallResult {
     if let KaramelPrimative::Text(text) = &*source.deref() {
+        let index = match get_position(text.chars().count(), index) {
+            Some(index) => index,
+            None => return Ok(EMPTY_OBJECT)
+        };
+
         return match text.chars().nth(index) {
             Some(old_char) => {
                 match &*item.deref() {
@@ -0,0 +1,71 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::{buildin::Class, compiler::function::{FunctionParameter, NativeCallResult}};
+use crate::compiler::value::EMPTY_OBJECT;
+use crate::buildin::class::baseclass::BasicInnerClass;
+use crate::compiler::value::KaramelPrimative;
+use crate::types::VmObject;
+use crate::arc_empty;
+use crate::buildin::class::{PRIMATIVE_CLASS_NAMES, get_position, get_slice_positions};
+
+/* Immutable value list, created with 'döndür a, b' or 'a, b = b, a'. Tuple has no setter */
+pub fn get_primative_class() -> Rc<dyn Class> {
+    let mut opcode = BasicInnerClass::default();
+    opcode.set_name("demet");
+
+    opcode.add_class_method("uzunluk", length);
+    opcode.add_class_method("liste", list);
+    opcode.set_getter(getter);
+    opcode.set_slicer(slicer);
+
+    PRIMATIVE_CLASS_NAMES.lock().unwrap().insert(opcode.get_class_name());
+    Rc::new(opcode)
+}
+
+fn getter(source: VmObject, index: f64) -> NativeCallResult {
+    if let KaramelPrimative::Tuple(items) = &*source.deref() {
+        return match get_position(items.len(), index) {
+            Some(position) => Ok(items[position]),
+            None => Ok(arc_empty!())
+        };
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn slicer(source: VmObject, start: Option<f64>, end: Option<f64>, step: Option<f64>) -> NativeCallResult {
+    if let KaramelPrimative::Tuple(items) = &*source.deref() {
+        let sliced = get_slice_positions(items.len(), start, end, step)?.into_iter().map(|position| items[position]).collect();
+        return Ok(VmObject::native_convert(KaramelPrimative::Tuple(Rc::new(sliced))));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn length(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Tuple(items) = &*parameter.source().unwrap().deref() {
+        return Ok(VmObject::from(items.len() as f64));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+fn list(parameter: FunctionParameter) -> NativeCallResult {
+    if let KaramelPrimative::Tuple(items) = &*parameter.source().unwrap().deref() {
+        return Ok(VmObject::native_convert(KaramelPrimative::List(RefCell::new(items.to_vec()))));
+    }
+    Ok(EMPTY_OBJECT)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+    use crate::compiler::value::KaramelPrimative;
+    use super::*;
+
+    use crate::nativecall_test;
+    use crate::primative_list;
+    use crate::arc_number;
+
+    nativecall_test!{test_length_1, length, KaramelPrimative::Tuple(Rc::new(vec![arc_number!(1), arc_number!(2)])), KaramelPrimative::Number(2.0)}
+    nativecall_test!{test_length_2, length, KaramelPrimative::Tuple(Rc::new(Vec::new())), KaramelPrimative::Number(0.0)}
+    nativecall_test!{test_list_1, list, KaramelPrimative::Tuple(Rc::new(vec![arc_number!(1), arc_number!(2)])), primative_list!(vec![arc_number!(1), arc_number!(2)])}
+}
@@ -0,0 +1,170 @@
+use crate::compiler::KaramelPrimative;
+
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum TextAlign {
+    Left,
+    Right,
+    Center
+}
+
+/*
+Format specifier for interpolated texts and 'gç::biçimlendir'.
+Syntax: [[doldur]hizala][0][genişlik][.hassasiyet]
+Example:
+    >10    : right aligned with 10 chars width
+    *^9    : centered with '*'
+    05     : number with zero padding
+    .2     : number with 2 decimal
+*/
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct TextFormat {
+    pub fill: char,
+    pub align: Option<TextAlign>,
+    pub zero_padding: bool,
+    pub width: usize,
+    pub precision: Option<usize>
+}
+
+impl Default for TextFormat {
+    fn default() -> Self {
+        TextFormat {
+            fill: ' ',
+            align: None,
+            zero_padding: false,
+            width: 0,
+            precision: None
+        }
+    }
+}
+
+fn get_align(ch: char) -> Option<TextAlign> {
+    match ch {
+        '<' => Some(TextAlign::Left),
+        '>' => Some(TextAlign::Right),
+        '^' => Some(TextAlign::Center),
+        _ => None
+    }
+}
+
+fn parse_number(chars: &[char], index: &mut usize) -> Option<usize> {
+    let start = *index;
+    while *index < chars.len() && chars[*index].is_ascii_digit() {
+        *index += 1;
+    }
+
+    match start == *index {
+        true => None,
+        false => chars[start..*index].iter().collect::<String>().parse::<usize>().ok()
+    }
+}
+
+impl TextFormat {
+    pub fn parse(format: &str) -> Option<TextFormat> {
+        let chars: Vec<char> = format.chars().collect();
+        let mut text_format = TextFormat::default();
+        let mut index = 0;
+
+        if chars.len() > 1 && get_align(chars[1]).is_some() {
+            text_format.fill  = chars[0];
+            text_format.align = get_align(chars[1]);
+            index = 2;
+        }
+        else if !chars.is_empty() && get_align(chars[0]).is_some() {
+            text_format.align = get_align(chars[0]);
+            index = 1;
+        }
+
+        if index < chars.len() && chars[index] == '0' {
+            text_format.zero_padding = true;
+            index += 1;
+        }
+
+        if let Some(width) = parse_number(&chars, &mut index) {
+            text_format.width = width;
+        }
+
+        if index < chars.len() && chars[index] == '.' {
+            index += 1;
+            text_format.precision = Some(parse_number(&chars, &mut index)?);
+        }
+
+        match index == chars.len() {
+            true => Some(text_format),
+            false => None
+        }
+    }
+
+    pub fn apply(&self, value: &KaramelPrimative) -> String {
+        let text = match (value, self.precision) {
+            (KaramelPrimative::Number(number), Some(precision)) => format!("{:.*}", precision, number),
+            (KaramelPrimative::Number(number), None) if number.fract() == 0.0 && number.abs() < 1e15 => format!("{}", *number as i64),
+            (KaramelPrimative::Text(text), _) => text.to_string(),
+            _ => value.to_string()
+        };
+
+        let length = text.chars().count();
+        if length >= self.width {
+            return text;
+        }
+
+        let padding = self.width - length;
+        let is_number = matches!(value, KaramelPrimative::Number(_));
+
+        /* Zero padding placed after the sign */
+        if self.zero_padding && self.align.is_none() && is_number {
+            return match text.strip_prefix('-') {
+                Some(number) => format!("-{}{}", "0".repeat(padding), number),
+                None => format!("{}{}", "0".repeat(padding), text)
+            };
+        }
+
+        let fill = self.fill.to_string();
+        let align = match self.align {
+            Some(align) => align,
+            None if is_number => TextAlign::Right,
+            None => TextAlign::Left
+        };
+
+        match align {
+            TextAlign::Left   => format!("{}{}", text, fill.repeat(padding)),
+            TextAlign::Right  => format!("{}{}", fill.repeat(padding), text),
+            TextAlign::Center => format!("{}{}{}", fill.repeat(padding / 2), text, fill.repeat(padding - (padding / 2)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::buildin::format::{TextAlign, TextFormat};
+    use crate::compiler::KaramelPrimative;
+
+    #[test]
+    fn format_parse() {
+        assert_eq!(TextFormat::parse(""), Some(TextFormat::default()));
+        assert_eq!(TextFormat::parse(">10").unwrap().align, Some(TextAlign::Right));
+        assert_eq!(TextFormat::parse("*^9.1").unwrap().fill, '*');
+        assert_eq!(TextFormat::parse("05").unwrap().zero_padding, true);
+        assert_eq!(TextFormat::parse(".2").unwrap().precision, Some(2));
+        assert_eq!(TextFormat::parse("."), None);
+        assert_eq!(TextFormat::parse("10x"), None);
+    }
+
+    #[test]
+    fn format_apply() {
+        let text = KaramelPrimative::Text(Rc::new("erhan".to_string()));
+        assert_eq!(TextFormat::parse("").unwrap().apply(&text), "erhan");
+        assert_eq!(TextFormat::parse("8").unwrap().apply(&text), "erhan   ");
+        assert_eq!(TextFormat::parse(">8").unwrap().apply(&text), "   erhan");
+        assert_eq!(TextFormat::parse("*^9").unwrap().apply(&text), "**erhan**");
+        assert_eq!(TextFormat::parse(".2").unwrap().apply(&KaramelPrimative::Number(3.14159)), "3.14");
+        assert_eq!(TextFormat::parse("5").unwrap().apply(&KaramelPrimative::Number(42.0)), "   42");
+        assert_eq!(TextFormat::parse("05").unwrap().apply(&KaramelPrimative::Number(-42.0)), "-0042");
+        assert_eq!(TextFormat::parse("<5").unwrap().apply(&KaramelPrimative::Bool(true)), "doğru");
+    }
+}
@@ -1,6 +1,8 @@
 use crate::compiler::{function::{FunctionParameter, FunctionReference, NativeCall, NativeCallResult}};
 use crate::types::{VmObject};
 use crate::compiler::value::EMPTY_OBJECT;
+use crate::compiler::KaramelPrimative;
+use crate::buildin::format::TextFormat;
 use crate::buildin::{Module, Class};
 use std::{cell::RefCell, collections::HashMap};
 use std::rc::Rc;
@@ -99,10 +101,22 @@ impl IoModule  {
     }
     
     pub fn format(parameter: FunctionParameter) -> NativeCallResult {
-        if parameter.length() != 1 {
-            return Ok(EMPTY_OBJECT);
+        match parameter.length() {
+            1 => Ok(VmObject::from(Rc::new(format!("{}", parameter.iter().next().unwrap().deref())))),
+            2 => {
+                let mut arguments = parameter.iter();
+                let value = arguments.next().unwrap().deref();
+                let text_format = match &*arguments.next().unwrap().deref() {
+                    KaramelPrimative::Text(format) => TextFormat::parse(format),
+                    _ => None
+                };
+
+                match text_format {
+                    Some(text_format) => Ok(VmObject::from(Rc::new(text_format.apply(&value)))),
+                    None => Ok(EMPTY_OBJECT)
+                }
+            },
+            _ => Ok(EMPTY_OBJECT)
         }
-
-        Ok(VmObject::from(Rc::new(format!("{}", parameter.iter().next().unwrap().deref()))))
     }
 }
@@ -2,15 +2,18 @@ pub mod debug;
 pub mod io;
 pub mod num;
 pub mod base_functions;
+pub mod format;
+pub mod task;
 
 use std::collections::hash_map::Iter;
 
 #[macro_use]
 pub mod class;
 
-use crate::{compiler::{GetType, function::{IndexerGetCall, IndexerSetCall, FunctionFlag}}, types::VmObject};
+use crate::{compiler::{GetType, function::{IndexerGetCall, IndexerSetCall, IndexerSliceCall, FunctionFlag, NativeCallResult}}, types::VmObject};
 
 use std::collections::HashMap;
+use std::cmp::Ordering;
 use std::vec::Vec;
 use std::rc::Rc;
 
@@ -58,6 +61,10 @@ impl ModuleCollection
     pub fn has_module(&self, module_path: &Vec<String>) -> bool {
         self.modules.iter().find_map(|(key, module)| if module.get_path() == module_path { Some(key) } else { None }).is_some()
     }
+
+    pub fn get_module(&self, module_path: &Vec<String>) -> Option<Rc<dyn Module>> {
+        self.modules.iter().find_map(|(_, module)| if module.get_path() == module_path { Some(module.clone()) } else { None })
+    }
 }
 
 impl<'a> Iterator for ModuleCollectionIterator<'a> {
@@ -88,7 +95,8 @@ pub struct ClassConfig {
 #[derive(Default)]
 pub struct Indexer {
     pub get: Option<IndexerGetCall>,
-    pub set: Option<IndexerSetCall>
+    pub set: Option<IndexerSetCall>,
+    pub slice: Option<IndexerSliceCall>
 }
 
 
@@ -109,6 +117,36 @@ pub trait Class: GetType {
     
     fn set_setter(&mut self, indexer: IndexerSetCall);
     fn get_setter(&self) -> Option<IndexerSetCall>;
+
+    /* 'kaynak[baş:son:adım]' support, missing positions are passed as None */
+    fn set_slicer(&mut self, _: IndexerSliceCall) {}
+    fn get_slicer(&self) -> Option<IndexerSliceCall> { None }
+
+    /* Field assignment, returns false if class is not accept new value */
+    fn set_element(&self, _: Option<VmObject>, _: Rc<String>, _: VmObject) -> bool { false }
+
+    /* Only user defined classes could create new instance */
+    fn create_instance(&self) -> Option<Rc<dyn Class>> { None }
+
+    /* Operator hooks, used when one of the operand is class. None means operator not supported */
+    fn addition(&self, _: VmObject, _: VmObject) -> Option<NativeCallResult> { None }
+    fn subtraction(&self, _: VmObject, _: VmObject) -> Option<NativeCallResult> { None }
+    fn multiply(&self, _: VmObject, _: VmObject) -> Option<NativeCallResult> { None }
+    fn division(&self, _: VmObject, _: VmObject) -> Option<NativeCallResult> { None }
+    fn modulo(&self, _: VmObject, _: VmObject) -> Option<NativeCallResult> { None }
+    fn compare(&self, _: VmObject, _: VmObject) -> Option<Ordering> { None }
+    fn equals(&self, _: &KaramelPrimative, _: &KaramelPrimative) -> Option<bool> { None }
+    fn to_text(&self) -> Option<String> { None }
+
+    /* 'içinde' operator support. None means class is searched with sequence hooks */
+    fn contains(&self, _: &KaramelPrimative) -> Option<bool> { None }
+
+    /* User defined 'içinde' support, method is called by VM with searched value */
+    fn get_contains_method(&self) -> Option<Rc<FunctionReference>> { None }
+
+    /* Sequence hooks, used by 'her' loop. None length means class is not iterable */
+    fn get_length(&self) -> Option<usize> { None }
+    fn get_item(&self, _: usize) -> Option<VmObject> { None }
 }
 
 pub struct DummyModule {
@@ -0,0 +1,228 @@
+use crate::compiler::context::KaramelCompilerContext;
+use crate::compiler::function::{FunctionFlag, FunctionReference, FunctionType, VmCall};
+use crate::compiler::value::EMPTY_OBJECT;
+use crate::compiler::KaramelPrimative;
+use crate::buildin::class::task::{TaskClass, TASK_CLASS_NAME, CHANNEL_CLASS_NAME};
+use crate::buildin::{Module, Class};
+use crate::error::KaramelErrorType;
+use crate::types::VmObject;
+use crate::vm::scheduler::{self, TaskState};
+use crate::{n_parameter_expected, expected_parameter_type};
+use std::{cell::RefCell, collections::HashMap};
+use std::rc::Rc;
+
+/* Cooperative tasks. Only one task runs at a time, it continues until it waits or gives the turn */
+pub struct TaskModule {
+    methods: RefCell<HashMap<String, Rc<FunctionReference>>>,
+    path: Vec<String>
+}
+
+impl Module for TaskModule {
+    fn get_module_name(&self) -> String {
+        "görev".to_string()
+    }
+
+    fn get_path(&self) -> &Vec<String> {
+        &self.path
+    }
+
+    fn get_method(&self, name: &str) -> Option<Rc<FunctionReference>> {
+        self.methods.borrow().get(name).cloned()
+    }
+
+    fn get_module(&self, _: &str) -> Option<Rc<dyn Module>> {
+        None
+    }
+
+    fn get_methods(&self) -> Vec<Rc<FunctionReference>> {
+        let mut response = Vec::new();
+        self.methods.borrow().iter().for_each(|(_, reference)| response.push(reference.clone()));
+        response
+    }
+
+    fn get_modules(&self) -> HashMap<String, Rc<dyn Module>> {
+        HashMap::new()
+    }
+
+    fn get_classes(&self) -> Vec<Rc<dyn Class>> {
+        Vec::new()
+    }
+}
+
+/* Arguments are removed from stack, VM functions push their own result */
+unsafe fn take_arguments(context: &mut KaramelCompilerContext) -> (Vec<VmObject>, bool) {
+    let total_args     = *context.opcodes_ptr.offset(1) as usize;
+    let assign_to_temp = *context.opcodes_ptr.offset(2) != 0;
+
+    let memory_index = get_memory_index!(context) as usize;
+    let stack = &(*context.current_scope).stack;
+    let arguments = stack[memory_index - total_args..memory_index].to_vec();
+    dec_memory_index!(context, total_args);
+    (arguments, assign_to_temp)
+}
+
+unsafe fn return_value(context: &mut KaramelCompilerContext, value: VmObject, assign_to_temp: bool) {
+    if assign_to_temp {
+        *(*context.current_scope).stack_ptr = value;
+        inc_memory_index!(context, 1);
+    }
+    context.opcodes_ptr = context.opcodes_ptr.offset(2);
+}
+
+impl TaskModule {
+    pub fn new() -> Rc<TaskModule> {
+        let module = TaskModule {
+            methods: RefCell::new(HashMap::new()),
+            path: vec!["görev".to_string()]
+        };
+
+        let rc_module = Rc::new(module);
+        rc_module.methods.borrow_mut().insert("başlat".to_string(), FunctionReference::vm_function(Self::spawn as VmCall, "başlat".to_string(), FunctionFlag::STATIC, rc_module.clone()));
+        rc_module.methods.borrow_mut().insert("bekle".to_string(), FunctionReference::vm_function(Self::wait as VmCall, "bekle".to_string(), FunctionFlag::STATIC, rc_module.clone()));
+        rc_module.methods.borrow_mut().insert("bırak".to_string(), FunctionReference::vm_function(Self::give_turn as VmCall, "bırak".to_string(), FunctionFlag::STATIC, rc_module.clone()));
+        rc_module.methods.borrow_mut().insert("kanal".to_string(), FunctionReference::vm_function(Self::channel as VmCall, "kanal".to_string(), FunctionFlag::STATIC, rc_module.clone()));
+        rc_module.clone()
+    }
+
+    /// 'görev::başlat(fonksiyon, argümanlar...)', function starts when current task waits
+    ///
+    /// # Safety
+    /// Called by VM while 'Call' opcode is executing, arguments are at the stack of the running scope.
+    pub unsafe fn spawn(_: &FunctionReference, context: &mut KaramelCompilerContext, _: Option<VmObject>) -> Result<(), KaramelErrorType> {
+        let (arguments, assign_to_temp) = take_arguments(context);
+        let function = match arguments.first() {
+            Some(function) => function.deref(),
+            None => {
+                context.keyword_arguments.clear();
+                return n_parameter_expected!("başlat".to_string(), 1);
+            }
+        };
+
+        let task_id = match &*function {
+            KaramelPrimative::Function(reference, base) if matches!(reference.callback, FunctionType::Opcode) => scheduler::create_task(context, reference.clone(), *base, &arguments[1..])?,
+            _ => {
+                context.keyword_arguments.clear();
+                return Err(KaramelErrorType::NotCallable(function.clone()));
+            }
+        };
+
+        return_value(context, TaskClass::task(task_id), assign_to_temp);
+        Ok(())
+    }
+
+    /// 'görev::bekle(görev)', returns result of the task. Error that is not caught in the task is raised again
+    ///
+    /// # Safety
+    /// Called by VM while 'Call' opcode is executing, running task could be switched before returning.
+    pub unsafe fn wait(_: &FunctionReference, context: &mut KaramelCompilerContext, _: Option<VmObject>) -> Result<(), KaramelErrorType> {
+        context.keyword_arguments.clear();
+        let (arguments, assign_to_temp) = take_arguments(context);
+        if arguments.len() != 1 {
+            return n_parameter_expected!("bekle".to_string(), 1, arguments.len() as u8);
+        }
+
+        let task_id = match TaskClass::get_id(&arguments[0].deref(), TASK_CLASS_NAME) {
+            Some(task_id) => task_id,
+            None => return expected_parameter_type!("bekle".to_string(), "Görev".to_string())
+        };
+
+        let task = &context.scheduler.tasks[task_id];
+        if task.state == TaskState::Finished {
+            let result = task.result.clone()?;
+            return_value(context, result, assign_to_temp);
+            return Ok(());
+        }
+
+        scheduler::can_wait(context)?;
+        let current = context.scheduler.current;
+        context.scheduler.tasks[task_id].waiters.push(current);
+        scheduler::wait_task(context, context.opcodes_ptr.offset(2), TaskState::Waiting, assign_to_temp)
+    }
+
+    /// 'görev::bırak()', other ready tasks run before current task continues
+    ///
+    /// # Safety
+    /// Called by VM while 'Call' opcode is executing, running task could be switched before returning.
+    pub unsafe fn give_turn(_: &FunctionReference, context: &mut KaramelCompilerContext, _: Option<VmObject>) -> Result<(), KaramelErrorType> {
+        context.keyword_arguments.clear();
+        let (arguments, assign_to_temp) = take_arguments(context);
+        if !arguments.is_empty() {
+            return n_parameter_expected!("bırak".to_string(), 0, arguments.len() as u8);
+        }
+
+        match context.scheduler.ready.is_empty() {
+            true => {
+                return_value(context, EMPTY_OBJECT, assign_to_temp);
+                Ok(())
+            },
+            false => scheduler::wait_task(context, context.opcodes_ptr.offset(2), TaskState::Ready, assign_to_temp)
+        }
+    }
+
+    /// 'görev::kanal()', values are sent with 'gönder' and received in order with 'al'
+    ///
+    /// # Safety
+    /// Called by VM while 'Call' opcode is executing, arguments are at the stack of the running scope.
+    pub unsafe fn channel(_: &FunctionReference, context: &mut KaramelCompilerContext, _: Option<VmObject>) -> Result<(), KaramelErrorType> {
+        context.keyword_arguments.clear();
+        let (arguments, assign_to_temp) = take_arguments(context);
+        if !arguments.is_empty() {
+            return n_parameter_expected!("kanal".to_string(), 0, arguments.len() as u8);
+        }
+
+        let channel_id = context.scheduler.create_channel();
+        return_value(context, TaskClass::channel(channel_id), assign_to_temp);
+        Ok(())
+    }
+
+    /// 'kanal.gönder(değer)', does not wait. Value goes to the first waiting receiver
+    ///
+    /// # Safety
+    /// Called by VM while 'Call' opcode is executing, arguments are at the stack of the running scope.
+    pub unsafe fn send(_: &FunctionReference, context: &mut KaramelCompilerContext, source: Option<VmObject>) -> Result<(), KaramelErrorType> {
+        context.keyword_arguments.clear();
+        let (arguments, assign_to_temp) = take_arguments(context);
+        if arguments.len() != 1 {
+            return n_parameter_expected!("gönder".to_string(), 1, arguments.len() as u8);
+        }
+
+        let channel_id = Self::get_channel(source)?;
+        match context.scheduler.channels[channel_id].receivers.pop_front() {
+            Some(receiver) => context.scheduler.wake(receiver, Ok(arguments[0])),
+            None => context.scheduler.channels[channel_id].values.push_back(arguments[0])
+        };
+
+        return_value(context, EMPTY_OBJECT, assign_to_temp);
+        Ok(())
+    }
+
+    /// 'kanal.al()', waits until a value is sent if channel is empty
+    ///
+    /// # Safety
+    /// Called by VM while 'Call' opcode is executing, running task could be switched before returning.
+    pub unsafe fn receive(_: &FunctionReference, context: &mut KaramelCompilerContext, source: Option<VmObject>) -> Result<(), KaramelErrorType> {
+        context.keyword_arguments.clear();
+        let (arguments, assign_to_temp) = take_arguments(context);
+        if !arguments.is_empty() {
+            return n_parameter_expected!("al".to_string(), 0, arguments.len() as u8);
+        }
+
+        let channel_id = Self::get_channel(source)?;
+        if let Some(value) = context.scheduler.channels[channel_id].values.pop_front() {
+            return_value(context, value, assign_to_temp);
+            return Ok(());
+        }
+
+        scheduler::can_wait(context)?;
+        let current = context.scheduler.current;
+        context.scheduler.channels[channel_id].receivers.push_back(current);
+        scheduler::wait_task(context, context.opcodes_ptr.offset(2), TaskState::Waiting, assign_to_temp)
+    }
+
+    fn get_channel(source: Option<VmObject>) -> Result<usize, KaramelErrorType> {
+        match source.and_then(|source| TaskClass::get_id(&source.deref(), CHANNEL_CLASS_NAME)) {
+            Some(channel_id) => Ok(channel_id),
+            None => Err(KaramelErrorType::FunctionNotFound("al".to_string()))
+        }
+    }
+}
@@ -3,7 +3,10 @@ use std::vec::Vec;
 use std::rc::Rc;
 
 use crate::compiler::value::KaramelPrimative;
+use crate::compiler::pattern::LoweredPattern;
 use crate::syntax::loops::LoopType;
+use crate::syntax::pattern_match::MatchParser;
+use crate::syntax::comprehension::ComprehensionParser;
 use crate::types::KaramelOperatorType;
 
 #[repr(C)]
@@ -24,6 +27,51 @@ pub struct KaramelDictItem {
     pub value: Rc<KaramelAstType>
 }
 
+/* Pattern of 'eşleştir' arm */
+#[repr(C)]
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum KaramelPattern {
+    /* '_' matches everything */
+    Any,
+    Binding(String),
+    Value(Rc<KaramelAstType>),
+    Range {
+        start: Rc<KaramelAstType>,
+        end: Rc<KaramelAstType>
+    },
+    Type(String),
+    List {
+        items: Vec<KaramelPattern>,
+
+        /* '...kalan' collects remaining items */
+        rest: Option<String>
+    },
+    Dict(Vec<(Rc<KaramelPrimative>, KaramelPattern)>)
+}
+
+#[repr(C)]
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct KaramelMatchArm {
+    pub pattern: KaramelPattern,
+    pub guard: Option<Rc<KaramelAstType>>,
+    pub body: Rc<KaramelAstType>
+}
+
+/* Optional types of 'fonk topla(a: sayı, b: sayı) -> sayı', None means type is not written */
+#[repr(C)]
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Default)]
+pub struct KaramelFunctionType {
+    pub arguments: Vec<Option<String>>,
+    pub result: Option<String>
+}
+
 impl KaramelIfStatementElseItem {
     pub fn new(condition: Rc<KaramelAstType>, body: Rc<KaramelAstType>) -> KaramelIfStatementElseItem {
         KaramelIfStatementElseItem {
@@ -55,7 +103,11 @@ pub enum KaramelAstType {
     Binary {
         left: Rc<KaramelAstType>, 
         operator: KaramelOperatorType, 
-        right: Rc<KaramelAstType>
+        right: Rc<KaramelAstType>,
+
+        /* Start of the expression, only filled for type checker */
+        line: u32,
+        column: u32
     },
     Control {
         left: Rc<KaramelAstType>, 
@@ -69,6 +121,12 @@ pub enum KaramelAstType {
         assign_to_temp: Cell<bool>
     },
     SuffixUnary(KaramelOperatorType, Rc<KaramelAstType>),
+
+    /* 'ad: sayı = 10', type is only used by type checker */
+    TypedAssignment {
+        type_name: String,
+        assignment: Rc<KaramelAstType>
+    },
     Assignment {
         variable: Rc<KaramelAstType>,
         operator: KaramelOperatorType,
@@ -83,19 +141,189 @@ pub enum KaramelAstType {
     FunctionDefination {
         name: String,
         arguments: Vec<String>,
+
+        /* Default values of the last arguments */
+        defaults: Vec<Rc<KaramelAstType>>,
+
+        /* Last argument collects remaining values as a list */
+        variadic: bool,
+        types: KaramelFunctionType,
         body: Rc<KaramelAstType>
     },
+    ClassDefination {
+        name: String,
+        fields: Vec<Rc<KaramelAstType>>,
+        methods: Vec<Rc<KaramelAstType>>
+    },
+
+    /* Function defination that used as expression */
+    Lambda(Rc<KaramelAstType>),
     Symbol(String),
     ModulePath(Vec<String>),
     Load(Vec<String>),
+    Use(Vec<String>),
     List(Vec<Rc<KaramelAstType>>),
+    Tuple(Vec<Rc<KaramelAstType>>),
+    Set(Vec<Rc<KaramelAstType>>),
     Dict(Vec<Rc<KaramelDictItem>>),
     Indexer { body: Rc<KaramelAstType>, indexer: Rc<KaramelAstType> },
+    Slice { body: Rc<KaramelAstType>, start: Option<Rc<KaramelAstType>>, end: Option<Rc<KaramelAstType>>, step: Option<Rc<KaramelAstType>> },
     Return(Rc<KaramelAstType>),
+    Yield(Rc<KaramelAstType>),
     Break,
     Continue,
     Loop {
         loop_type: LoopType,
         body: Rc<KaramelAstType>
+    },
+    Try {
+        id: usize,
+        body: Rc<KaramelAstType>,
+        catch_variable: Option<String>,
+        catch_body: Option<Rc<KaramelAstType>>,
+        finally_body: Option<Rc<KaramelAstType>>
+    },
+    Throw(Rc<KaramelAstType>),
+
+    Comprehension {
+        id: usize,
+        key: Option<Rc<KaramelAstType>>,
+        value: Rc<KaramelAstType>,
+        variables: Vec<String>,
+        iterable: Rc<KaramelAstType>,
+        condition: Option<Rc<KaramelAstType>>
+    },
+    ComprehensionItem {
+        id: usize,
+        key: Option<Rc<KaramelAstType>>,
+        value: Rc<KaramelAstType>
+    },
+
+    Match {
+        id: usize,
+        line: u32,
+        column: u32,
+        value: Rc<KaramelAstType>,
+        arms: Vec<Rc<KaramelMatchArm>>,
+        default: Option<Rc<KaramelAstType>>
+    },
+
+    /* 'koşul ? a : b' or 'a eğer koşul yoksa b', only selected expression evaluated */
+    Conditional {
+        condition: Rc<KaramelAstType>,
+        true_expression: Rc<KaramelAstType>,
+        false_expression: Rc<KaramelAstType>
+    },
+
+    /* 'ad=değer' argument at function call */
+    KeywordArgument {
+        name: String,
+        expression: Rc<KaramelAstType>
+    },
+
+    /* Interpolated text, parts are concatenated */
+    TextTemplate(Vec<Rc<KaramelAstType>>),
+    TextFormat {
+        expression: Rc<KaramelAstType>,
+        format: Rc<String>
+    },
+
+    /* Start of the next statement, only added for type checker */
+    Location {
+        line: u32,
+        column: u32
+    }
+}
+
+impl KaramelAstType {
+    /// Inner statements and expressions. Function and class bodies are not included.
+    pub fn children(&self) -> Vec<Rc<KaramelAstType>> {
+        match self {
+            KaramelAstType::Block(items) | KaramelAstType::List(items) | KaramelAstType::Tuple(items) | KaramelAstType::Set(items) | KaramelAstType::TextTemplate(items) => items.to_vec(),
+            KaramelAstType::Dict(items) => items.iter().map(|item| item.value.clone()).collect(),
+            KaramelAstType::FuncCall { func_name_expression, arguments, assign_to_temp: _ } => {
+                let mut children = vec![func_name_expression.clone()];
+                children.extend(arguments.iter().cloned());
+                children
+            },
+            KaramelAstType::AccessorFuncCall { source, indexer, assign_to_temp: _ } => vec![source.clone(), indexer.clone()],
+            KaramelAstType::Binary { left, right, .. } |
+            KaramelAstType::Control { left, operator: _, right } => vec![left.clone(), right.clone()],
+            KaramelAstType::PrefixUnary { operator: _, expression, assign_to_temp: _ } |
+            KaramelAstType::SuffixUnary(_, expression) |
+            KaramelAstType::Return(expression) |
+            KaramelAstType::Yield(expression) |
+            KaramelAstType::Throw(expression) |
+            KaramelAstType::TextFormat { expression, format: _ } |
+            KaramelAstType::KeywordArgument { name: _, expression } => vec![expression.clone()],
+            KaramelAstType::Assignment { variable, operator: _, expression } => vec![variable.clone(), expression.clone()],
+            KaramelAstType::TypedAssignment { type_name: _, assignment } => vec![assignment.clone()],
+            KaramelAstType::Indexer { body, indexer } => vec![body.clone(), indexer.clone()],
+            KaramelAstType::Slice { body, start, end, step } => {
+                let mut children = vec![body.clone()];
+                children.extend(start.iter().chain(end.iter()).chain(step.iter()).cloned());
+                children
+            },
+            KaramelAstType::Conditional { condition, true_expression, false_expression } => vec![condition.clone(), true_expression.clone(), false_expression.clone()],
+            KaramelAstType::IfStatement { condition, body, else_body, else_if } => {
+                let mut children = vec![condition.clone(), body.clone()];
+                for item in else_if {
+                    children.push(item.condition.clone());
+                    children.push(item.body.clone());
+                }
+
+                if let Some(else_body) = else_body {
+                    children.push(else_body.clone());
+                }
+                children
+            },
+            KaramelAstType::Comprehension { id, key, value, variables, iterable, condition } => vec![
+                Rc::new(ComprehensionParser::get_result_assignment(*id, key.is_some())),
+                Rc::new(ComprehensionParser::get_loop(*id, key, value, variables, iterable, condition))
+            ],
+            KaramelAstType::ComprehensionItem { id, key, value } => {
+                let mut children = vec![Rc::new(KaramelAstType::Symbol(ComprehensionParser::get_result_variable(*id)))];
+                children.extend(key.iter().cloned());
+                children.push(value.clone());
+                children
+            },
+            KaramelAstType::Match { id, value, arms, default, .. } => {
+                let subject = Rc::new(KaramelAstType::Symbol(MatchParser::get_value_variable(*id)));
+                let mut children = vec![value.clone()];
+                for arm in arms {
+                    let lowered = LoweredPattern::new(&arm.pattern, subject.clone());
+                    children.extend(lowered.checks);
+                    children.extend(lowered.bindings);
+                    children.extend(arm.guard.iter().cloned());
+                    children.push(arm.body.clone());
+                }
+                children.extend(default.iter().cloned());
+                children
+            },
+            KaramelAstType::Loop { loop_type, body } => {
+                let mut children = match loop_type {
+                    LoopType::Simple(control) => vec![control.clone()],
+                    LoopType::Scalar { variable, control, increment } => vec![variable.clone(), control.clone(), increment.clone()],
+                    LoopType::ForEach { id: _, variables: _, iterable } => vec![iterable.clone()],
+                    LoopType::Range { id: _, variable: _, start, end, step } => {
+                        let mut children = vec![start.clone(), end.clone()];
+                        if let Some(step) = step {
+                            children.push(step.clone());
+                        }
+                        children
+                    },
+                    LoopType::Endless => Vec::new()
+                };
+                children.push(body.clone());
+                children
+            },
+            KaramelAstType::Try { id: _, body, catch_variable: _, catch_body, finally_body } => {
+                let mut children = vec![body.clone()];
+                children.extend(catch_body.iter().cloned());
+                children.extend(finally_body.iter().cloned());
+                children
+            },
+            _ => Vec::new()
+        }
     }
 }
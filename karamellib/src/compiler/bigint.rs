@@ -0,0 +1,603 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::compiler::value::KaramelPrimative;
+use crate::types::VmObject;
+
+/* Largest integer that f64 keeps exact, integers outside of this range are stored as 'BigInt' */
+pub const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+const DECIMAL_BASE: u32 = 1_000_000_000;
+
+/* Power and left shift results larger than this are calculated as float */
+const MAX_RESULT_BITS: u64 = 1 << 18;
+
+/// Arbitrary-precision integer. Magnitude is kept in 32 bit digits, least significant digit first and without leading zeros.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    digits: Vec<u32>
+}
+
+impl BigInt {
+    fn new(negative: bool, mut digits: Vec<u32>) -> BigInt {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        BigInt { negative: negative && !digits.is_empty(), digits }
+    }
+
+    pub fn zero() -> BigInt {
+        BigInt::new(false, Vec::new())
+    }
+
+    pub fn from_u64(value: u64) -> BigInt {
+        BigInt::new(false, vec![value as u32, (value >> 32) as u32])
+    }
+
+    pub fn from_i64(value: i64) -> BigInt {
+        BigInt::new(value < 0, BigInt::from_u64(value.unsigned_abs()).digits)
+    }
+
+    /* Only integral values could be converted, conversion is exact */
+    pub fn from_f64(value: f64) -> Option<BigInt> {
+        if !value.is_finite() || value.fract() != 0.0 {
+            return None;
+        }
+
+        if value == 0.0 {
+            return Some(BigInt::zero());
+        }
+
+        /* Integral values are normal numbers, value is 'mantissa * 2^(exponent - 1075)' */
+        let bits     = value.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i64 - 1075;
+        let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+
+        let magnitude = match exponent >= 0 {
+            true  => BigInt::from_u64(mantissa).shl(exponent as usize),
+            false => BigInt::from_u64(mantissa >> -exponent)
+        };
+        Some(BigInt::new(value < 0.0, magnitude.digits))
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let magnitude = self.digits.iter().rev().fold(0.0, |total, digit| total * 4_294_967_296.0 + *digit as f64);
+        match self.negative {
+            true  => -magnitude,
+            false => magnitude
+        }
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.digits.len() > 2 {
+            return None;
+        }
+
+        let magnitude = self.digits.iter().rev().fold(0_u64, |total, digit| (total << 32) | *digit as u64);
+        match self.negative {
+            true if magnitude <= i64::MIN.unsigned_abs() => Some((magnitude as i64).wrapping_neg()),
+            false if magnitude <= i64::MAX as u64 => Some(magnitude as i64),
+            _ => None
+        }
+    }
+
+    pub fn bits(&self) -> usize {
+        match self.digits.last() {
+            Some(last) => self.digits.len() * 32 - last.leading_zeros() as usize,
+            None => 0
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn neg(&self) -> BigInt {
+        BigInt::new(!self.negative, self.digits.clone())
+    }
+
+    pub fn abs(&self) -> BigInt {
+        BigInt::new(false, self.digits.clone())
+    }
+
+    pub fn add(&self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(self.negative, add_digits(&self.digits, &other.digits));
+        }
+
+        match compare_digits(&self.digits, &other.digits) {
+            Ordering::Less => BigInt::new(other.negative, sub_digits(&other.digits, &self.digits)),
+            _ => BigInt::new(self.negative, sub_digits(&self.digits, &other.digits))
+        }
+    }
+
+    pub fn sub(&self, other: &BigInt) -> BigInt {
+        self.add(&other.neg())
+    }
+
+    pub fn mul(&self, other: &BigInt) -> BigInt {
+        let mut result = vec![0_u32; self.digits.len() + other.digits.len()];
+        for (left_index, left) in self.digits.iter().enumerate() {
+            let mut carry = 0_u64;
+            for (right_index, right) in other.digits.iter().enumerate() {
+                let total = result[left_index + right_index] as u64 + *left as u64 * *right as u64 + carry;
+                result[left_index + right_index] = total as u32;
+                carry = total >> 32;
+            }
+            result[left_index + other.digits.len()] = carry as u32;
+        }
+
+        BigInt::new(self.negative != other.negative, result)
+    }
+
+    /* Quotient is truncated and remainder has the sign of the dividend, same with f64 '%'. None for division by zero */
+    pub fn div_rem(&self, other: &BigInt) -> Option<(BigInt, BigInt)> {
+        if other.is_zero() {
+            return None;
+        }
+
+        let (quotient, remainder) = match other.digits.len() {
+            1 => {
+                let (quotient, remainder) = div_rem_digit(&self.digits, other.digits[0]);
+                (quotient, vec![remainder])
+            },
+            _ => div_rem_digits(&self.digits, &other.digits)
+        };
+
+        Some((BigInt::new(self.negative != other.negative, quotient), BigInt::new(self.negative, remainder)))
+    }
+
+    /* Magnitude is shifted, sign does not change */
+    pub fn shl(&self, bits: usize) -> BigInt {
+        let mut digits = vec![0_u32; bits / 32];
+        let shift = bits % 32;
+        let mut carry = 0_u32;
+
+        for digit in self.digits.iter() {
+            match shift {
+                0 => digits.push(*digit),
+                _ => {
+                    digits.push((*digit << shift) | carry);
+                    carry = *digit >> (32 - shift);
+                }
+            }
+        }
+
+        digits.push(carry);
+        BigInt::new(self.negative, digits)
+    }
+
+    /* Rounds toward negative infinity like integer division by power of two */
+    pub fn shr(&self, bits: usize) -> BigInt {
+        match self.negative {
+            false => BigInt::new(false, shr_digits(&self.digits, bits)),
+            true => {
+                let one = BigInt::from_u64(1);
+                BigInt::new(false, shr_digits(&self.abs().sub(&one).digits, bits)).add(&one).neg()
+            }
+        }
+    }
+
+    pub fn pow(&self, mut exponent: u32) -> BigInt {
+        let mut result = BigInt::from_u64(1);
+        let mut base = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul(&base);
+            }
+
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.mul(&base);
+            }
+        }
+        result
+    }
+
+    /* Bit operators use two's complement, negative numbers have infinite leading ones */
+    pub fn bitand(&self, other: &BigInt) -> BigInt {
+        self.bitwise(other, |left, right| left & right)
+    }
+
+    pub fn bitor(&self, other: &BigInt) -> BigInt {
+        self.bitwise(other, |left, right| left | right)
+    }
+
+    pub fn bitxor(&self, other: &BigInt) -> BigInt {
+        self.bitwise(other, |left, right| left ^ right)
+    }
+
+    /* '~x' is '-(x + 1)' */
+    pub fn not(&self) -> BigInt {
+        self.add(&BigInt::from_u64(1)).neg()
+    }
+
+    fn bitwise(&self, other: &BigInt, operator: fn(u32, u32) -> u32) -> BigInt {
+        let length = self.digits.len().max(other.digits.len()) + 1;
+        let left = self.to_twos_complement(length);
+        let right = other.to_twos_complement(length);
+        BigInt::from_twos_complement(left.iter().zip(right.iter()).map(|(left, right)| operator(*left, *right)).collect())
+    }
+
+    /* Length should have a free digit for the sign */
+    fn to_twos_complement(&self, length: usize) -> Vec<u32> {
+        let mut digits = self.digits.clone();
+        digits.resize(length, 0);
+        if self.negative {
+            negate_digits(&mut digits);
+        }
+        digits
+    }
+
+    fn from_twos_complement(mut digits: Vec<u32>) -> BigInt {
+        let negative = digits.last().is_some_and(|last| last >> 31 == 1);
+        if negative {
+            negate_digits(&mut digits);
+        }
+        BigInt::new(negative, digits)
+    }
+
+    pub fn to_hex(&self) -> String {
+        let mut text = match self.negative {
+            true  => "-0x".to_string(),
+            false => "0x".to_string()
+        };
+
+        match self.digits.split_last() {
+            Some((last, rest)) => {
+                text.push_str(&format!("{:x}", last));
+                rest.iter().rev().for_each(|digit| text.push_str(&format!("{:08x}", digit)));
+            },
+            None => text.push('0')
+        };
+        text
+    }
+
+    /* Values in the safe range are converted back to number */
+    pub fn to_primative(self) -> KaramelPrimative {
+        let value = self.to_f64();
+        match value.abs() <= MAX_SAFE_INTEGER {
+            true  => KaramelPrimative::Number(value),
+            false => KaramelPrimative::BigInt(Rc::new(self))
+        }
+    }
+
+    pub fn to_object(self) -> VmObject {
+        VmObject::native_convert(self.to_primative())
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_digits(&self.digits, &other.digits),
+            (true, true) => compare_digits(&other.digits, &self.digits)
+        }
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        let mut digits = self.digits.clone();
+        while !digits.is_empty() {
+            let (quotient, remainder) = div_rem_digit(&digits, DECIMAL_BASE);
+            parts.push(remainder);
+            digits = BigInt::new(false, quotient).digits;
+        }
+
+        if self.negative {
+            write!(f, "-")?;
+        }
+
+        match parts.split_last() {
+            Some((last, rest)) => {
+                write!(f, "{}", last)?;
+                rest.iter().rev().try_for_each(|part| write!(f, "{:09}", part))
+            },
+            None => write!(f, "0")
+        }
+    }
+}
+
+fn compare_digits(left: &[u32], right: &[u32]) -> Ordering {
+    left.len().cmp(&right.len()).then_with(|| left.iter().rev().cmp(right.iter().rev()))
+}
+
+fn add_digits(left: &[u32], right: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(left.len().max(right.len()) + 1);
+    let mut carry = 0_u64;
+    for index in 0..left.len().max(right.len()) {
+        let total = *left.get(index).unwrap_or(&0) as u64 + *right.get(index).unwrap_or(&0) as u64 + carry;
+        result.push(total as u32);
+        carry = total >> 32;
+    }
+
+    result.push(carry as u32);
+    result
+}
+
+/* Left should not be smaller than right */
+fn sub_digits(left: &[u32], right: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(left.len());
+    let mut borrow = 0_i64;
+    for (index, digit) in left.iter().enumerate() {
+        let mut total = *digit as i64 - *right.get(index).unwrap_or(&0) as i64 - borrow;
+        borrow = 0;
+        if total < 0 {
+            total += 1 << 32;
+            borrow = 1;
+        }
+        result.push(total as u32);
+    }
+
+    result
+}
+
+fn shr_digits(digits: &[u32], bits: usize) -> Vec<u32> {
+    let rest = match digits.get(bits / 32..) {
+        Some(rest) => rest,
+        None => return Vec::new()
+    };
+
+    let shift = bits % 32;
+    rest.iter().enumerate().map(|(index, digit)| match shift {
+        0 => *digit,
+        _ => (*digit >> shift) | (rest.get(index + 1).unwrap_or(&0) << (32 - shift))
+    }).collect()
+}
+
+/* Two's complement negation, digits are inverted and one is added */
+fn negate_digits(digits: &mut [u32]) {
+    let mut carry = 1_u64;
+    for digit in digits.iter_mut() {
+        let total = (!*digit) as u64 + carry;
+        *digit = total as u32;
+        carry = total >> 32;
+    }
+}
+
+fn div_rem_digit(digits: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0_u32; digits.len()];
+    let mut remainder = 0_u64;
+    for (index, digit) in digits.iter().enumerate().rev() {
+        let current = (remainder << 32) | *digit as u64;
+        quotient[index] = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+
+    (quotient, remainder as u32)
+}
+
+/* Long division bit by bit, divisor has more than one digit */
+fn div_rem_digits(digits: &[u32], divisor: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let mut quotient = vec![0_u32; digits.len()];
+    let mut remainder: Vec<u32> = Vec::new();
+
+    for bit in (0..digits.len() * 32).rev() {
+        remainder = BigInt::new(false, remainder).shl(1).digits;
+        if (digits[bit / 32] >> (bit % 32)) & 1 == 1 {
+            match remainder.first_mut() {
+                Some(first) => *first |= 1,
+                None => remainder.push(1)
+            };
+        }
+
+        if compare_digits(&remainder, divisor) != Ordering::Less {
+            remainder = BigInt::new(false, sub_digits(&remainder, divisor)).digits;
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+
+    (quotient, remainder)
+}
+
+/* Integral numbers in the safe range are exact, calculations with them do not lose precision */
+pub fn is_safe_integer(value: f64) -> bool {
+    value.fract() == 0.0 && value.abs() <= MAX_SAFE_INTEGER
+}
+
+/* f64 result of two exact integers could be rounded, calculation should be repeated with big integers */
+pub fn is_exact_result(left: f64, right: f64, result: f64) -> bool {
+    result.abs() <= MAX_SAFE_INTEGER || !is_safe_integer(left) || !is_safe_integer(right)
+}
+
+/* Result of the unboxed numbers, it stays float if one of the operands is float */
+pub fn number_result(left: VmObject, right: VmObject, result: f64) -> VmObject {
+    match left.is_integer() && right.is_integer() {
+        true => VmObject::from(result),
+        false => VmObject::float(result)
+    }
+}
+
+pub fn to_f64(value: &KaramelPrimative) -> Option<f64> {
+    match value {
+        KaramelPrimative::Number(number) | KaramelPrimative::Float(number) => Some(*number),
+        KaramelPrimative::BigInt(number) => Some(number.to_f64()),
+        _ => None
+    }
+}
+
+/* Exact value of an integral number, large floats are converted without rounding */
+pub fn to_integer(value: &KaramelPrimative) -> Option<BigInt> {
+    match value {
+        KaramelPrimative::Number(number) | KaramelPrimative::Float(number) => BigInt::from_f64(*number),
+        KaramelPrimative::BigInt(number) => Some((**number).clone()),
+        _ => None
+    }
+}
+
+/// Calculation of two numbers. Integers are calculated without precision loss, float is used if one of the operands is float or 'exact' does not have a result.
+pub fn calculate(left: VmObject, right: VmObject, float: fn(f64, f64) -> f64, exact: fn(&BigInt, &BigInt) -> Option<BigInt>) -> Option<VmObject> {
+    let (l_primative, r_primative) = (left.deref(), right.deref());
+    let (l_value, r_value) = (to_f64(&l_primative)?, to_f64(&r_primative)?);
+    if left.is_integer() && right.is_integer() {
+        if let Some(result) = exact(&to_integer(&l_primative)?, &to_integer(&r_primative)?) {
+            return Some(result.to_object());
+        }
+    }
+
+    Some(VmObject::float(float(l_value, r_value)))
+}
+
+/* Integral numbers in the safe range and big integers, floats are not included */
+pub fn exact_integer(value: VmObject) -> Option<BigInt> {
+    match value.is_integer() {
+        true => to_integer(&value.deref()),
+        false => None
+    }
+}
+
+/// Calculation that is defined only for integers, like bit operators.
+pub fn calculate_integer(left: VmObject, right: VmObject, exact: fn(&BigInt, &BigInt) -> Option<BigInt>) -> Option<VmObject> {
+    exact(&exact_integer(left)?, &exact_integer(right)?).map(|result| result.to_object())
+}
+
+/* Negative exponent and very large results are calculated as float */
+pub fn power(base: &BigInt, exponent: &BigInt) -> Option<BigInt> {
+    let exponent = exponent.to_i64().filter(|exponent| *exponent >= 0)? as u64;
+    match exponent <= MAX_RESULT_BITS && (base.bits() as u64).saturating_mul(exponent) <= MAX_RESULT_BITS {
+        true => Some(base.pow(exponent as u32)),
+        false => None
+    }
+}
+
+/* Quotient is rounded toward negative infinity */
+pub fn floor_division(left: &BigInt, right: &BigInt) -> Option<BigInt> {
+    let (quotient, remainder) = left.div_rem(right)?;
+    match !remainder.is_zero() && left.is_negative() != right.is_negative() {
+        true => Some(quotient.sub(&BigInt::from_u64(1))),
+        false => Some(quotient)
+    }
+}
+
+pub fn shift_left(value: &BigInt, bits: &BigInt) -> Option<BigInt> {
+    let bits = bits.to_i64().filter(|bits| *bits >= 0)? as u64;
+    match (value.bits() as u64).saturating_add(bits) <= MAX_RESULT_BITS {
+        true => Some(value.shl(bits as usize)),
+        false => None
+    }
+}
+
+pub fn shift_right(value: &BigInt, bits: &BigInt) -> Option<BigInt> {
+    let bits = bits.to_i64().filter(|bits| *bits >= 0)? as u64;
+    Some(value.shr(bits.min(value.bits() as u64 + 1) as usize))
+}
+
+/* Numbers are compared with their exact values */
+pub fn compare(left: &KaramelPrimative, right: &KaramelPrimative) -> Option<Ordering> {
+    match (to_integer(left), to_integer(right)) {
+        (Some(left), Some(right)) => Some(left.cmp(&right)),
+        _ => to_f64(left)?.partial_cmp(&to_f64(right)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use crate::compiler::bigint::{BigInt, compare, power, floor_division};
+    use crate::compiler::value::KaramelPrimative;
+
+    fn parse(text: &str) -> BigInt {
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, text)
+        };
+
+        let ten = BigInt::from_u64(10);
+        let value = digits.chars().fold(BigInt::zero(), |total, digit| total.mul(&ten).add(&BigInt::from_u64(digit.to_digit(10).unwrap() as u64)));
+        match negative {
+            true => value.neg(),
+            false => value
+        }
+    }
+
+    #[test]
+    fn bigint_text_1() {
+        assert_eq!(parse("123456789012345678901234567890").to_string(), "123456789012345678901234567890");
+        assert_eq!(parse("-1000000000000000000000").to_string(), "-1000000000000000000000");
+        assert_eq!(BigInt::zero().to_string(), "0");
+        assert_eq!(parse("-0").to_string(), "0");
+        assert_eq!(parse("18446744073709551616").to_hex(), "0x10000000000000000");
+        assert_eq!(parse("-255").to_hex(), "-0xff");
+    }
+
+    #[test]
+    fn bigint_arithmetic_1() {
+        let left = parse("99999999999999999999");
+        let right = parse("-12345678901234567890");
+        assert_eq!(left.add(&right).to_string(), "87654321098765432109");
+        assert_eq!(left.sub(&right).to_string(), "112345678901234567889");
+        assert_eq!(left.mul(&right).to_string(), "-1234567890123456788987654321098765432110");
+        assert_eq!(right.sub(&right), BigInt::zero());
+    }
+
+    #[test]
+    fn bigint_div_rem_1() {
+        let (quotient, remainder) = parse("1234567890123456789012345").div_rem(&parse("-98765432109876")).unwrap();
+        assert_eq!(quotient.to_string(), "-12499999886");
+        assert_eq!(remainder.to_string(), "9266049538209");
+        let (quotient, remainder) = parse("-7").div_rem(&parse("2")).unwrap();
+        assert_eq!((quotient.to_i64(), remainder.to_i64()), (Some(-3), Some(-1)));
+        assert!(parse("7").div_rem(&BigInt::zero()).is_none());
+    }
+
+    #[test]
+    fn bigint_bitwise_1() {
+        assert_eq!(parse("-12").bitand(&parse("10")), parse("0"));
+        assert_eq!(parse("-12").bitor(&parse("10")), parse("-2"));
+        assert_eq!(parse("-12").bitxor(&parse("10")), parse("-2"));
+        assert_eq!(parse("36893488147419103231").bitand(&parse("-4294967296")), parse("36893488143124135936"));
+        assert_eq!(parse("5").not(), parse("-6"));
+        assert_eq!(parse("-7").shr(1), parse("-4"));
+        assert_eq!(parse("-1").shr(100), parse("-1"));
+        assert_eq!(parse("12345678901234567890").shr(33), parse("1437226182"));
+        assert_eq!(parse("-3").shl(70), parse("-3541774862152233910272"));
+    }
+
+    #[test]
+    fn bigint_power_1() {
+        assert_eq!(power(&parse("3"), &parse("50")), Some(parse("717897987691852588770249")));
+        assert_eq!(power(&parse("-2"), &parse("3")), Some(parse("-8")));
+        assert_eq!(power(&parse("2"), &parse("-1")), None);
+        assert_eq!(power(&parse("2"), &parse("10000000")), None);
+        assert_eq!(floor_division(&parse("-7"), &parse("2")), Some(parse("-4")));
+        assert_eq!(floor_division(&parse("7"), &parse("2")), Some(parse("3")));
+        assert_eq!(floor_division(&parse("7"), &parse("0")), None);
+    }
+
+    #[test]
+    fn bigint_convert_1() {
+        assert_eq!(BigInt::from_f64(1e20).unwrap().to_string(), "100000000000000000000");
+        assert_eq!(BigInt::from_f64(-3.0), Some(BigInt::from_i64(-3)));
+        assert_eq!(BigInt::from_f64(1.5), None);
+        assert_eq!(BigInt::from_i64(i64::MIN).to_i64(), Some(i64::MIN));
+        assert_eq!(parse("9223372036854775808").to_i64(), None);
+        assert_eq!(parse("9007199254740993").to_f64(), 9007199254740992.0);
+    }
+
+    #[test]
+    fn bigint_primative_1() {
+        assert_eq!(BigInt::from_i64(10).to_primative(), KaramelPrimative::Number(10.0));
+        assert!(matches!(parse("9007199254740992").to_primative(), KaramelPrimative::BigInt(_)));
+        assert_eq!(compare(&parse("9007199254740993").to_primative(), &KaramelPrimative::Number(9007199254740992.0)), Some(Ordering::Greater));
+        assert_eq!(compare(&KaramelPrimative::Number(1.5), &KaramelPrimative::Number(2.0)), Some(Ordering::Less));
+    }
+}
@@ -0,0 +1,204 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::buildin::{Class, ClassConfig, ClassProperty};
+use crate::compiler::function::{FunctionFlag, FunctionReference, IndexerGetCall, IndexerSetCall, NativeCall};
+use crate::compiler::{GetType, KaramelPrimative};
+use crate::types::VmObject;
+
+pub const CONSTRUCTOR_NAME: &str = "başlat";
+pub const CONSTRUCTOR_ASCII_NAME: &str = "baslat";
+pub const INSTANCE_NAME: &str = "bu";
+pub const CONTAINS_NAME: &str = "içeriyormu";
+pub const CONTAINS_ASCII_NAME: &str = "iceriyormu";
+
+/* User defined class, created with 'sınıf' keyword */
+pub struct OpcodeClass {
+    name: String,
+    properties: Rc<HashMap<String, ClassProperty>>
+}
+
+/* Object that created from user defined class */
+pub struct OpcodeClassInstance {
+    name: String,
+    properties: Rc<HashMap<String, ClassProperty>>,
+    fields: RefCell<HashMap<String, VmObject>>
+}
+
+unsafe impl Send for OpcodeClass {}
+unsafe impl Sync for OpcodeClass {}
+
+impl OpcodeClass {
+    pub fn new(name: String, properties: HashMap<String, ClassProperty>) -> OpcodeClass {
+        OpcodeClass {
+            name,
+            properties: Rc::new(properties)
+        }
+    }
+}
+
+impl Class for OpcodeClass {
+    fn set_class_config(&mut self, _: ClassConfig) {}
+
+    fn get_class_name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn has_element(&self, _: Option<VmObject>, field: Rc<String>) -> bool {
+        self.properties.contains_key(&*field)
+    }
+
+    fn get_element(&self, _: Option<VmObject>, field: Rc<String>) -> Option<ClassProperty> {
+        self.properties.get(&*field).cloned()
+    }
+
+    fn property_count(&self) -> usize {
+        self.properties.len()
+    }
+
+    fn properties(&self) -> std::collections::hash_map::Iter<'_, String, ClassProperty> {
+        self.properties.iter()
+    }
+
+    fn add_method(&mut self, _: &str, _: NativeCall, _: FunctionFlag) {}
+
+    fn add_property(&mut self, _: &str, _: Rc<KaramelPrimative>) {}
+
+    fn set_getter(&mut self, _: IndexerGetCall) {}
+
+    fn get_getter(&self) -> Option<IndexerGetCall> {
+        None
+    }
+
+    fn set_setter(&mut self, _: IndexerSetCall) {}
+
+    fn get_setter(&self) -> Option<IndexerSetCall> {
+        None
+    }
+
+    fn create_instance(&self) -> Option<Rc<dyn Class>> {
+        Some(Rc::new(OpcodeClassInstance {
+            name: self.name.to_string(),
+            properties: self.properties.clone(),
+            fields: RefCell::new(HashMap::new())
+        }))
+    }
+}
+
+impl GetType for OpcodeClass {
+    fn get_type(&self) -> String {
+        self.name.to_string()
+    }
+}
+
+impl Class for OpcodeClassInstance {
+    fn set_class_config(&mut self, _: ClassConfig) {}
+
+    fn get_class_name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn has_element(&self, _: Option<VmObject>, field: Rc<String>) -> bool {
+        self.fields.borrow().contains_key(&*field) || self.properties.contains_key(&*field)
+    }
+
+    fn get_element(&self, _: Option<VmObject>, field: Rc<String>) -> Option<ClassProperty> {
+        match self.fields.borrow().get(&*field) {
+            Some(value) => Some(ClassProperty::Field(value.deref())),
+            None => self.properties.get(&*field).cloned()
+        }
+    }
+
+    fn property_count(&self) -> usize {
+        self.properties.len()
+    }
+
+    fn properties(&self) -> std::collections::hash_map::Iter<'_, String, ClassProperty> {
+        self.properties.iter()
+    }
+
+    fn add_method(&mut self, _: &str, _: NativeCall, _: FunctionFlag) {}
+
+    fn add_property(&mut self, name: &str, property: Rc<KaramelPrimative>) {
+        self.fields.borrow_mut().insert(name.to_string(), VmObject::from(property));
+    }
+
+    fn set_getter(&mut self, _: IndexerGetCall) {}
+
+    fn get_getter(&self) -> Option<IndexerGetCall> {
+        None
+    }
+
+    fn set_setter(&mut self, _: IndexerSetCall) {}
+
+    fn get_setter(&self) -> Option<IndexerSetCall> {
+        None
+    }
+
+    fn set_element(&self, _: Option<VmObject>, field: Rc<String>, value: VmObject) -> bool {
+        self.fields.borrow_mut().insert(field.to_string(), value);
+        true
+    }
+
+    /* Objects are equal only if both of them are the same object */
+    fn equals(&self, left: &KaramelPrimative, right: &KaramelPrimative) -> Option<bool> {
+        match (left, right) {
+            (KaramelPrimative::Class(l_value), KaramelPrimative::Class(r_value)) => Some(Rc::ptr_eq(l_value, r_value)),
+            _ => Some(false)
+        }
+    }
+
+    fn get_contains_method(&self) -> Option<Rc<FunctionReference>> {
+        match self.properties.get(CONTAINS_NAME).or_else(|| self.properties.get(CONTAINS_ASCII_NAME)) {
+            Some(ClassProperty::Function(method)) => Some(method.clone()),
+            _ => None
+        }
+    }
+}
+
+impl GetType for OpcodeClassInstance {
+    fn get_type(&self) -> String {
+        self.name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    use crate::buildin::{Class, ClassProperty};
+    use crate::compiler::KaramelPrimative;
+    use crate::compiler::GetType;
+    use crate::compiler::class::OpcodeClass;
+    use crate::types::VmObject;
+
+    #[test]
+    fn test_opcode_class_1() {
+        let opcode_class = OpcodeClass::new("Nokta".to_string(), HashMap::new());
+        assert_eq!(opcode_class.get_type(), "Nokta".to_string());
+        assert_eq!(opcode_class.property_count(), 0);
+    }
+
+    #[test]
+    fn test_opcode_class_2() {
+        let opcode_class = OpcodeClass::new("Nokta".to_string(), HashMap::new());
+        let instance = opcode_class.create_instance().unwrap();
+        let field = Rc::new("x".to_string());
+
+        assert_eq!(instance.get_class_name(), "Nokta".to_string());
+        assert!(!instance.has_element(None, field.clone()));
+        assert!(instance.set_element(None, field.clone(), VmObject::from(10.0)));
+        assert!(instance.has_element(None, field.clone()));
+
+        match instance.get_element(None, field.clone()) {
+            Some(ClassProperty::Field(value)) => assert_eq!(*value, KaramelPrimative::Number(10.0)),
+            _ => assert!(false, "Alan bulunamadı")
+        };
+
+        /* Fields belong to instance */
+        let other_instance = opcode_class.create_instance().unwrap();
+        assert!(!other_instance.has_element(None, field));
+    }
+}
@@ -6,18 +6,23 @@ use std::path::PathBuf;
 use std::cell::RefCell;
 
 use ast::KaramelDictItem;
-use crate::buildin::Module;
+use crate::buildin::{ClassProperty, Module};
 use crate::file::read_module_or_script;
 use crate::syntax::loops::LoopType;
+use crate::syntax::try_catch::TryParser;
+use crate::syntax::pattern_match::MatchParser;
+use crate::syntax::comprehension::ComprehensionParser;
 use crate::types::*;
 use crate::error::*;
 use crate::compiler::*;
 use crate::parser::*;
 use crate::syntax::SyntaxParser;
 use crate::compiler::value::KaramelPrimative;
-use crate::compiler::ast::{KaramelAstType, KaramelIfStatementElseItem};
+use crate::compiler::ast::{KaramelAstType, KaramelIfStatementElseItem, KaramelMatchArm};
+use crate::compiler::pattern::LoweredPattern;
 use crate::compiler::storage_builder::StorageBuilder;
-use crate::compiler::function::{FunctionReference};
+use crate::compiler::function::{FunctionReference, FunctionType};
+use crate::compiler::class::CONSTRUCTOR_NAME;
 use crate::buildin::class::PRIMATIVE_CLASS_NAMES;
 
 use log;
@@ -60,6 +65,7 @@ impl InterpreterCompiler {
 
         /* Generate main function code */
         self.generate_opcode(main_module.clone(), &*main_ast, &KaramelAstType::None, context, 0)?;
+        context.halt_location = context.opcodes.len();
         context.opcodes.push(VmOpCode::Halt as u8);
         context.opcodes_ptr = context.opcodes.as_mut_ptr();
 
@@ -116,23 +122,42 @@ impl InterpreterCompiler {
 
     fn get_function_definations(&self, module: Rc<OpcodeModule>, ast: Rc<KaramelAstType>, functions: &mut Vec<Rc<FunctionReference>>, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult{
         match &*ast {
-            KaramelAstType::FunctionDefination { name, arguments: _, body  } => {
+            KaramelAstType::FunctionDefination { name, arguments: _, defaults, variadic: _, types: _, body  } => {
                 let search = context.get_function(name.to_string(), module.get_path(), storage_index);
                 match search {
                     Some(reference) => {
                         functions.push(reference.clone());
-                        self.get_function_definations(module.clone(), body.clone(), functions, context, reference.storage_index)?;
+                        for item in defaults.iter().chain(std::iter::once(body)) {
+                            self.get_function_definations(module.clone(), item.clone(), functions, context, reference.storage_index)?;
+                        }
                     },
 
                     None => return Err(KaramelErrorType::FunctionNotFound(name.to_string()))
                 };
             },
-            KaramelAstType::Block(blocks) => {
-                for block in blocks {
-                    self.get_function_definations(module.clone(), block.clone(), functions, context, storage_index)?;
-                }
+            KaramelAstType::ClassDefination { name, fields: _, methods: _ } => {
+                let search = context.find_class(name.to_string(), module.get_path(), storage_index);
+                match search {
+                    Some(class) => {
+                        for (_, property) in class.properties() {
+                            if let ClassProperty::Function(reference) = property {
+                                functions.push(reference.clone());
+                                for item in reference.defaults.iter().chain(reference.opcode_body.iter()) {
+                                    self.get_function_definations(module.clone(), item.clone(), functions, context, reference.storage_index)?;
+                                }
+                            }
+                        }
+                    },
+
+                    None => return Err(KaramelErrorType::ClassNotFound(name.to_string()))
+                };
             },
-            _ => ()
+            KaramelAstType::Lambda(defination) => self.get_function_definations(module.clone(), defination.clone(), functions, context, storage_index)?,
+            _ => {
+                for child in ast.children() {
+                    self.get_function_definations(module.clone(), child, functions, context, storage_index)?;
+                }
+            }
         };
 
         Ok(())
@@ -158,21 +183,71 @@ impl InterpreterCompiler {
                 context.opcodes.push(function.arguments.len() as u8);
             }
 
+            self.generate_default_arguments(module.clone(), function, context)?;
+
+            /* Arguments that used by inner functions moved to cells */
+            for argument in &function.arguments {
+                let storage = &context.storages[function.storage_index];
+                if let (Some(cell_location), Some(location)) = (storage.get_cell_location(argument), storage.get_variable_location(argument)) {
+                    context.opcodes.push(VmOpCode::Load as u8);
+                    context.opcodes.push(location);
+                    context.opcodes.push(VmOpCode::StoreCell as u8);
+                    context.opcodes.push(cell_location);
+                }
+            }
+
             self.generate_opcode(module.clone(), &function.opcode_body.as_ref().unwrap(), &function.opcode_body.as_ref().unwrap(), context, function.storage_index as usize)?;
         }
 
         Ok(())
     }
 
+    /*
+    Default values are calculated for missing arguments.
+    ╔═══════════════════════════╗
+    ║ InitDefault (index, exit) ║
+    ╠═══════════════════════════╣
+    ║       Default value       ║
+    ╠═══════════════════════════╣
+    ║           Store           ║
+    ╚═══════════════════════════╝
+    */
+    fn generate_default_arguments(&self, module: Rc<OpcodeModule>, function: &FunctionReference, context: &mut KaramelCompilerContext) -> CompilerResult {
+        let first_default = function.arguments.len() - function.variadic as usize - function.defaults.len();
+
+        for (index, default) in function.defaults.iter().enumerate() {
+            let location = match context.storages[function.storage_index].get_variable_location(&function.arguments[first_default + index]) {
+                Some(location) => location,
+                None => return Err(KaramelErrorType::ValueNotFoundInStorage)
+            };
+
+            context.opcodes.push(VmOpCode::InitDefault as u8);
+            context.opcodes.push((first_default + index) as u8);
+            let exit_location = context.opcodes.len();
+            context.opcodes.push(0_u8);
+            context.opcodes.push(0_u8);
+
+            self.generate_opcode(module.clone(), default, default, context, function.storage_index)?;
+            context.opcodes.push(VmOpCode::Store as u8);
+            context.opcodes.push(location);
+            self.build_jump_location(context, exit_location);
+        }
+
+        Ok(())
+    }
+
     fn generate_opcode(&self, module: Rc<OpcodeModule>, ast: &KaramelAstType, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
         match ast {
             KaramelAstType::Assignment { variable, operator, expression } => self.generate_assignment(module.clone(), variable, operator, expression, context, storage_index),
+            KaramelAstType::TypedAssignment { type_name: _, assignment } => self.generate_opcode(module.clone(), assignment, upper_ast, context, storage_index),
             KaramelAstType::Symbol(variable) => self.generate_symbol(module.clone(), variable, upper_ast, context, storage_index),
             KaramelAstType::Control { left, operator, right } => self.generate_control(module.clone(), left, operator, right, upper_ast, context, storage_index),
-            KaramelAstType::Binary { left, operator, right } => self.generate_binary(module.clone(), left, operator, right, upper_ast, context, storage_index),
+            KaramelAstType::Binary { left, operator, right, .. } => self.generate_binary(module.clone(), left, operator, right, upper_ast, context, storage_index),
             KaramelAstType::Block(asts) => self.generate_block(module.clone(), asts, upper_ast, context, storage_index),
             KaramelAstType::Primative(primative) => self.generate_primative(primative.clone(), upper_ast, context, storage_index),
             KaramelAstType::List(list) => self.generate_list(module.clone(), list, upper_ast, context, storage_index),
+            KaramelAstType::Tuple(items) => self.generate_tuple(module.clone(), items, upper_ast, context, storage_index),
+            KaramelAstType::Set(items) => self.generate_set(module.clone(), items, upper_ast, context, storage_index),
             KaramelAstType::Dict(dict) => self.generate_dict(module.clone(), dict, upper_ast, context, storage_index),
             KaramelAstType::FuncCall { func_name_expression, arguments, assign_to_temp } => self.generate_func_call(module.clone(), func_name_expression, arguments, assign_to_temp.get(), upper_ast, context, storage_index),
             KaramelAstType::AccessorFuncCall { source, indexer, assign_to_temp } => self.generate_accessor_func_call(module.clone(), source, indexer, assign_to_temp.get(), upper_ast, context, storage_index),
@@ -180,16 +255,55 @@ impl InterpreterCompiler {
             KaramelAstType::SuffixUnary(operator, expression) => self.generate_suffix_unary(operator, expression, upper_ast, context, storage_index),
             KaramelAstType::NewLine => Ok(()),
             KaramelAstType::Loop { loop_type, body } => self.generate_loop(module.clone(), loop_type, body, upper_ast, context, storage_index),
-            KaramelAstType::Break => self.generate_break(upper_ast, context, storage_index),
-            KaramelAstType::Continue => self.generate_continue(upper_ast, context, storage_index),
+            KaramelAstType::Break => self.generate_break(module.clone(), upper_ast, context, storage_index),
+            KaramelAstType::Continue => self.generate_continue(module.clone(), upper_ast, context, storage_index),
             KaramelAstType::Return(expression) => self.generate_return(module.clone(), expression, upper_ast, context, storage_index),
+            KaramelAstType::Yield(expression) => self.generate_yield(module.clone(), expression, upper_ast, context, storage_index),
             KaramelAstType::IfStatement {condition, body, else_body, else_if} => self.generate_if_condition(module.clone(),condition, body, else_body, else_if, upper_ast, context, storage_index),
             KaramelAstType::Indexer {body, indexer} => self.generate_indexer(module.clone(), body, indexer, upper_ast, context, storage_index),
+            KaramelAstType::Slice {body, start, end, step} => self.generate_slice(module.clone(), body, &[start, end, step], upper_ast, context, storage_index),
+            KaramelAstType::Comprehension {id, key, value, variables, iterable, condition} => self.generate_comprehension(module.clone(), *id, key, value, variables, iterable, condition, upper_ast, context, storage_index),
+            KaramelAstType::ComprehensionItem {id, key, value} => self.generate_comprehension_item(module.clone(), *id, key, value, upper_ast, context, storage_index),
+            KaramelAstType::Match {id, line, column, value, arms, default} => self.generate_match(module.clone(), *id, (*line, *column), value, arms, default, upper_ast, context, storage_index),
+            KaramelAstType::Conditional {condition, true_expression, false_expression} => self.generate_conditional(module.clone(), condition, true_expression, false_expression, upper_ast, context, storage_index),
             KaramelAstType::None => self.generate_none(context, storage_index),
-            KaramelAstType::FunctionDefination{name: _, arguments: _, body: _} => Ok(()),
+            KaramelAstType::FunctionDefination{name: _, arguments: _, defaults: _, variadic: _, types: _, body: _} => Ok(()),
+            KaramelAstType::ClassDefination{name: _, fields: _, methods: _} => Ok(()),
+            KaramelAstType::Lambda(defination) => self.generate_lambda(module.clone(), defination, context, storage_index),
             KaramelAstType::ModulePath(name) => self.generate_function_map(name, context, storage_index),
             KaramelAstType::Load(names) => self.generate_load_module(names, context),
+            KaramelAstType::Use(_) => Ok(()),
+            KaramelAstType::Try { id, body, catch_variable, catch_body, finally_body } => self.generate_try(module.clone(), *id, body, catch_variable, catch_body, finally_body, upper_ast, context, storage_index),
+            KaramelAstType::Throw(expression) => self.generate_throw(module.clone(), expression, upper_ast, context, storage_index),
+            KaramelAstType::TextTemplate(parts) => self.generate_text_template(module.clone(), parts, upper_ast, context, storage_index),
+            KaramelAstType::TextFormat { expression, format } => self.generate_text_format(module.clone(), expression, format, upper_ast, context, storage_index),
+            KaramelAstType::Location { line: _, column: _ } => Ok(()),
+            KaramelAstType::KeywordArgument { name: _, expression } => self.generate_opcode(module.clone(), expression, upper_ast, context, storage_index),
+        }
+    }
+
+    fn generate_text_template(&self, module: Rc<OpcodeModule>, parts: &[Rc<KaramelAstType>], upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        for (index, part) in parts.iter().enumerate() {
+            self.generate_opcode(module.clone(), part, upper_ast, context, storage_index)?;
+            if index > 0 {
+                context.opcodes.push(VmOpCode::Addition as u8);
+            }
         }
+        Ok(())
+    }
+
+    fn generate_text_format(&self, module: Rc<OpcodeModule>, expression: &KaramelAstType, format: &Rc<String>, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        self.generate_opcode(module.clone(), expression, upper_ast, context, storage_index)?;
+
+        let storage = &context.storages[storage_index];
+        let format_location = match storage.get_constant_location(Rc::new(KaramelPrimative::Text(format.clone()))) {
+            Some(location) => location,
+            None => return Err(KaramelErrorType::ValueNotFoundInStorage)
+        };
+
+        context.opcodes.push(VmOpCode::Format as u8);
+        context.opcodes.push(format_location);
+        Ok(())
     }
 
     fn generate_primative(&self, primative: Rc<KaramelPrimative>, _: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
@@ -283,6 +397,49 @@ impl InterpreterCompiler {
         Ok(())
     }
 
+    fn generate_tuple(&self, module: Rc<OpcodeModule>, items: &Vec<Rc<KaramelAstType>>, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        for item in items.iter().rev() {
+            self.generate_opcode(module.clone(), item, upper_ast, context, storage_index)?;
+        }
+        context.opcodes.push(VmOpCode::InitTuple as u8);
+        context.opcodes.push(items.len() as u8);
+        Ok(())
+    }
+
+    fn generate_set(&self, module: Rc<OpcodeModule>, items: &Vec<Rc<KaramelAstType>>, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        for item in items.iter().rev() {
+            self.generate_opcode(module.clone(), item, upper_ast, context, storage_index)?;
+        }
+        context.opcodes.push(VmOpCode::InitSet as u8);
+        context.opcodes.push(items.len() as u8);
+        Ok(())
+    }
+
+    /* Generated list or dict is filled by a for each loop then loaded to stack */
+    fn generate_comprehension(&self, module: Rc<OpcodeModule>, id: usize, key: &Option<Rc<KaramelAstType>>, value: &Rc<KaramelAstType>, variables: &Vec<String>, iterable: &Rc<KaramelAstType>, condition: &Option<Rc<KaramelAstType>>, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        self.generate_opcode(module.clone(), &ComprehensionParser::get_result_assignment(id, key.is_some()), upper_ast, context, storage_index)?;
+        self.generate_opcode(module.clone(), &ComprehensionParser::get_loop(id, key, value, variables, iterable, condition), upper_ast, context, storage_index)?;
+        self.generate_opcode(module.clone(), &KaramelAstType::Symbol(ComprehensionParser::get_result_variable(id)), upper_ast, context, storage_index)
+    }
+
+    /* Items are added directly, without calling 'ekle' method */
+    fn generate_comprehension_item(&self, module: Rc<OpcodeModule>, id: usize, key: &Option<Rc<KaramelAstType>>, value: &Rc<KaramelAstType>, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        self.generate_opcode(module.clone(), &KaramelAstType::Symbol(ComprehensionParser::get_result_variable(id)), upper_ast, context, storage_index)?;
+
+        match key {
+            Some(key) => {
+                self.generate_opcode(module.clone(), key, upper_ast, context, storage_index)?;
+                self.generate_opcode(module.clone(), value, upper_ast, context, storage_index)?;
+                context.opcodes.push(VmOpCode::SetItem as u8);
+            },
+            None => {
+                self.generate_opcode(module.clone(), value, upper_ast, context, storage_index)?;
+                context.opcodes.push(VmOpCode::Append as u8);
+            }
+        };
+        Ok(())
+    }
+
     fn generate_dict(&self, module: Rc<OpcodeModule>, dict: &Vec<Rc<KaramelDictItem>>, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
         for item in dict.iter().rev() {
             self.generate_primative(item.key.clone(), upper_ast, context, storage_index)?;
@@ -300,7 +457,19 @@ impl InterpreterCompiler {
             Some(function_ref) => {
                 let search_location = context.storages[storage_index].get_constant_location(Rc::new(KaramelPrimative::Function(function_ref.clone(), None)));
                 match search_location {
+                    /* Closure should be created before call */
+                    Some(location) if !context.storages[function_ref.storage_index].captured_variables.is_empty() => {
+                        self.check_arguments(&function_ref, arguments, 0)?;
+                        self.generate_function_load(&function_ref, location, context, storage_index)?;
+                        self.generate_keyword_arguments(arguments, context, storage_index)?;
+                        context.opcodes.push(VmOpCode::CallStack as u8);
+                        context.opcodes.push(arguments.len() as u8);
+                        context.opcodes.push(assign_to_temp as u8);
+                        return Ok(true);
+                    },
                     Some(location) => {
+                        self.check_arguments(&function_ref, arguments, 0)?;
+                        self.generate_keyword_arguments(arguments, context, storage_index)?;
                         context.opcodes.push(VmOpCode::Call as u8);
                         context.opcodes.push(location as u8);
                         context.opcodes.push(arguments.len() as u8);
@@ -313,12 +482,44 @@ impl InterpreterCompiler {
             _ => ()
         };
 
+        /* Class call creates new object */
+        match context.storages[storage_index].get_class_constant(name.to_string(), module_path) {
+            Some(location) => {
+                if let Some(ClassProperty::Function(constructor)) = context.find_class(name.to_string(), module_path, storage_index).and_then(|class| class.get_element(None, Rc::new(CONSTRUCTOR_NAME.to_string()))) {
+                    self.check_arguments(&constructor, arguments, 1)?;
+                }
+
+                context.opcodes.push(VmOpCode::Load as u8);
+                context.opcodes.push(location as u8);
+
+                self.generate_keyword_arguments(arguments, context, storage_index)?;
+                context.opcodes.push(VmOpCode::CallStack as u8);
+                context.opcodes.push(arguments.len() as u8);
+                context.opcodes.push(assign_to_temp as u8);
+                return Ok(true);
+            },
+            None => ()
+        };
+
+        /* Function shared with closure */
+        if let Some(location) = context.storages[storage_index].get_cell_location(&name) {
+            context.opcodes.push(VmOpCode::LoadCell as u8);
+            context.opcodes.push(location);
+
+            self.generate_keyword_arguments(arguments, context, storage_index)?;
+            context.opcodes.push(VmOpCode::CallStack as u8);
+            context.opcodes.push(arguments.len() as u8);
+            context.opcodes.push(true as u8);
+            return Ok(true);
+        }
+
         match context.storages[storage_index].get_variable_location(&name) {
             /* Variable found */
             Some(location) => {
                 context.opcodes.push(VmOpCode::Load as u8);
                 context.opcodes.push(location as u8);
 
+                self.generate_keyword_arguments(arguments, context, storage_index)?;
                 context.opcodes.push(VmOpCode::CallStack as u8);
                 context.opcodes.push(arguments.len() as u8);
                 context.opcodes.push(true as u8);
@@ -331,9 +532,45 @@ impl InterpreterCompiler {
         Ok(false)
     }
 
+    fn get_keyword_arguments(&self, arguments: &[Rc<KaramelAstType>]) -> Vec<String> {
+        arguments.iter().filter_map(|argument| match &**argument {
+            KaramelAstType::KeywordArgument { name, expression: _ } => Some(name.to_string()),
+            _ => None
+        }).collect()
+    }
+
+    /* Wrong call to known function is reported before execution */
+    fn check_arguments(&self, function: &FunctionReference, arguments: &[Rc<KaramelAstType>], offset: usize) -> CompilerResult {
+        if let FunctionType::Opcode = function.callback {
+            let keywords = self.get_keyword_arguments(arguments);
+            function.match_arguments(arguments.len() - keywords.len(), &keywords, offset)?;
+        }
+
+        Ok(())
+    }
+
+    /* Keyword argument names are passed to VM just before the call */
+    fn generate_keyword_arguments(&self, arguments: &[Rc<KaramelAstType>], context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        let keywords = self.get_keyword_arguments(arguments);
+        if keywords.is_empty() {
+            return Ok(());
+        }
+
+        context.opcodes.push(VmOpCode::KeywordArguments as u8);
+        context.opcodes.push(keywords.len() as u8);
+        for keyword in keywords {
+            match context.storages[storage_index].get_constant_location(Rc::new(KaramelPrimative::Text(Rc::new(keyword)))) {
+                Some(location) => context.opcodes.push(location),
+                None => return Err(KaramelErrorType::ValueNotFoundInStorage)
+            };
+        }
+
+        Ok(())
+    }
+
     fn generate_accessor_func_call(&self, module: Rc<OpcodeModule>, source: &KaramelAstType, indexer: &KaramelAstType, _assign_to_temp: bool,  upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
 
-        if let KaramelAstType::FuncCall { func_name_expression, arguments, assign_to_temp: _ } = indexer {
+        if let KaramelAstType::FuncCall { func_name_expression, arguments, assign_to_temp } = indexer {
             match &**func_name_expression {
                 KaramelAstType::Symbol(function_name) => {
                             /* Build arguments */
@@ -351,11 +588,10 @@ impl InterpreterCompiler {
                             context.opcodes.push(location as u8);
                             context.opcodes.push(VmOpCode::GetItem as u8);
                             
+                            self.generate_keyword_arguments(arguments, context, storage_index)?;
                             context.opcodes.push(VmOpCode::CallStack as u8);
                             context.opcodes.push(arguments.len() as u8);
-                            context.opcodes.push(true as u8);
-                            /*context.opcodes.push(arguments.len() as u8);
-                            context.opcodes.push(assign_to_temp as u8);*/
+                            context.opcodes.push(assign_to_temp.get() as u8);
                             return Ok(());
                         },
                         _ => return Err(KaramelErrorType::FunctionNotFound(function_name.to_string()))
@@ -399,6 +635,7 @@ impl InterpreterCompiler {
 
             KaramelAstType::FuncCall {func_name_expression, arguments: inner_arguments, assign_to_temp: _} => {
                 self.generate_func_call(module.clone(), func_name_expression, inner_arguments, true, upper_ast, context, storage_index)?;
+                self.generate_keyword_arguments(arguments, context, storage_index)?;
                 context.opcodes.push(VmOpCode::CallStack as u8);
                 context.opcodes.push(arguments.len() as u8);
                 context.opcodes.push(true as u8);
@@ -415,6 +652,7 @@ impl InterpreterCompiler {
             },
             _ => {
                 self.generate_opcode(module.clone(), func_name_expression, upper_ast, context, storage_index)?;
+                self.generate_keyword_arguments(arguments, context, storage_index)?;
                 context.opcodes.push(VmOpCode::CallStack as u8);
                 context.opcodes.push(arguments.len() as u8);
                 context.opcodes.push(true as u8);
@@ -423,7 +661,26 @@ impl InterpreterCompiler {
         }
     }
 
-    fn generate_break(&self, _: &KaramelAstType, context: &mut KaramelCompilerContext, _: usize) -> CompilerResult {       
+    /* Leaving 'dene' blocks with jump, their handlers need to be closed */
+    /* Handlers are closed from inner to outer and 'sonunda' bodies are executed before leaving the blocks */
+    fn generate_end_try(&self, module: Rc<OpcodeModule>, depth: usize, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        let try_blocks = context.try_blocks.to_vec();
+        for finally_body in try_blocks.iter().rev().take(depth) {
+            /* 'sonunda' body is not protected by its own handler */
+            context.try_blocks.pop();
+            context.opcodes.push(VmOpCode::EndTry as u8);
+
+            if let Some(finally_body) = finally_body {
+                self.generate_opcode(module.clone(), finally_body, upper_ast, context, storage_index)?;
+            }
+        }
+
+        context.try_blocks = try_blocks;
+        Ok(())
+    }
+
+    fn generate_break(&self, module: Rc<OpcodeModule>, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {       
+        self.generate_end_try(module, context.try_blocks.len().saturating_sub(context.loop_try_depth), upper_ast, context, storage_index)?;
         context.opcodes.push(VmOpCode::Jump as u8);
         context.loop_breaks.push(context.opcodes.len());
         context.opcodes.push(0);
@@ -431,7 +688,8 @@ impl InterpreterCompiler {
         Ok(())
     }
 
-    fn generate_continue(&self, _: &KaramelAstType, context: &mut KaramelCompilerContext, _: usize) -> CompilerResult {       
+    fn generate_continue(&self, module: Rc<OpcodeModule>, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {       
+        self.generate_end_try(module, context.try_blocks.len().saturating_sub(context.loop_try_depth), upper_ast, context, storage_index)?;
         context.opcodes.push(VmOpCode::Jump as u8);
         context.loop_continues.push(context.opcodes.len());
         context.opcodes.push(0);
@@ -441,15 +699,119 @@ impl InterpreterCompiler {
 
     fn generate_return(&self, module: Rc<OpcodeModule>, expression: &KaramelAstType, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
         self.generate_opcode(module.clone(), expression, upper_ast, context, storage_index)?;
+        self.generate_end_try(module, context.try_blocks.len(), upper_ast, context, storage_index)?;
         context.opcodes.push(VmOpCode::Return as u8);
         Ok(())
     }
 
+    fn generate_yield(&self, module: Rc<OpcodeModule>, expression: &KaramelAstType, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        self.generate_opcode(module.clone(), expression, upper_ast, context, storage_index)?;
+        context.opcodes.push(VmOpCode::Yield as u8);
+        Ok(())
+    }
+
+    fn generate_throw(&self, module: Rc<OpcodeModule>, expression: &KaramelAstType, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        self.generate_opcode(module.clone(), expression, upper_ast, context, storage_index)?;
+        context.opcodes.push(VmOpCode::Throw as u8);
+        Ok(())
+    }
+
+    fn create_try(&self, context: &mut KaramelCompilerContext) -> usize {
+        context.opcodes.push(VmOpCode::Try as u8);
+        let handler_location = context.opcodes.len();
+
+        context.opcodes.push(0_u8);
+        context.opcodes.push(0_u8);
+
+        handler_location
+    }
+
+    fn generate_try(&self, module: Rc<OpcodeModule>, id: usize, body: &KaramelAstType, catch_variable: &Option<String>, catch_body: &Option<Rc<KaramelAstType>>, finally_body: &Option<Rc<KaramelAstType>>, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        /*
+        ╔════════════════════╗
+        ║   TRY HANDLER      ║
+        ╠════════════════════╣
+        ║   BODY             ║
+        ╠════════════════════╣
+        ║   END TRY          ║
+        ║   JUMP TO FINALLY  ║
+        ╠════════════════════╣
+        ║   STORE ERROR      ║
+        ║   CATCH BODY       ║
+        ║   JUMP TO FINALLY  ║
+        ╠════════════════════╣
+        ║   STORE ERROR      ║
+        ║   FINALLY BODY     ║
+        ║   THROW ERROR      ║
+        ╠════════════════════╣
+        ║   FINALLY BODY     ║
+        ╚════════════════════╝
+        */
+        let mut exit_locations: Vec<usize> = Vec::new();
+        let (error_load_opcode, error_store_opcode, error_location) = self.get_variable_opcodes(&TryParser::get_error_variable(id), context, storage_index)?;
+
+        let mut handler_location = self.create_try(context);
+        context.try_blocks.push(finally_body.clone());
+        self.generate_opcode(module.clone(), body, upper_ast, context, storage_index)?;
+        context.try_blocks.pop();
+        context.opcodes.push(VmOpCode::EndTry as u8);
+        self.create_exit_jump(context, &mut exit_locations);
+
+        if let Some(catch_body) = catch_body {
+            self.build_jump_location(context, handler_location);
+
+            /* VM pushes error object to stack before jumping to handler */
+            let (_, store_opcode, location) = match catch_variable {
+                Some(variable) => self.get_variable_opcodes(variable, context, storage_index)?,
+                None => (error_load_opcode, error_store_opcode, error_location)
+            };
+            context.opcodes.push(store_opcode as u8);
+            context.opcodes.push(location);
+
+            /* Errors at catch body should not skip finally body */
+            if finally_body.is_some() {
+                handler_location = self.create_try(context);
+                context.try_blocks.push(finally_body.clone());
+            }
+
+            self.generate_opcode(module.clone(), catch_body, upper_ast, context, storage_index)?;
+
+            if finally_body.is_some() {
+                context.try_blocks.pop();
+                context.opcodes.push(VmOpCode::EndTry as u8);
+                self.create_exit_jump(context, &mut exit_locations);
+            }
+        }
+
+        if let Some(finally_body) = finally_body {
+            /* Execute finally body and throw error again */
+            self.build_jump_location(context, handler_location);
+            context.opcodes.push(error_store_opcode as u8);
+            context.opcodes.push(error_location);
+            self.generate_opcode(module.clone(), finally_body, upper_ast, context, storage_index)?;
+            context.opcodes.push(error_load_opcode as u8);
+            context.opcodes.push(error_location);
+            context.opcodes.push(VmOpCode::Throw as u8);
+        }
+
+        for exit_location in exit_locations {
+            self.build_jump_location(context, exit_location);
+        }
+
+        if let Some(finally_body) = finally_body {
+            self.generate_opcode(module.clone(), finally_body, upper_ast, context, storage_index)?;
+        }
+
+        Ok(())
+    }
+
     fn generate_loop(&self, module: Rc<OpcodeModule>, loop_type: &LoopType, body: &KaramelAstType, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
         /* Backup loop informations */
         let loop_breaks = context.loop_breaks.to_vec();
         let loop_continues = context.loop_continues.to_vec();
+        let loop_try_depth = context.loop_try_depth;
         let mut compare_location = 0;
+        context.loop_try_depth = context.try_blocks.len();
 
         let (variable, control, increment) = match loop_type {
             LoopType::Endless => {
@@ -466,6 +828,10 @@ impl InterpreterCompiler {
                 increment
             } => {
                 (Some(variable.clone()), Some(control.clone()), Some(increment.clone()))
+            },
+
+            LoopType::ForEach { id: _, variables: _, iterable: _ } | LoopType::Range { id: _, variable: _, start: _, end: _, step: _ } => {
+                (None, None, None)
             }
         };
 
@@ -473,7 +839,96 @@ impl InterpreterCompiler {
             self.generate_opcode(module.clone(), &*&variable, upper_ast, context, storage_index)?;
         }
 
+        /* Iterated object and position are stored at hidden variables */
+        if let LoopType::ForEach { id, variables: _, iterable } = loop_type {
+            let (source, position) = LoopType::get_iterator_variables(*id);
+            let storage = &context.storages[storage_index];
+            let (source_location, position_location, zero_location) = match (storage.get_variable_location(&source), storage.get_variable_location(&position), storage.get_constant_location(Rc::new(KaramelPrimative::Number(0.0)))) {
+                (Some(source_location), Some(position_location), Some(zero_location)) => (source_location, position_location, zero_location),
+                _ => return Err(KaramelErrorType::ValueNotFoundInStorage)
+            };
+
+            self.generate_opcode(module.clone(), iterable, upper_ast, context, storage_index)?;
+            context.opcodes.push(VmOpCode::Store as u8);
+            context.opcodes.push(source_location);
+
+            context.opcodes.push(VmOpCode::FastStore as u8);
+            context.opcodes.push(position_location);
+            context.opcodes.push(zero_location);
+        }
+
+        /* Range values are calculated once and stored at hidden variables. Zero step means that direction will be decided by range */
+        if let LoopType::Range { id, variable: _, start, end, step } = loop_type {
+            let (next, last, step_variable) = LoopType::get_range_variables(*id);
+            let storage = &context.storages[storage_index];
+            let (next_location, last_location, step_location, zero_location) = match (storage.get_variable_location(&next), storage.get_variable_location(&last), storage.get_variable_location(&step_variable), storage.get_constant_location(Rc::new(KaramelPrimative::Number(0.0)))) {
+                (Some(next_location), Some(last_location), Some(step_location), Some(zero_location)) => (next_location, last_location, step_location, zero_location),
+                _ => return Err(KaramelErrorType::ValueNotFoundInStorage)
+            };
+
+            self.generate_opcode(module.clone(), start, upper_ast, context, storage_index)?;
+            context.opcodes.push(VmOpCode::Store as u8);
+            context.opcodes.push(next_location);
+
+            self.generate_opcode(module.clone(), end, upper_ast, context, storage_index)?;
+            context.opcodes.push(VmOpCode::Store as u8);
+            context.opcodes.push(last_location);
+
+            match step {
+                Some(step) => {
+                    self.generate_opcode(module.clone(), step, upper_ast, context, storage_index)?;
+                    context.opcodes.push(VmOpCode::Store as u8);
+                    context.opcodes.push(step_location);
+                },
+                None => {
+                    context.opcodes.push(VmOpCode::FastStore as u8);
+                    context.opcodes.push(step_location);
+                    context.opcodes.push(zero_location);
+                }
+            };
+        }
+
         let start_location = context.opcodes.len();
+
+        if let LoopType::ForEach { id, variables, iterable: _ } = loop_type {
+            let (source, position) = LoopType::get_iterator_variables(*id);
+            let storage = &context.storages[storage_index];
+
+            context.opcodes.push(VmOpCode::Iterate as u8);
+            context.opcodes.push(storage.get_variable_location(&source).unwrap_or_default());
+            context.opcodes.push(storage.get_variable_location(&position).unwrap_or_default());
+            context.opcodes.push(variables.len() as u8);
+            compare_location = context.opcodes.len();
+
+            context.opcodes.push(0_u8);
+            context.opcodes.push(0_u8);
+
+            /* Last pushed value belongs to last variable */
+            for variable in variables.iter().rev() {
+                let (_, store_opcode, location) = self.get_variable_opcodes(variable, context, storage_index)?;
+                context.opcodes.push(store_opcode as u8);
+                context.opcodes.push(location);
+            }
+        }
+
+        if let LoopType::Range { id, variable, start: _, end: _, step } = loop_type {
+            let (next, last, step_variable) = LoopType::get_range_variables(*id);
+            let storage = &context.storages[storage_index];
+
+            context.opcodes.push(VmOpCode::RangeIterate as u8);
+            context.opcodes.push(storage.get_variable_location(&next).unwrap_or_default());
+            context.opcodes.push(storage.get_variable_location(&last).unwrap_or_default());
+            context.opcodes.push(storage.get_variable_location(&step_variable).unwrap_or_default());
+            context.opcodes.push(step.is_some() as u8);
+            compare_location = context.opcodes.len();
+
+            context.opcodes.push(0_u8);
+            context.opcodes.push(0_u8);
+
+            let (_, store_opcode, location) = self.get_variable_opcodes(variable, context, storage_index)?;
+            context.opcodes.push(store_opcode as u8);
+            context.opcodes.push(location);
+        }
         
         if let Some(control) = &control {
             self.generate_opcode(module.clone(), &*control, upper_ast, context, storage_index)?;
@@ -508,8 +963,9 @@ impl InterpreterCompiler {
 
         context.loop_breaks    = loop_breaks.to_vec();
         context.loop_continues = loop_continues.to_vec();
+        context.loop_try_depth = loop_try_depth;
 
-        if control.is_some() {
+        if compare_location != 0 {
             let end_location = current_location - compare_location;
             context.opcodes[compare_location]     = end_location as u8;
             context.opcodes[compare_location + 1] = (end_location >> 8) as u8;
@@ -518,19 +974,67 @@ impl InterpreterCompiler {
         Ok(())
     }
 
+    fn generate_function_load(&self, function: &FunctionReference, location: u8, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        let captured_variables = &context.storages[function.storage_index].captured_variables;
+        if captured_variables.is_empty() {
+            context.opcodes.push(VmOpCode::Load as u8);
+            context.opcodes.push(location);
+            return Ok(());
+        }
+
+        /* Closure receives cells from current function */
+        let mut cells = Vec::new();
+        for variable in captured_variables.iter() {
+            match context.storages[storage_index].get_cell_location(variable) {
+                Some(cell_location) => cells.push(cell_location),
+                None => return Err(KaramelErrorType::ValueNotFoundInStorage)
+            };
+        }
+
+        context.opcodes.push(VmOpCode::Closure as u8);
+        context.opcodes.push(location);
+        context.opcodes.push(cells.len() as u8);
+        context.opcodes.extend(cells);
+        Ok(())
+    }
+
+    fn generate_lambda(&self, module: Rc<OpcodeModule>, defination: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        let name = match defination {
+            KaramelAstType::FunctionDefination { name, arguments: _, defaults: _, variadic: _, types: _, body: _ } => name,
+            _ => return Err(KaramelErrorType::FunctionNotFound(String::new()))
+        };
+
+        let function = match context.get_function(name.to_string(), module.get_path(), storage_index) {
+            Some(function) => function,
+            None => return Err(KaramelErrorType::FunctionNotFound(name.to_string()))
+        };
+
+        match context.storages[storage_index].get_constant_location(Rc::new(KaramelPrimative::Function(function.clone(), None))) {
+            Some(location) => self.generate_function_load(&function, location, context, storage_index),
+            None => Err(KaramelErrorType::FunctionNotFound(name.to_string()))
+        }
+    }
+
     fn generate_symbol(&self, module: Rc<OpcodeModule>, variable: &String, _: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
         let storage = &context.storages[storage_index];                
-        let result = storage.get_function_constant(variable.to_string(), module.clone());
+        let result = match storage.get_function_constant(variable.to_string(), module.clone()) {
+            Some(index) => Some(index),
+
+            /* Imported function belongs to other module */
+            None => context.get_function(variable.to_string(), module.get_path(), storage_index).and_then(|reference| storage.get_constant_location(Rc::new(KaramelPrimative::Function(reference, None))))
+        };
         match result {
             Some(index) => {
-                context.opcodes.push(VmOpCode::Load as u8);
-                context.opcodes.push(index as u8);
-                return Ok(())
+                let function = match &*storage.memory[index as usize].deref() {
+                    KaramelPrimative::Function(function, _) => function.clone(),
+                    _ => return Err(KaramelErrorType::FunctionNotFound(variable.to_string()))
+                };
+                return self.generate_function_load(&function, index, context, storage_index);
             },
             _ => ()
         };
 
-        let result = storage.get_class_constant(variable.to_string(), module.clone());
+        let result = storage.get_class_constant(variable.to_string(), module.get_path());
         match result {
             Some(index) => {
                 context.opcodes.push(VmOpCode::Load as u8);
@@ -540,6 +1044,12 @@ impl InterpreterCompiler {
             _ => ()
         };
 
+        if let Some(location) = storage.get_cell_location(variable) {
+            context.opcodes.push(VmOpCode::LoadCell as u8);
+            context.opcodes.push(location);
+            return Ok(());
+        }
+
         match storage.get_variable_location(variable) {
             /* Variable found */
             Some(location) => {
@@ -554,21 +1064,50 @@ impl InterpreterCompiler {
 
     fn generate_control(&self, module: Rc<OpcodeModule>, left_ast: &KaramelAstType, operator: &KaramelOperatorType, right_ast: &KaramelAstType, _: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
         self.generate_opcode(module.clone(), left_ast, &KaramelAstType::None, context, storage_index)?;
+
+        /*
+        ╔════════════════════╗
+        ║   LEFT EXPRESSION  ║
+        ╠════════════════════╣
+        ║   JUMP TO OUT OF   ║
+        ║   EXPRESSION       ║
+        ╠════════════════════╣
+        ║   RIGHT EXPRESSION ║
+        ╚════════════════════╝
+        */
+        let short_circuit = match operator {
+            KaramelOperatorType::And => Some(VmOpCode::JumpIfFalse),
+            KaramelOperatorType::Or  => Some(VmOpCode::JumpIfTrue),
+            _ => None
+        };
+
+        if let Some(opcode) = short_circuit {
+            let exit_location = self.create_conditional_jump(context, opcode);
+            self.generate_opcode(module.clone(), right_ast, &KaramelAstType::None, context, storage_index)?;
+            self.build_compare_location(context, exit_location);
+            return Ok(());
+        }
+
         self.generate_opcode(module.clone(), right_ast, &KaramelAstType::None, context, storage_index)?;
 
         let opcode = match operator {
-            KaramelOperatorType::Or               => VmOpCode::Or as u8,
-            KaramelOperatorType::And              => VmOpCode::And as u8,
             KaramelOperatorType::Equal            => VmOpCode::Equal as u8,
             KaramelOperatorType::NotEqual         => VmOpCode::NotEqual as u8,
             KaramelOperatorType::GreaterThan      => VmOpCode::GreaterThan as u8,
             KaramelOperatorType::LessThan         => VmOpCode::LessThan as u8,
             KaramelOperatorType::GreaterEqualThan => VmOpCode::GreaterEqualThan as u8,
             KaramelOperatorType::LessEqualThan    => VmOpCode::LessEqualThan as u8,
+            KaramelOperatorType::In               => VmOpCode::In as u8,
+            KaramelOperatorType::NotIn            => VmOpCode::In as u8,
             _ => VmOpCode::None as u8
         };
 
         context.opcodes.push(opcode);
+
+        /* 'içinde değil' is the opposite of 'içinde' result */
+        if *operator == KaramelOperatorType::NotIn {
+            context.opcodes.push(VmOpCode::Not as u8);
+        }
         Ok(())
     }
 
@@ -581,6 +1120,24 @@ impl InterpreterCompiler {
                     self.check_prohibited_names(variable_name)?;    
                 }
                 
+                /* Variable shared with closure */
+                if let Some(location) = context.storages[storage_index].get_cell_location(symbol) {
+                    if *operator != KaramelOperatorType::Assign {
+                        context.opcodes.push(VmOpCode::LoadCell as u8);
+                        context.opcodes.push(location);
+                    }
+
+                    self.generate_opcode(module.clone(), expression_ast, &KaramelAstType::None, context, storage_index)?;
+
+                    if *operator != KaramelOperatorType::Assign {
+                        context.opcodes.push(self.get_assignment_opcode(operator));
+                    }
+
+                    context.opcodes.push(VmOpCode::StoreCell as u8);
+                    context.opcodes.push(location);
+                    return Ok(());
+                }
+
                 let location = context.storages.get_mut(storage_index).unwrap().add_variable(&*symbol);
                 let storage = &context.storages[storage_index];
                 
@@ -608,15 +1165,7 @@ impl InterpreterCompiler {
 
                     self.generate_opcode(module.clone(), expression_ast, &KaramelAstType::None, context, storage_index)?;
 
-                    let opcode = match operator {
-                        KaramelOperatorType::AssignAddition       => VmOpCode::Addition as u8,
-                        KaramelOperatorType::AssignDivision       => VmOpCode::Division as u8,
-                        KaramelOperatorType::AssignMultiplication => VmOpCode::Multiply as u8,
-                        KaramelOperatorType::AssignSubtraction    => VmOpCode::Subraction as u8,
-                        _ => KaramelOperatorType::None as u8
-                    };
-
-                    context.opcodes.push(opcode);
+                    context.opcodes.push(self.get_assignment_opcode(operator));
                 } else {
                     self.generate_opcode(module.clone(), expression_ast, &KaramelAstType::None, context, storage_index)?;
                 }
@@ -630,15 +1179,75 @@ impl InterpreterCompiler {
                 self.generate_opcode(module.clone(), body, &KaramelAstType::None, context, storage_index)?;
                 self.generate_opcode(module.clone(), indexer, &KaramelAstType::None, context, storage_index)?;
 
-                self.generate_opcode(module.clone(), expression_ast, &KaramelAstType::None, context, storage_index)?;
+                if *operator != KaramelOperatorType::Assign {
+
+                    /* Load current value to stack */
+                    self.generate_indexer(module.clone(), body, indexer, &KaramelAstType::None, context, storage_index)?;
+                    self.generate_opcode(module.clone(), expression_ast, &KaramelAstType::None, context, storage_index)?;
+
+                    context.opcodes.push(self.get_assignment_opcode(operator));
+                } else {
+                    self.generate_opcode(module.clone(), expression_ast, &KaramelAstType::None, context, storage_index)?;
+                }
                 
                 context.opcodes.push(VmOpCode::SetItem as u8);
                 Ok(())
             },
+
+            KaramelAstType::Tuple(targets) | KaramelAstType::List(targets) => self.generate_destructuring(module.clone(), targets, operator, expression_ast, context, storage_index),
             _ => Ok(())
         }
     }
 
+    /* 'a, b = b, a' and '[a, b] = liste', value is unpacked to stack and stored to targets in order */
+    fn generate_destructuring(&self, module: Rc<OpcodeModule>, targets: &Vec<Rc<KaramelAstType>>, operator: &KaramelOperatorType, expression_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        if *operator != KaramelOperatorType::Assign {
+            return Err(KaramelErrorType::DestructuringNotValid);
+        }
+
+        self.generate_opcode(module.clone(), expression_ast, &KaramelAstType::None, context, storage_index)?;
+        context.opcodes.push(VmOpCode::Unpack as u8);
+        context.opcodes.push(targets.len() as u8);
+
+        for target in targets {
+            let symbol = match &**target {
+                KaramelAstType::Symbol(symbol) => symbol,
+                _ => return Err(KaramelErrorType::DestructuringNotValid)
+            };
+            self.check_prohibited_names(symbol)?;
+
+            match context.storages[storage_index].get_cell_location(symbol) {
+                Some(location) => {
+                    context.opcodes.push(VmOpCode::StoreCell as u8);
+                    context.opcodes.push(location);
+                },
+                None => {
+                    let location = context.storages.get_mut(storage_index).unwrap().add_variable(&*symbol);
+                    context.opcodes.push(VmOpCode::Store as u8);
+                    context.opcodes.push(location);
+                }
+            };
+        }
+        Ok(())
+    }
+
+    fn get_assignment_opcode(&self, operator: &KaramelOperatorType) -> u8 {
+        match operator {
+            KaramelOperatorType::AssignAddition       => VmOpCode::Addition as u8,
+            KaramelOperatorType::AssignDivision       => VmOpCode::Division as u8,
+            KaramelOperatorType::AssignMultiplication => VmOpCode::Multiply as u8,
+            KaramelOperatorType::AssignSubtraction    => VmOpCode::Subraction as u8,
+            KaramelOperatorType::AssignPower          => VmOpCode::Power as u8,
+            KaramelOperatorType::AssignIntegerDivision => VmOpCode::IntegerDivision as u8,
+            KaramelOperatorType::AssignBitwiseAnd     => VmOpCode::BitwiseAnd as u8,
+            KaramelOperatorType::AssignBitwiseOr      => VmOpCode::BitwiseOr as u8,
+            KaramelOperatorType::AssignBitwiseXor     => VmOpCode::BitwiseXor as u8,
+            KaramelOperatorType::AssignLeftShift      => VmOpCode::LeftShift as u8,
+            KaramelOperatorType::AssignRightShift     => VmOpCode::RightShift as u8,
+            _ => KaramelOperatorType::None as u8
+        }
+    }
+
     fn generate_binary(&self, module: Rc<OpcodeModule>, left_ast: &KaramelAstType, operator: &KaramelOperatorType, right_ast: &KaramelAstType, _: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult { 
         self.generate_opcode(module.clone(), left_ast, &KaramelAstType::None, context, storage_index)?;
         self.generate_opcode(module.clone(), right_ast, &KaramelAstType::None, context, storage_index)?;
@@ -648,6 +1257,13 @@ impl InterpreterCompiler {
             KaramelOperatorType::Multiplication => VmOpCode::Multiply as u8,
             KaramelOperatorType::Division       => VmOpCode::Division as u8,
             KaramelOperatorType::Modulo         => VmOpCode::Module as u8,
+            KaramelOperatorType::Power          => VmOpCode::Power as u8,
+            KaramelOperatorType::IntegerDivision => VmOpCode::IntegerDivision as u8,
+            KaramelOperatorType::BitwiseAnd     => VmOpCode::BitwiseAnd as u8,
+            KaramelOperatorType::BitwiseOr      => VmOpCode::BitwiseOr as u8,
+            KaramelOperatorType::BitwiseXor     => VmOpCode::BitwiseXor as u8,
+            KaramelOperatorType::LeftShift      => VmOpCode::LeftShift as u8,
+            KaramelOperatorType::RightShift     => VmOpCode::RightShift as u8,
             _ => VmOpCode::None as u8
         };
 
@@ -661,14 +1277,17 @@ impl InterpreterCompiler {
             return self.generate_not(module.clone(), expression, context, storage_index);
         }
 
+        if *operator == KaramelOperatorType::BitwiseNot { 
+            self.generate_opcode(module.clone(), expression, &KaramelAstType::None, context, storage_index)?;
+            context.opcodes.push(VmOpCode::BitwiseNot as u8);
+            return Ok(());
+        }
+
         if let KaramelAstType::Symbol(variable) = expression {
-            let location = match context.storages.get_mut(storage_index).unwrap().get_variable_location(variable) {
-                Some(location) => location,
-                _ => return Err(KaramelErrorType::ValueNotFoundInStorage)
-            };
+            let (load_opcode, store_opcode, location) = self.get_variable_opcodes(variable, context, storage_index)?;
 
             /* Load data from memory */
-            context.opcodes.push(VmOpCode::Load as u8);
+            context.opcodes.push(load_opcode as u8);
             context.opcodes.push(location);
         
             let opcode = match operator {
@@ -680,9 +1299,13 @@ impl InterpreterCompiler {
             context.opcodes.push(opcode);
 
             // Keep value at the stack if assign_to_temp is true
-            match assign_to_temp.get() {
-                true => context.opcodes.push(VmOpCode::CopyToStore as u8),
-                false => context.opcodes.push(VmOpCode::Store as u8),
+            match (assign_to_temp.get(), store_opcode) {
+                (true, VmOpCode::StoreCell) => {
+                    context.opcodes.push(VmOpCode::Dublicate as u8);
+                    context.opcodes.push(VmOpCode::StoreCell as u8);
+                },
+                (true, _) => context.opcodes.push(VmOpCode::CopyToStore as u8),
+                (false, _) => context.opcodes.push(store_opcode as u8),
             }
             
             context.opcodes.push(location);
@@ -706,7 +1329,12 @@ impl InterpreterCompiler {
     }
 
     fn create_compare(&self, context: &mut KaramelCompilerContext) -> usize {
-        context.opcodes.push(VmOpCode::Compare as u8);
+        self.create_conditional_jump(context, VmOpCode::Compare)
+    }
+
+    /* Location is relative to opcode, same as compare */
+    fn create_conditional_jump(&self, context: &mut KaramelCompilerContext, opcode: VmOpCode) -> usize {
+        context.opcodes.push(opcode as u8);
         let compare_location = context.opcodes.len();
 
         context.opcodes.push(0_u8);
@@ -787,6 +1415,109 @@ impl InterpreterCompiler {
         Ok(())
     }
 
+    fn generate_match(&self, module: Rc<OpcodeModule>, id: usize, location: (u32, u32), value: &Rc<KaramelAstType>, arms: &[Rc<KaramelMatchArm>], default: &Option<Rc<KaramelAstType>>, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        /*
+        ╔════════════════════╗
+        ║   STORE VALUE      ║
+        ╠════════════════════╣
+        ║   PATTERN CHECKS   ║
+        ║   JUMP TO NEXT ARM ║
+        ╠════════════════════╣
+        ║   BINDINGS         ║
+        ╠════════════════════╣
+        ║   GUARD            ║
+        ║   JUMP TO NEXT ARM ║
+        ╠════════════════════╣
+        ║   ARM BODY         ║
+        ╠════════════════════╣
+        ║   JUMP TO OUT OF   ║
+        ║   MATCH            ║
+        ╠════════════════════╣
+        ║   NEXT ARMS        ║
+        ╠════════════════════╣
+        ║   DEFAULT BODY     ║
+        ╚════════════════════╝
+        */
+        let mut exit_locations: Vec<usize> = Vec::new();
+        let subject = Rc::new(KaramelAstType::Symbol(MatchParser::get_value_variable(id)));
+        self.generate_opcode(module.clone(), &MatchParser::get_value_assignment(id, value.clone()), upper_ast, context, storage_index)?;
+
+        for arm in arms {
+            let lowered = LoweredPattern::new(&arm.pattern, subject.clone());
+            let mut failed_locations = Vec::new();
+
+            for check in lowered.checks.iter() {
+                self.generate_opcode(module.clone(), check, upper_ast, context, storage_index)?;
+                failed_locations.push(self.create_compare(context));
+            }
+
+            for binding in lowered.bindings.iter() {
+                self.generate_opcode(module.clone(), binding, upper_ast, context, storage_index)?;
+            }
+
+            if let Some(guard) = &arm.guard {
+                self.generate_opcode(module.clone(), guard, upper_ast, context, storage_index)?;
+                failed_locations.push(self.create_compare(context));
+            }
+
+            self.generate_opcode(module.clone(), &arm.body, upper_ast, context, storage_index)?;
+            self.create_exit_jump(context, &mut exit_locations);
+
+            for failed_location in failed_locations {
+                self.build_compare_location(context, failed_location);
+            }
+        }
+
+        match default {
+            Some(default) => self.generate_opcode(module.clone(), default, upper_ast, context, storage_index)?,
+            None => {
+                let has_catch_all = arms.last().map_or(false, |arm| arm.guard.is_none() && arm.pattern.is_catch_all());
+                if !has_catch_all {
+                    context.warnings.push(KaramelError::new(location.0, location.1 + 1, KaramelErrorType::MatchCatchAllMissing));
+                }
+            }
+        };
+
+        for exit_location in exit_locations {
+            self.build_jump_location(context, exit_location);
+        }
+
+        Ok(())
+    }
+
+    fn generate_conditional(&self, module: Rc<OpcodeModule>, condition: &KaramelAstType, true_expression: &KaramelAstType, false_expression: &KaramelAstType, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        /*
+        ╔════════════════════╗
+        ║   CONDITION        ║
+        ╠════════════════════╣
+        ║   JUMP TO FALSE    ║
+        ║   EXPRESSION       ║
+        ╠════════════════════╣
+        ║   TRUE EXPRESSION  ║
+        ╠════════════════════╣
+        ║   JUMP TO OUT OF   ║
+        ║   EXPRESSION       ║
+        ╠════════════════════╣
+        ║   FALSE EXPRESSION ║
+        ╚════════════════════╝
+        */
+        let mut exit_locations: Vec<usize> = Vec::new();
+
+        self.generate_opcode(module.clone(), condition, upper_ast, context, storage_index)?;
+        let false_location = self.create_compare(context);
+        self.generate_opcode(module.clone(), true_expression, upper_ast, context, storage_index)?;
+        self.create_exit_jump(context, &mut exit_locations);
+
+        self.build_compare_location(context, false_location);
+        self.generate_opcode(module.clone(), false_expression, upper_ast, context, storage_index)?;
+
+        for exit_location in exit_locations {
+            self.build_jump_location(context, exit_location);
+        }
+
+        Ok(())
+    }
+
     fn generate_indexer(&self, module: Rc<OpcodeModule>, body: &KaramelAstType, indexer: &KaramelAstType, upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
         self.generate_opcode(module.clone(), body, upper_ast, context, storage_index)?;
         self.generate_opcode(module.clone(), indexer, upper_ast, context, storage_index)?;
@@ -795,14 +1526,34 @@ impl InterpreterCompiler {
         Ok(())
     }
 
+    fn generate_slice(&self, module: Rc<OpcodeModule>, body: &KaramelAstType, parts: &[&Option<Rc<KaramelAstType>>], upper_ast: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult {
+        self.generate_opcode(module.clone(), body, upper_ast, context, storage_index)?;
+        for part in parts {
+            match part {
+                Some(part) => self.generate_opcode(module.clone(), part, upper_ast, context, storage_index)?,
+                None => self.generate_none(context, storage_index)?
+            };
+        }
+        context.opcodes.push(VmOpCode::GetSlice as u8);
+
+        Ok(())
+    }
+
+    /* Variables that shared with closures are kept in cells */
+    fn get_variable_opcodes(&self, variable: &str, context: &KaramelCompilerContext, storage_index: usize) -> Result<(VmOpCode, VmOpCode, u8), KaramelErrorType> {
+        let storage = &context.storages[storage_index];
+        match (storage.get_cell_location(variable), storage.get_variable_location(variable)) {
+            (Some(location), _) => Ok((VmOpCode::LoadCell, VmOpCode::StoreCell, location)),
+            (None, Some(location)) => Ok((VmOpCode::Load, VmOpCode::Store, location)),
+            _ => Err(KaramelErrorType::ValueNotFoundInStorage)
+        }
+    }
+
     fn generate_suffix_unary(&self, operator: &KaramelOperatorType, expression: &KaramelAstType, _: &KaramelAstType, context: &mut KaramelCompilerContext, storage_index: usize) -> CompilerResult { 
         if let KaramelAstType::Symbol(variable) = expression {
-            let location = match context.storages.get_mut(storage_index).unwrap().get_variable_location(variable) {
-                Some(location) => location,
-                _ => return Err(KaramelErrorType::ValueNotFoundInStorage)
-            };
+            let (load_opcode, store_opcode, location) = self.get_variable_opcodes(variable, context, storage_index)?;
 
-            context.opcodes.push(VmOpCode::Load as u8);
+            context.opcodes.push(load_opcode as u8);
             context.opcodes.push(location);
             context.opcodes.push(VmOpCode::Dublicate as u8);
 
@@ -814,7 +1565,7 @@ impl InterpreterCompiler {
             };
     
             context.opcodes.push(opcode);
-            context.opcodes.push(VmOpCode::Store as u8);
+            context.opcodes.push(store_opcode as u8);
             context.opcodes.push(location);
             return Ok(());
         }
@@ -852,7 +1603,7 @@ mod tests {
         let storage_builder: StorageBuilder = StorageBuilder::new();
         let mut compiler_options = StorageBuilderOption { max_stack: 0 };
 
-        let function_define = FunctionReference::opcode_function("test".to_string(), Vec::new(), Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
+        let function_define = FunctionReference::opcode_function("test".to_string(), Vec::new(), Vec::new(), false, Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
 
         let mut functions = Vec::new();
         functions.push(function_define);
@@ -870,7 +1621,7 @@ mod tests {
         let storage_builder: StorageBuilder = StorageBuilder::new();
         let mut compiler_options = StorageBuilderOption { max_stack: 0 };
 
-        let function_define = FunctionReference::opcode_function("yazı".to_string(), Vec::new(), Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
+        let function_define = FunctionReference::opcode_function("yazı".to_string(), Vec::new(), Vec::new(), false, Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
 
         let mut functions = Vec::new();
         functions.push(function_define);
@@ -892,7 +1643,7 @@ mod tests {
         let storage_builder: StorageBuilder = StorageBuilder::new();
         let mut compiler_options = StorageBuilderOption { max_stack: 0 };
 
-        let function_define = FunctionReference::opcode_function("döndür".to_string(), Vec::new(), Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
+        let function_define = FunctionReference::opcode_function("döndür".to_string(), Vec::new(), Vec::new(), false, Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
 
         let mut functions = Vec::new();
         functions.push(function_define);
@@ -914,7 +1665,7 @@ mod tests {
         let storage_builder: StorageBuilder = StorageBuilder::new();
         let mut compiler_options = StorageBuilderOption { max_stack: 0 };
 
-        let function_define = FunctionReference::opcode_function("sayı".to_string(), Vec::new(), Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
+        let function_define = FunctionReference::opcode_function("sayı".to_string(), Vec::new(), Vec::new(), false, Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
 
         let mut functions = Vec::new();
         functions.push(function_define);
@@ -937,7 +1688,7 @@ mod tests {
         let storage_builder: StorageBuilder = StorageBuilder::new();
         let mut compiler_options = StorageBuilderOption { max_stack: 0 };
 
-        let function_define = FunctionReference::opcode_function("test".to_string(), vec!["test".to_string()], Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
+        let function_define = FunctionReference::opcode_function("test".to_string(), vec!["test".to_string()], Vec::new(), false, Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
 
         let mut functions = Vec::new();
         functions.push(function_define);
@@ -955,7 +1706,7 @@ mod tests {
         let storage_builder: StorageBuilder = StorageBuilder::new();
         let mut compiler_options = StorageBuilderOption { max_stack: 0 };
 
-        let function_define = FunctionReference::opcode_function("test".to_string(), vec!["sayı".to_string()], Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
+        let function_define = FunctionReference::opcode_function("test".to_string(), vec!["sayı".to_string()], Vec::new(), false, Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
 
         let mut functions = Vec::new();
         functions.push(function_define);
@@ -977,7 +1728,7 @@ mod tests {
         let storage_builder: StorageBuilder = StorageBuilder::new();
         let mut compiler_options = StorageBuilderOption { max_stack: 0 };
 
-        let function_define = FunctionReference::opcode_function("döndür".to_string(), vec!["sayı".to_string()], Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
+        let function_define = FunctionReference::opcode_function("döndür".to_string(), vec!["sayı".to_string()], Vec::new(), false, Rc::new(KaramelAstType::None), Rc::new(DummyModule::new()), 0, 0, true);
 
         let mut functions = Vec::new();
         functions.push(function_define);
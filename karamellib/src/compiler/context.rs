@@ -1,8 +1,13 @@
 use std::borrow::Borrow;
+use std::collections::HashMap;
 use std::{cell::RefCell, ptr, rc::Rc};
 use crate::buildin::num::{NumModule};
+use crate::buildin::task::TaskModule;
+use crate::vm::scheduler::Scheduler;
 
-use crate::{buildin::{Class, Module, ModuleCollection, base_functions, class::{dict, get_empty_class, list, number, proxy, text}, debug, io}, compiler::scope::Scope};
+use crate::{buildin::{Class, Module, ModuleCollection, base_functions, class::{dict, generator, get_empty_class, list, number, proxy, set, text, tuple}, debug, io}, compiler::scope::{Scope, ErrorHandler}};
+use crate::error::{KaramelError, KaramelErrorType};
+use crate::compiler::ast::KaramelAstType;
 
 use super::{KaramelPrimative, StaticStorage, function::{FunctionReference, FunctionType, FunctionFlag}, module::OpcodeModule};
 
@@ -20,7 +25,19 @@ pub struct KaramelCompilerContext {
     pub modules: ModuleCollection,
     pub loop_breaks: Vec<usize>,
     pub loop_continues: Vec<usize>,
+
+    /* 'sonunda' bodies of the active 'dene' blocks while compiling, used to close handlers before leaving block */
+    pub try_blocks: Vec<Option<Rc<KaramelAstType>>>,
+    pub loop_try_depth: usize,
+
     pub scopes: Vec<Scope>,
+    pub error_handlers: Vec<ErrorHandler>,
+
+    /* Keyword argument names for the next function call */
+    pub keyword_arguments: Vec<Rc<String>>,
+
+    /* Problems that does not stop compilation */
+    pub warnings: Vec<KaramelError>,
     pub current_scope: *mut Scope,
     pub scope_index: usize,
     pub functions : Vec<Rc<FunctionReference>>,
@@ -28,7 +45,14 @@ pub struct KaramelCompilerContext {
     pub stdout: Option<RefCell<String>>,
     pub stderr: Option<RefCell<String>>,
     pub opcodes_ptr: *mut u8,
-    pub primative_classes: Vec<Rc<dyn Class>>
+    pub primative_classes: Vec<Rc<dyn Class>>,
+    pub imports: HashMap<Vec<String>, HashMap<String, Rc<FunctionReference>>>,
+
+    /* Location of the last 'Halt', tasks return there and finish */
+    pub halt_location: usize,
+
+    /* Tasks created with 'görev' module */
+    pub scheduler: Scheduler
 }
 
 impl  KaramelCompilerContext {
@@ -40,6 +64,11 @@ impl  KaramelCompilerContext {
             modules: ModuleCollection::new(),
             loop_breaks: Vec::new(),
             loop_continues: Vec::new(),
+            try_blocks: Vec::new(),
+            loop_try_depth: 0,
+            error_handlers: Vec::new(),
+            keyword_arguments: Vec::new(),
+            warnings: Vec::new(),
             scopes: Vec::new(),
             current_scope: ptr::null_mut(),
             scope_index: 0,
@@ -49,7 +78,10 @@ impl  KaramelCompilerContext {
             stderr: None,
             opcodes_ptr: ptr::null_mut(),
             primative_classes: Vec::new(),
-            main_module: ptr::null_mut()
+            imports: HashMap::new(),
+            main_module: ptr::null_mut(),
+            halt_location: 0,
+            scheduler: Scheduler::default()
         };
 
         compiler.primative_classes.push(number::get_primative_class());
@@ -59,14 +91,16 @@ impl  KaramelCompilerContext {
         compiler.primative_classes.push(get_empty_class());
         compiler.primative_classes.push(get_empty_class());
         compiler.primative_classes.push(get_empty_class());
-        compiler.primative_classes.push(get_empty_class());
         compiler.primative_classes.push(proxy::get_primative_class());
-        compiler.primative_classes.push(get_empty_class());
+        compiler.primative_classes.push(tuple::get_primative_class());
+        compiler.primative_classes.push(generator::get_primative_class());
+        compiler.primative_classes.push(set::get_primative_class());
 
         compiler.add_module(base_functions::BaseFunctionsModule::new());
         compiler.add_module(io::IoModule::new());
         compiler.add_module(NumModule::new());
         compiler.add_module(debug::DebugModule::new());
+        compiler.add_module(TaskModule::new());
 
         for _ in 0..32{
             compiler.scopes.push(Scope::empty());
@@ -92,7 +126,7 @@ impl  KaramelCompilerContext {
         self.functions.push(information);
     }
 
-    pub fn add_class(&mut self, class_info: Rc<dyn Class + Sync + Send>) {
+    pub fn add_class(&mut self, class_info: Rc<dyn Class>) {
         self.classes.push(class_info.clone());
     }
 
@@ -103,7 +137,7 @@ impl  KaramelCompilerContext {
             for (_, module) in self.modules.iter() {
                 for function_reference in module.get_methods().iter() {
                     let result = match &function_reference.callback {
-                        FunctionType::Native(_) =>
+                        FunctionType::Native(_) | FunctionType::Vm(_) =>
                             function_reference.module.get_path() == module_path && 
                             &function_reference.name == name.borrow(),
                         FunctionType::Opcode => 
@@ -120,12 +154,37 @@ impl  KaramelCompilerContext {
             
             search_storage = match self.storages[search_storage].get_parent_location() {
                 Some(parent_storage_index) => match parent_storage_index == search_storage {
-                    true => return None,
+                    true => break,
                     false => parent_storage_index.into()
                 },
-                None => return None
+                None => break
             };
         }
+
+        /* Function imported with 'kullan' */
+        self.imports.get(module_path).and_then(|imports| imports.get(name.borrow()).cloned())
+    }
+
+    pub fn has_import(&self, module_path: &Vec<String>, name: &str) -> bool {
+        self.imports.get(module_path).map_or(false, |imports| imports.contains_key(name))
+    }
+
+    /* Same name could not be imported from different modules or defined in the module */
+    pub fn add_import(&mut self, module_path: &Vec<String>, reference: Rc<FunctionReference>) -> Result<(), KaramelErrorType> {
+        if let Some(module) = self.modules.get_module(module_path) {
+            if module.get_method(&reference.name).is_some() {
+                return Err(KaramelErrorType::ImportConflict(reference.name.to_string()));
+            }
+        }
+
+        let imports = self.imports.entry(module_path.to_vec()).or_insert_with(HashMap::new);
+        match imports.get(&reference.name) {
+            Some(imported) if !Rc::ptr_eq(imported, &reference) => Err(KaramelErrorType::ImportConflict(reference.name.to_string())),
+            _ => {
+                imports.insert(reference.name.to_string(), reference);
+                Ok(())
+            }
+        }
     }
 
     pub fn get_class(&self, value: &KaramelPrimative) -> Rc<dyn Class > {
@@ -138,7 +197,7 @@ impl  KaramelCompilerContext {
         let primative_search = self.primative_classes.iter().find(|&item| item.get_class_name() == name);
         match primative_search {
             Some(class) => Some(class.clone()),
-            None => None
+            None => self.classes.iter().find(|&item| item.get_class_name() == name).map(|class| class.clone())
         }
     }
 
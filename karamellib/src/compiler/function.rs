@@ -1,28 +1,34 @@
 use std::borrow::Borrow;
 use std::{iter::Skip, rc::Rc, vec::Vec};
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::cell::Cell;
+use std::mem;
 use std::slice::Iter;
 use std::iter::Take;
 use bitflags::bitflags;
 
-use crate::buildin::{DummyModule, Module};
-use crate::compiler::scope::Scope;
+use crate::buildin::{Class, ClassProperty, DummyModule, Module};
+use crate::compiler::scope::{Scope, Generator};
 use crate::error::KaramelErrorType;
 use crate::{inc_memory_index, dec_memory_index, get_memory_index};
 use crate::types::*;
 use crate::compiler::value::EMPTY_OBJECT;
 use crate::compiler::context::KaramelCompilerContext;
 
+use super::class::{OpcodeClass, CONSTRUCTOR_NAME, CONSTRUCTOR_ASCII_NAME, INSTANCE_NAME};
 use super::module::OpcodeModule;
 use super::{KaramelPrimative, StaticStorage};
-use super::ast::KaramelAstType;
+use super::ast::{KaramelAstType, KaramelFunctionType};
+use crate::syntax::loops::LoopType;
 use super::storage_builder::{StorageBuilder, StorageBuilderOption};
 
 pub type NativeCallResult = Result<VmObject, KaramelErrorType>;
 pub type NativeCall       = fn(FunctionParameter) -> NativeCallResult;
 pub type IndexerGetCall   = fn (VmObject, f64) -> NativeCallResult ;
 pub type IndexerSetCall   = fn (VmObject, f64, VmObject) -> NativeCallResult ;
+pub type IndexerSliceCall = fn (VmObject, Option<f64>, Option<f64>, Option<f64>) -> NativeCallResult ;
+pub type VmCall           = unsafe fn(&FunctionReference, &mut KaramelCompilerContext, Option<VmObject>) -> Result<(), KaramelErrorType>;
 
 #[derive(Debug)]
 pub struct FunctionParameter<'a> {
@@ -87,6 +93,7 @@ bitflags! {
         const STATIC       = 0b00000001;
         const IN_CLASS     = 0b00000010;
         const MODULE_LEVEL = 0b00000100;
+        const GENERATOR    = 0b00001000;
     }
 }
 
@@ -96,12 +103,15 @@ pub struct FunctionReference {
     pub flags: FunctionFlag,
     pub name: String,
     pub arguments: Vec<String>,
+    pub defaults: Vec<Rc<KaramelAstType>>,
+    pub variadic: bool,
     pub defined_storage_index: usize,
     pub storage_index: usize,
     pub opcode_location: Cell<usize>,
     pub used_locations: RefCell<Vec<u16>>,
     pub opcode_body: Option<Rc<KaramelAstType>>,
-    pub module: Rc<dyn Module>
+    pub module: Rc<dyn Module>,
+    pub captures: Vec<Rc<Cell<VmObject>>>
 }
 
 unsafe impl Send for FunctionReference {}
@@ -110,35 +120,98 @@ unsafe impl Sync for FunctionReference {}
 #[derive(Clone)]
 pub enum FunctionType {
     Native(NativeCall),
-    Opcode
+    Opcode,
+
+    /* Functions that change VM state, like continuing generators or switching tasks */
+    Vm(VmCall)
 }
 
 impl Default for FunctionType {
     fn default() -> Self { FunctionType::Opcode }
 }
 
+/* Where the function argument's value comes from */
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum ArgumentSource {
+    Value(usize),
+    Rest(usize, usize),
+    Default
+}
+
 impl FunctionReference {
     pub fn execute(&self, compiler: &mut KaramelCompilerContext, base: Option<VmObject>) -> Result<(), KaramelErrorType>{
         unsafe {
             match self.callback {
                 FunctionType::Native(func) => FunctionReference::native_function_call(&self, func, compiler, base),
-                FunctionType::Opcode => FunctionReference::opcode_function_call(&self,  compiler)
+                FunctionType::Opcode => FunctionReference::opcode_function_call(&self,  compiler, base),
+                FunctionType::Vm(func) => func(&self, compiler, base)
             }
         }
     }
 
+    /// Call arguments are matched with function arguments. Positional values come first, keyword values follow them.
+    /// Offset skips the arguments that passed by VM, like class instance.
+    pub fn match_arguments<T: Borrow<String>>(&self, positional_size: usize, keywords: &[T], offset: usize) -> Result<Vec<ArgumentSource>, KaramelErrorType> {
+        let arguments     = &self.arguments[offset.min(self.arguments.len())..];
+        let fixed_size    = arguments.len() - self.variadic as usize;
+        let required_size = fixed_size.saturating_sub(self.defaults.len());
+
+        if positional_size > fixed_size && !self.variadic {
+            return Err(KaramelErrorType::FunctionArgumentNotMatching {
+                function: self.name.to_string(),
+                expected: fixed_size as u8,
+                found: positional_size as u8
+            });
+        }
+
+        let mut sources: Vec<Option<ArgumentSource>> = vec![None; arguments.len()];
+        for position in 0..positional_size.min(fixed_size) {
+            sources[position] = Some(ArgumentSource::Value(position));
+        }
+
+        if self.variadic {
+            sources[fixed_size] = Some(ArgumentSource::Rest(fixed_size.min(positional_size), positional_size));
+        }
+
+        for (index, keyword) in keywords.iter().enumerate() {
+            match arguments[..fixed_size].iter().position(|argument| argument == keyword.borrow()) {
+                Some(position) if sources[position].is_none() => sources[position] = Some(ArgumentSource::Value(positional_size + index)),
+                _ => return Err(KaramelErrorType::KeywordArgumentNotValid {
+                    function: self.name.to_string(),
+                    argument: keyword.borrow().to_string()
+                })
+            };
+        }
+
+        let found = sources[..required_size].iter().filter(|source| source.is_some()).count();
+        if found < required_size {
+            return Err(KaramelErrorType::FunctionArgumentNotMatching {
+                function: self.name.to_string(),
+                expected: required_size as u8,
+                found: found as u8
+            });
+        }
+
+        Ok(sources.into_iter().map(|source| source.unwrap_or(ArgumentSource::Default)).collect())
+    }
+
     pub fn buildin_function(func: NativeCall, name: String, flags: FunctionFlag) -> Rc<FunctionReference> {
         let reference = FunctionReference {
             callback: FunctionType::Native(func),
             flags: flags,
             name,
             arguments: Vec::new(),
+            defaults: Vec::new(),
+            variadic: false,
             storage_index: 0,
             opcode_location: Cell::new(0),
             used_locations: RefCell::new(Vec::new()),
             defined_storage_index: 0,
             opcode_body: None,
-            module: Rc::new(DummyModule::new())
+            module: Rc::new(DummyModule::new()),
+            captures: Vec::new()
         };
         Rc::new(reference)
     }
@@ -149,34 +222,87 @@ impl FunctionReference {
             flags: FunctionFlag::STATIC,
             name,
             arguments: Vec::new(),
+            defaults: Vec::new(),
+            variadic: false,
             storage_index: 0,
             opcode_location: Cell::new(0),
             used_locations: RefCell::new(Vec::new()),
             defined_storage_index: 0,
             opcode_body: None,
-            module
+            module,
+            captures: Vec::new()
         };
         Rc::new(reference)
     }
 
-    pub fn opcode_function(name: String, arguments: Vec<String>, body: Rc<KaramelAstType>, module: Rc<dyn Module>, storage_index: usize, defined_storage_index: usize, module_level: bool) -> Rc<FunctionReference> {
+    pub fn vm_function(func: VmCall, name: String, flags: FunctionFlag, module: Rc<dyn Module>) -> Rc<FunctionReference> {
+        let reference = FunctionReference {
+            callback: FunctionType::Vm(func),
+            flags,
+            name,
+            arguments: Vec::new(),
+            defaults: Vec::new(),
+            variadic: false,
+            storage_index: 0,
+            opcode_location: Cell::new(0),
+            used_locations: RefCell::new(Vec::new()),
+            defined_storage_index: 0,
+            opcode_body: None,
+            module,
+            captures: Vec::new()
+        };
+        Rc::new(reference)
+    }
+
+    pub fn opcode_function(name: String, arguments: Vec<String>, defaults: Vec<Rc<KaramelAstType>>, variadic: bool, body: Rc<KaramelAstType>, module: Rc<dyn Module>, storage_index: usize, defined_storage_index: usize, module_level: bool) -> Rc<FunctionReference> {
         let mut reference = FunctionReference {
             callback: FunctionType::Opcode,
             flags: FunctionFlag::STATIC,
             module,
             name,
             arguments,
+            defaults,
+            variadic,
             storage_index,
             defined_storage_index,
             opcode_location: Cell::new(0),
             used_locations: RefCell::new(Vec::new()),
-            opcode_body: Some(body.clone())
+            opcode_body: Some(body.clone()),
+            captures: Vec::new()
         };
 
         if module_level {
             reference.flags = reference.flags | FunctionFlag::MODULE_LEVEL;
         }
 
+        if has_yield(&body) {
+            reference.flags = reference.flags | FunctionFlag::GENERATOR;
+        }
+
+        Rc::new(reference)
+    }
+
+    pub fn opcode_method(name: String, arguments: Vec<String>, defaults: Vec<Rc<KaramelAstType>>, variadic: bool, body: Rc<KaramelAstType>, module: Rc<dyn Module>, storage_index: usize, defined_storage_index: usize) -> Rc<FunctionReference> {
+        let mut reference = FunctionReference {
+            callback: FunctionType::Opcode,
+            flags: FunctionFlag::IN_CLASS,
+            module,
+            name,
+            arguments,
+            defaults,
+            variadic,
+            storage_index,
+            defined_storage_index,
+            opcode_location: Cell::new(0),
+            used_locations: RefCell::new(Vec::new()),
+            opcode_body: Some(body.clone()),
+            captures: Vec::new()
+        };
+
+        if has_yield(&body) {
+            reference.flags = reference.flags | FunctionFlag::GENERATOR;
+        }
+
         Rc::new(reference)
     }
 
@@ -185,6 +311,15 @@ impl FunctionReference {
         let call_return_assign_to_temp = *compiler.opcodes_ptr.offset(2) != 0;
         let before = get_memory_index!(compiler);
 
+        /* Native functions does not know their argument names */
+        if let Some(keyword) = mem::take(&mut compiler.keyword_arguments).first() {
+            dec_memory_index!(compiler, total_args as usize);
+            return Err(KaramelErrorType::KeywordArgumentNotValid {
+                function: reference.name.to_string(),
+                argument: keyword.to_string()
+            });
+        }
+
         let parameter = match reference.flags {
             FunctionFlag::IN_CLASS => FunctionParameter::new(&(*compiler.current_scope).stack, source, get_memory_index!(compiler) as usize, karamel_dbg!(total_args), &compiler.stdout, &compiler.stderr),
             _ => FunctionParameter::new(&(*compiler.current_scope).stack, source, get_memory_index!(compiler) as usize, karamel_dbg!(total_args), &compiler.stdout, &compiler.stderr)
@@ -204,88 +339,230 @@ impl FunctionReference {
             },
             Err(error) => {
                 dec_memory_index!(compiler, total_args as usize);
-                println!("{:?}", error);
                 Err(error)
             }
         }
     }
 
-    fn opcode_function_call(reference: &FunctionReference, options: &mut KaramelCompilerContext) -> Result<(), KaramelErrorType> {
+    fn opcode_function_call(reference: &FunctionReference, options: &mut KaramelCompilerContext, base: Option<VmObject>) -> Result<(), KaramelErrorType> {
         unsafe {
             let argument_size              = *options.opcodes_ptr.offset(1);
             let call_return_assign_to_temp = *options.opcodes_ptr.offset(2) != 0;
             let old_index                  = options.opcodes_ptr.offset(2);
-            options.opcodes_ptr            = options.opcodes.as_mut_ptr().offset(reference.opcode_location.get() as isize);
-            options.scope_index           += 1;
-
-            if argument_size != *options.opcodes_ptr {
-                return Err(KaramelErrorType::FunctionArgumentNotMatching {
-                    function: reference.name.to_string(),
-                    expected: argument_size, 
-                    found: *options.opcodes_ptr
-                });
-            }
+            FunctionReference::create_call_scope(reference, options, base, argument_size, call_return_assign_to_temp, old_index)
+        }
+    }
 
-            let memory_index = get_memory_index!(options) as usize;
-            let arguments = &(*options.current_scope).stack[memory_index - argument_size as usize..memory_index];
-            dec_memory_index!(options, argument_size.into());
+    /// New scope is created for the function and arguments are moved from current stack. Execution continues from 'old_index' after return.
+    pub unsafe fn create_call_scope(reference: &FunctionReference, options: &mut KaramelCompilerContext, base: Option<VmObject>, argument_size: u8, call_return_assign_to_temp: bool, old_index: *mut u8) -> Result<(), KaramelErrorType> {
+        let keywords = mem::take(&mut options.keyword_arguments);
 
-            if options.scopes.len() <= options.scope_index {
-                options.scopes.resize(options.scopes.len() * 2, Scope::empty());
-            }
+        /* Class methods receive object as a first argument */
+        let instance = match reference.flags.contains(FunctionFlag::IN_CLASS) {
+            true => base,
+            false => None
+        };
+
+        let instance_size = instance.is_some() as usize;
+
+        /* Default, keyword and variadic arguments need to be matched, others passed as is */
+        let sources = match keywords.is_empty() && !reference.variadic && argument_size as usize + instance_size == reference.arguments.len() {
+            true => None,
+            false => Some(reference.match_arguments(argument_size as usize - keywords.len(), &keywords, instance_size)?)
+        };
+
+        options.opcodes_ptr            = options.opcodes.as_mut_ptr().offset(reference.opcode_location.get() as isize);
+        options.scope_index           += 1;
+
+        let memory_index = get_memory_index!(options) as usize;
+        let arguments = &(*options.current_scope).stack[memory_index - argument_size as usize..memory_index];
+        dec_memory_index!(options, argument_size.into());
 
-            let mut scope = &mut options.scopes[options.scope_index];
-            let storage = &mut options.storages[reference.storage_index];
-            
-            /*
-            TODO: fast but has bug
-            if scope.storage_index == -1 {
-                scope.memory = storage.get_memory();
-                scope.stack.resize(storage.get_temp_size() as usize, EMPTY_OBJECT);
-                scope.storage_index = reference.storage_index as isize;
-            }*/
+        let matched_arguments: Option<Vec<VmObject>> = sources.as_ref().map(|sources| sources.iter().map(|source| match source {
+            ArgumentSource::Value(index) => arguments[*index],
+            ArgumentSource::Rest(start, end) => VmObject::native_convert(KaramelPrimative::List(RefCell::new(arguments[*start..*end].to_vec()))),
+            ArgumentSource::Default => EMPTY_OBJECT
+        }).collect());
 
+        let arguments = match &matched_arguments {
+            Some(matched_arguments) => &matched_arguments[..],
+            None => arguments
+        };
+
+        if options.scopes.len() <= options.scope_index {
+            options.scopes.resize(options.scopes.len() * 2, Scope::empty());
+        }
+
+        let mut scope = &mut options.scopes[options.scope_index];
+        let storage = &mut options.storages[reference.storage_index];
+        
+        /*
+        TODO: fast but has bug
+        if scope.storage_index == -1 {
             scope.memory = storage.get_memory();
             scope.stack.resize(storage.get_temp_size() as usize, EMPTY_OBJECT);
             scope.storage_index = reference.storage_index as isize;
+        }*/
+
+        scope.memory = storage.get_memory();
+        scope.stack.resize(storage.get_temp_size() as usize, EMPTY_OBJECT);
+        scope.storage_index = reference.storage_index as isize;
 
-            scope.stack_ptr = scope.stack.as_mut_ptr();
-            scope.memory_ptr = scope.memory.as_mut_ptr();
+        scope.stack_ptr = scope.stack.as_mut_ptr();
+        scope.memory_ptr = scope.memory.as_mut_ptr();
 
-            scope.location                   = old_index;
-            scope.const_size                 = storage.get_constant_size();
-            scope.call_return_assign_to_temp = call_return_assign_to_temp;
+        /* New cells for closure variables, captured cells comes from closure */
+        scope.cells.clear();
+        for _ in 0..storage.get_closure_size() {
+            scope.cells.push(Rc::new(Cell::new(EMPTY_OBJECT)));
+        }
+        scope.cells.extend(reference.captures.iter().cloned());
 
-            options.current_scope = scope;
-            
+        /* Default values are calculated at function for not passed arguments */
+        scope.missing_arguments.clear();
+        if let Some(sources) = &sources {
+            scope.missing_arguments.resize(instance_size, false);
+            scope.missing_arguments.extend(sources.iter().map(|source| *source == ArgumentSource::Default));
+        }
 
-            if argument_size > 0 {
-                for index in 0..argument_size {
-                    *scope.stack_ptr = arguments[argument_size as usize-index as usize - 1];
-                    inc_memory_index!(options, 1);
-                }
+        scope.generator                  = None;
+        scope.location                   = old_index;
+        scope.const_size                 = storage.get_constant_size();
+        scope.call_return_assign_to_temp = call_return_assign_to_temp;
+
+        options.current_scope = scope;
+
+        for argument in arguments.iter().rev() {
+            *scope.stack_ptr = *argument;
+            inc_memory_index!(options, 1);
+        }
+
+        if let Some(object) = instance {
+            *scope.stack_ptr = object;
+            inc_memory_index!(options, 1);
+        }
+
+        /* Generator function does not run until first value requested, scope is moved to generator */
+        if reference.flags.contains(FunctionFlag::GENERATOR) {
+            let scope = mem::replace(&mut options.scopes[options.scope_index], Scope::empty());
+            let generator = Generator::new(reference.name.to_string(), scope, options.opcodes_ptr);
+
+            options.scope_index  -= 1;
+            options.current_scope = &mut options.scopes[options.scope_index] as *mut Scope;
+            options.opcodes_ptr   = old_index;
+
+            if call_return_assign_to_temp {
+                *(*options.current_scope).stack_ptr = VmObject::native_convert(KaramelPrimative::Generator(Rc::new(RefCell::new(generator))));
+                inc_memory_index!(options, 1);
             }
         }
         Ok(())
     }
+
+    pub unsafe fn resume_function_call(reference: &FunctionReference, compiler: &mut KaramelCompilerContext, source: Option<VmObject>) -> Result<(), KaramelErrorType> {
+        let total_args                 = *compiler.opcodes_ptr.offset(1);
+        let call_return_assign_to_temp = *compiler.opcodes_ptr.offset(2) != 0;
+        compiler.keyword_arguments.clear();
+
+        if total_args != 0 {
+            dec_memory_index!(compiler, total_args as usize);
+            return Err(KaramelErrorType::FunctionArgumentNotMatching {
+                function: reference.name.to_string(),
+                expected: 0,
+                found: total_args
+            });
+        }
+
+        let value = match source {
+            Some(source) => source.deref(),
+            None => return Err(KaramelErrorType::FunctionNotFound(reference.name.to_string()))
+        };
+
+        match &*value {
+            KaramelPrimative::Generator(generator) => resume_generator(compiler, generator.clone(), compiler.opcodes_ptr.offset(2), call_return_assign_to_temp, false),
+            _ => Err(KaramelErrorType::NotCallable(value.clone()))
+        }
+    }
+}
+
+/* Generator continues from the last 'üret', scope is moved back to the scope list until next 'üret' */
+pub unsafe fn resume_generator(context: &mut KaramelCompilerContext, generator: Rc<RefCell<Generator>>, return_location: *mut u8, assign_to_temp: bool, iterating: bool) -> Result<(), KaramelErrorType> {
+    let (mut scope, location, error_handlers) = {
+        let mut state = generator.borrow_mut();
+        if state.finished {
+            return Err(KaramelErrorType::GeneratorFinished);
+        }
+
+        let scope = match state.scope.take() {
+            Some(scope) => scope,
+            None => return Err(KaramelErrorType::GeneratorAlreadyRunning)
+        };
+
+        state.iterating = iterating;
+        (scope, state.location, mem::take(&mut state.error_handlers))
+    };
+
+    scope.location                   = return_location;
+    scope.call_return_assign_to_temp = assign_to_temp;
+    scope.generator                  = Some(generator);
+
+    context.scope_index += 1;
+    if context.scopes.len() <= context.scope_index {
+        context.scopes.resize(context.scopes.len() * 2, Scope::empty());
+    }
+
+    context.scopes[context.scope_index] = scope;
+    context.current_scope = &mut context.scopes[context.scope_index] as *mut Scope;
+    context.opcodes_ptr   = location;
+
+    /* Generator could be resumed from different scope depth */
+    for mut handler in error_handlers {
+        handler.scope_index = context.scope_index;
+        context.error_handlers.push(handler);
+    }
+    Ok(())
+}
+
+/* Function with 'üret' is generator, inner functions are not checked */
+fn has_yield(ast: &KaramelAstType) -> bool {
+    match ast {
+        KaramelAstType::Yield(_) => true,
+        KaramelAstType::FunctionDefination { .. } |
+        KaramelAstType::ClassDefination { .. } |
+        KaramelAstType::Lambda(_) => false,
+        _ => ast.children().iter().any(|child| has_yield(child))
+    }
 }
 
 pub fn find_function_definition_type(module: Rc<OpcodeModule>, ast: Rc<KaramelAstType>, options: &mut KaramelCompilerContext, current_storage_index: usize, module_level: bool) -> CompilerResult {
     match ast.borrow() {
-        KaramelAstType::FunctionDefination { name, arguments, body  } => {
+        KaramelAstType::FunctionDefination { name, arguments, defaults, variadic, types: _, body  } => {
+            /* Module level function could not hide function that imported with 'kullan' */
+            if module_level && options.has_import(module.get_path(), name) {
+                return Err(KaramelErrorType::ImportConflict(name.to_string()));
+            }
+
             /* Create new storage for new function */
             let new_storage_index = options.storages.len();
             options.storages.push(StaticStorage::new(new_storage_index));
             options.storages[new_storage_index].set_parent_location(current_storage_index);
 
-            let function = FunctionReference::opcode_function(name.to_string(), arguments.to_vec(), body.clone(), module.clone(), new_storage_index, current_storage_index, module_level);
+            let function = FunctionReference::opcode_function(name.to_string(), arguments.to_vec(), defaults.to_vec(), *variadic, body.clone(), module.clone(), new_storage_index, current_storage_index, module_level);
             let old_function = module.functions.borrow_mut().insert(name.to_string(), function.clone());
 
             if let Some(_) = old_function {
                 return Err(KaramelErrorType::FunctionAlreadyDefined(name.to_string()));
             }
-            
-            find_function_definition_type(module.clone(), body.clone(), options, new_storage_index, false)?;
+
+            prepare_closure_variables(options, current_storage_index, new_storage_index, arguments, defaults, body);
+            for item in defaults.iter().chain(std::iter::once(body)) {
+                find_function_definition_type(module.clone(), item.clone(), options, new_storage_index, false)?;
+            }
+
+            /* Arguments should be placed at the beginning of the variables */
+            for argument in arguments {
+                options.storages[new_storage_index].add_variable(argument);
+            }
 
             let storage_builder = StorageBuilder::new();
             let mut builder_option = StorageBuilderOption { max_stack: 0 };
@@ -293,18 +570,257 @@ pub fn find_function_definition_type(module: Rc<OpcodeModule>, ast: Rc<KaramelAs
 
             //options.storages[current_storage_index].add_static_data(name, Rc::new(KaramelPrimative::Function(function.clone(), None)));
             options.storages[current_storage_index].add_constant(Rc::new(KaramelPrimative::Function(function.clone(), None)));
+        },
+        KaramelAstType::ClassDefination { name, fields, methods } => {
+            find_class_definition_type(module.clone(), name, fields, methods, options, current_storage_index)?;
+        },
+        KaramelAstType::Lambda(defination) => {
+            find_function_definition_type(module.clone(), defination.clone(), options, current_storage_index, module_level)?;
+        },
+        _ => {
+            for child in ast.children() {
+                find_function_definition_type(module.clone(), child, options, current_storage_index, module_level)?;
+            }
+        }
+    }
 
-            for argument in arguments {
-                options.storages[new_storage_index].add_variable(argument);
+    Ok(())
+}
+
+fn find_class_definition_type(module: Rc<OpcodeModule>, name: &String, fields: &[Rc<KaramelAstType>], methods: &[Rc<KaramelAstType>], options: &mut KaramelCompilerContext, current_storage_index: usize) -> CompilerResult {
+    if options.find_class(name.to_string(), module.get_path(), current_storage_index).is_some() {
+        return Err(KaramelErrorType::ClassAlreadyDefined(name.to_string()));
+    }
+
+    let mut properties = HashMap::new();
+    let mut references = Vec::new();
+    let mut has_constructor = false;
+
+    for method in methods {
+        if let KaramelAstType::FunctionDefination { name: method_name, arguments, defaults, variadic, types: _, body } = &**method {
+            let is_constructor = method_name == CONSTRUCTOR_NAME || method_name == CONSTRUCTOR_ASCII_NAME;
+            let (method_name, body) = match is_constructor {
+                true => (CONSTRUCTOR_NAME.to_string(), build_constructor_body(fields, Some(body.clone()))),
+                false => (method_name.to_string(), body.clone())
+            };
+
+            /* Instance is the first argument for all methods */
+            let mut method_arguments = vec![INSTANCE_NAME.to_string()];
+            method_arguments.extend(arguments.iter().cloned());
+
+            let function = create_method(module.clone(), method_name.to_string(), method_arguments, defaults.to_vec(), *variadic, body, options, current_storage_index);
+            if let Some(_) = properties.insert(method_name.to_string(), ClassProperty::Function(function.clone())) {
+                return Err(KaramelErrorType::FunctionAlreadyDefined(method_name));
+            }
+
+            has_constructor |= is_constructor;
+            references.push(function);
+        }
+    }
+
+    /* Fields are initialized in constructor */
+    if !has_constructor {
+        let function = create_method(module.clone(), CONSTRUCTOR_NAME.to_string(), vec![INSTANCE_NAME.to_string()], Vec::new(), false, build_constructor_body(fields, None), options, current_storage_index);
+        properties.insert(CONSTRUCTOR_NAME.to_string(), ClassProperty::Function(function.clone()));
+        references.push(function);
+    }
+
+    /* Class should be registered before methods, they might use it */
+    let class: Rc<dyn Class> = Rc::new(OpcodeClass::new(name.to_string(), properties));
+    options.add_class(class.clone());
+    options.storages[current_storage_index].add_constant(Rc::new(KaramelPrimative::Class(class)));
+
+    for reference in references {
+        let body = reference.opcode_body.as_ref().unwrap().clone();
+        prepare_closure_variables(options, reference.defined_storage_index, reference.storage_index, &reference.arguments, &reference.defaults, &body);
+        for item in reference.defaults.iter().chain(std::iter::once(&body)) {
+            find_function_definition_type(module.clone(), item.clone(), options, reference.storage_index, false)?;
+        }
+
+        for argument in reference.arguments.iter() {
+            options.storages[reference.storage_index].add_variable(argument);
+        }
+
+        let method_ast = KaramelAstType::FunctionDefination {
+            name: reference.name.to_string(),
+            arguments: reference.arguments.to_vec(),
+            defaults: reference.defaults.to_vec(),
+            variadic: reference.variadic,
+            types: KaramelFunctionType::default(),
+            body
+        };
+
+        let storage_builder = StorageBuilder::new();
+        let mut builder_option = StorageBuilderOption { max_stack: 0 };
+        storage_builder.prepare(module.clone(), &method_ast, reference.storage_index, options, &mut builder_option)?;
+    }
+
+    Ok(())
+}
+
+fn create_method(module: Rc<OpcodeModule>, name: String, arguments: Vec<String>, defaults: Vec<Rc<KaramelAstType>>, variadic: bool, body: Rc<KaramelAstType>, options: &mut KaramelCompilerContext, current_storage_index: usize) -> Rc<FunctionReference> {
+    /* Create new storage for new method */
+    let new_storage_index = options.storages.len();
+    options.storages.push(StaticStorage::new(new_storage_index));
+    options.storages[new_storage_index].set_parent_location(current_storage_index);
+
+    FunctionReference::opcode_method(name, arguments, defaults, variadic, body, module, new_storage_index, current_storage_index)
+}
+
+fn build_constructor_body(fields: &[Rc<KaramelAstType>], body: Option<Rc<KaramelAstType>>) -> Rc<KaramelAstType> {
+    let mut blocks = Vec::new();
+
+    /* 'x = 10' field defination converted to 'bu.x = 10' */
+    for field in fields {
+        if let KaramelAstType::Assignment { variable, operator, expression } = &**field {
+            if let KaramelAstType::Symbol(field_name) = &**variable {
+                blocks.push(Rc::new(KaramelAstType::Assignment {
+                    variable: Rc::new(KaramelAstType::Indexer {
+                        body: Rc::new(KaramelAstType::Symbol(INSTANCE_NAME.to_string())),
+                        indexer: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new(field_name.to_string())))))
+                    }),
+                    operator: *operator,
+                    expression: expression.clone()
+                }));
+            }
+        }
+    }
+
+    if let Some(body) = body {
+        let items = match &*body {
+            KaramelAstType::Block(items) => items.to_vec(),
+            _ => vec![body.clone()]
+        };
+
+        for item in items {
+            match &*item {
+                KaramelAstType::Return(expression) if **expression == KaramelAstType::None => (),
+                _ => blocks.push(item.clone())
+            };
+        }
+    }
+
+    /* Constructor always return new object */
+    blocks.push(Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::Symbol(INSTANCE_NAME.to_string())))));
+    Rc::new(KaramelAstType::Block(blocks))
+}
+
+/* Variables of outer functions are shared with inner function via cells */
+fn prepare_closure_variables(options: &mut KaramelCompilerContext, parent_storage_index: usize, storage_index: usize, arguments: &[String], defaults: &[Rc<KaramelAstType>], body: &KaramelAstType) {
+    let mut assigned_variables = Vec::new();
+    let mut used_variables = Vec::new();
+    for default in defaults {
+        find_variables(default, false, &mut assigned_variables, &mut used_variables);
+    }
+    find_variables(body, false, &mut assigned_variables, &mut used_variables);
+
+    /* Assignment to outer function variable does not create new variable */
+    let mut local_variables = arguments.to_vec();
+    for variable in assigned_variables {
+        if !local_variables.contains(&variable) && find_variable_owner(options, parent_storage_index, &variable).is_none() {
+            local_variables.push(variable);
+        }
+    }
+
+    for variable in used_variables {
+        if local_variables.contains(&variable) {
+            continue;
+        }
+
+        if let Some(owner_storage_index) = find_variable_owner(options, parent_storage_index, &variable) {
+            options.storages[owner_storage_index].add_closure_variable(&variable);
+            options.storages[storage_index].add_captured_variable(&variable);
+        }
+    }
+
+    options.storages[storage_index].local_variables = local_variables;
+}
+
+fn find_variable_owner(options: &KaramelCompilerContext, storage_index: usize, name: &str) -> Option<usize> {
+    let mut search_index = Some(storage_index);
+
+    while let Some(index) = search_index {
+        if options.storages[index].local_variables.iter().any(|variable| variable == name) {
+            return Some(index);
+        }
+
+        search_index = options.storages[index].get_parent_location();
+    }
+
+    None
+}
+
+fn add_variable_name(variables: &mut Vec<String>, name: &str) {
+    if !variables.iter().any(|variable| variable == name) {
+        variables.push(name.to_string());
+    }
+}
+
+/* Inner functions' variables are counted as used, they might need to be passed through */
+fn find_variables(ast: &KaramelAstType, in_inner_function: bool, assigned_variables: &mut Vec<String>, used_variables: &mut Vec<String>) {
+    match ast {
+        KaramelAstType::Symbol(name) => add_variable_name(used_variables, name),
+        KaramelAstType::Assignment { variable, operator: _, expression: _ } => {
+            if !in_inner_function {
+                match &**variable {
+                    KaramelAstType::Symbol(name) => add_variable_name(assigned_variables, name),
+
+                    /* Destructuring targets */
+                    KaramelAstType::Tuple(targets) | KaramelAstType::List(targets) => for target in targets {
+                        if let KaramelAstType::Symbol(name) = &**target {
+                            add_variable_name(assigned_variables, name);
+                        }
+                    },
+                    _ => ()
+                };
+            }
+        },
+        KaramelAstType::Loop { loop_type: LoopType::ForEach { id: _, variables, iterable: _ }, body: _ } => {
+            if !in_inner_function {
+                for variable in variables {
+                    add_variable_name(assigned_variables, variable);
+                }
+            }
+        },
+        KaramelAstType::Loop { loop_type: LoopType::Range { id: _, variable, start: _, end: _, step: _ }, body: _ } => {
+            if !in_inner_function {
+                add_variable_name(assigned_variables, variable);
+            }
+        },
+        KaramelAstType::Try { id: _, body: _, catch_variable: Some(variable), catch_body: _, finally_body: _ } => {
+            if !in_inner_function {
+                add_variable_name(assigned_variables, variable);
+            }
+        },
+        KaramelAstType::AccessorFuncCall { source, indexer, assign_to_temp: _ } => {
+            find_variables(source, in_inner_function, assigned_variables, used_variables);
+            match &**indexer {
+                /* Method name is not a variable */
+                KaramelAstType::FuncCall { func_name_expression: _, arguments, assign_to_temp: _ } => {
+                    for argument in arguments {
+                        find_variables(argument, in_inner_function, assigned_variables, used_variables);
+                    }
+                },
+                _ => find_variables(indexer, in_inner_function, assigned_variables, used_variables)
+            };
+            return;
+        },
+        KaramelAstType::FunctionDefination { name: _, arguments: _, defaults, variadic: _, types: _, body } => {
+            for default in defaults {
+                find_variables(default, true, assigned_variables, used_variables);
             }
+            find_variables(body, true, assigned_variables, used_variables);
         },
-        KaramelAstType::Block(blocks) => {
-            for block in blocks {
-                find_function_definition_type(module.clone(), block.clone(), options, current_storage_index, module_level)?;
+        KaramelAstType::Lambda(defination) => find_variables(defination, true, assigned_variables, used_variables),
+        KaramelAstType::ClassDefination { name: _, fields, methods } => {
+            for item in fields.iter().chain(methods.iter()) {
+                find_variables(item, true, assigned_variables, used_variables);
             }
         },
         _ => ()
-    }
+    };
 
-    Ok(())
-}
\ No newline at end of file
+    for child in ast.children() {
+        find_variables(&child, in_inner_function, assigned_variables, used_variables);
+    }
+}
@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::compiler::bigint::{BigInt, is_safe_integer};
+use crate::compiler::value::KaramelPrimative;
+use crate::types::VmObject;
+
+/* Only immutable primatives could be hashed. Numbers are compared with their bits, '-0' and '0' are same key.
+   Integral numbers out of safe range use exact value, so they match with same big integer */
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq, Eq, Hash)]
+pub enum HashKey {
+    Number(u64),
+    BigInt(Rc<BigInt>),
+    Text(Rc<String>),
+    Bool(bool),
+    Tuple(Rc<Vec<HashKey>>)
+}
+
+impl HashKey {
+    pub fn from_primative(value: &KaramelPrimative) -> Option<HashKey> {
+        match value {
+            KaramelPrimative::Number(number) if *number == 0.0 => Some(HashKey::Number(0.0_f64.to_bits())),
+            KaramelPrimative::Number(number) if number.fract() == 0.0 && !is_safe_integer(*number) => BigInt::from_f64(*number).map(|number| HashKey::BigInt(Rc::new(number))),
+            KaramelPrimative::Number(number) => Some(HashKey::Number(number.to_bits())),
+            KaramelPrimative::BigInt(number) => Some(HashKey::BigInt(number.clone())),
+            KaramelPrimative::Text(text) => Some(HashKey::Text(text.clone())),
+            KaramelPrimative::Bool(value) => Some(HashKey::Bool(*value)),
+            KaramelPrimative::Tuple(items) => {
+                let keys: Option<Vec<HashKey>> = items.iter().map(|item| HashKey::from_primative(&item.deref())).collect();
+                keys.map(|keys| HashKey::Tuple(Rc::new(keys)))
+            },
+            _ => None
+        }
+    }
+
+    pub fn to_object(&self) -> VmObject {
+        match self {
+            HashKey::Number(bits) => VmObject::from(f64::from_bits(*bits)),
+            HashKey::BigInt(number) => VmObject::native_convert(KaramelPrimative::BigInt(number.clone())),
+            HashKey::Text(text) => VmObject::from(text.clone()),
+            HashKey::Bool(value) => VmObject::from(*value),
+            HashKey::Tuple(items) => VmObject::native_convert(KaramelPrimative::Tuple(Rc::new(items.iter().map(|item| item.to_object()).collect())))
+        }
+    }
+}
+
+/* Hash map that keeps insertion order, updating a key does not change its position */
+#[derive(Clone)]
+pub struct OrderedMap<V: Clone> {
+    items: Vec<(HashKey, V)>,
+    positions: HashMap<HashKey, usize>
+}
+
+impl<V: Clone> Default for OrderedMap<V> {
+    fn default() -> Self {
+        OrderedMap {
+            items: Vec::new(),
+            positions: HashMap::new()
+        }
+    }
+}
+
+impl<V: Clone> OrderedMap<V> {
+    pub fn new() -> OrderedMap<V> {
+        OrderedMap::default()
+    }
+
+    /* Old value is returned if key was already added */
+    pub fn insert(&mut self, key: HashKey, value: V) -> Option<V> {
+        match self.positions.get(&key) {
+            Some(position) => Some(std::mem::replace(&mut self.items[*position].1, value)),
+            None => {
+                self.positions.insert(key.clone(), self.items.len());
+                self.items.push((key, value));
+                None
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &HashKey) -> Option<V> {
+        let position = self.positions.remove(key)?;
+        let (_, value) = self.items.remove(position);
+        for (index, (item, _)) in self.items.iter().enumerate().skip(position) {
+            self.positions.insert(item.clone(), index);
+        }
+        Some(value)
+    }
+
+    pub fn get(&self, key: &HashKey) -> Option<&V> {
+        self.positions.get(key).map(|position| &self.items[*position].1)
+    }
+
+    pub fn contains_key(&self, key: &HashKey) -> bool {
+        self.positions.contains_key(key)
+    }
+
+    pub fn get_index(&self, position: usize) -> Option<&(HashKey, V)> {
+        self.items.get(position)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.positions.clear();
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, (HashKey, V)> {
+        self.items.iter()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &HashKey> {
+        self.items.iter().map(|(key, _)| key)
+    }
+}
+
+/* Dictionary values are compared after getting from VM, order is not important */
+pub type KaramelDict = OrderedMap<VmObject>;
+
+/* Set that keeps insertion order, items are listed and iterated in the order they are added */
+#[derive(Clone)]
+#[derive(Default)]
+pub struct KaramelSet {
+    items: OrderedMap<()>
+}
+
+impl KaramelSet {
+    pub fn new() -> KaramelSet {
+        KaramelSet::default()
+    }
+
+    pub fn insert(&mut self, key: HashKey) -> bool {
+        self.items.insert(key, ()).is_none()
+    }
+
+    pub fn remove(&mut self, key: &HashKey) -> bool {
+        self.items.remove(key).is_some()
+    }
+
+    pub fn contains(&self, key: &HashKey) -> bool {
+        self.items.contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn get(&self, position: usize) -> Option<&HashKey> {
+        self.items.get_index(position).map(|(key, _)| key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &HashKey> {
+        self.items.keys()
+    }
+
+    pub fn union(&self, other: &KaramelSet) -> KaramelSet {
+        let mut result = self.clone();
+        for item in other.iter() {
+            result.insert(item.clone());
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &KaramelSet) -> KaramelSet {
+        self.filter(|item| other.contains(item))
+    }
+
+    pub fn difference(&self, other: &KaramelSet) -> KaramelSet {
+        self.filter(|item| !other.contains(item))
+    }
+
+    pub fn is_subset(&self, other: &KaramelSet) -> bool {
+        self.iter().all(|item| other.contains(item))
+    }
+
+    fn filter<F: Fn(&HashKey) -> bool>(&self, check: F) -> KaramelSet {
+        let mut result = KaramelSet::new();
+        for item in self.iter().filter(|item| check(item)) {
+            result.insert(item.clone());
+        }
+        result
+    }
+}
+
+/* Order is not important while comparing */
+impl PartialEq for KaramelSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.is_subset(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+    use crate::compiler::hash::{HashKey, KaramelSet, OrderedMap};
+    use crate::compiler::value::KaramelPrimative;
+    use crate::types::VmObject;
+
+    fn number(value: f64) -> HashKey {
+        HashKey::from_primative(&KaramelPrimative::Number(value)).unwrap()
+    }
+
+    #[test]
+    fn hash_key_1() {
+        assert_eq!(number(0.0), number(-0.0));
+        assert_eq!(HashKey::from_primative(&KaramelPrimative::Text(Rc::new("a".to_string()))), Some(HashKey::Text(Rc::new("a".to_string()))));
+        assert_eq!(HashKey::from_primative(&KaramelPrimative::Empty), None);
+    }
+
+    #[test]
+    fn hash_key_2() {
+        let tuple = KaramelPrimative::Tuple(Rc::new(vec![VmObject::from(1.0), VmObject::from(true)]));
+        assert_eq!(HashKey::from_primative(&tuple), Some(HashKey::Tuple(Rc::new(vec![number(1.0), HashKey::Bool(true)]))));
+
+        let tuple = KaramelPrimative::Tuple(Rc::new(vec![VmObject::from(1.0), VmObject::from(Vec::new())]));
+        assert_eq!(HashKey::from_primative(&tuple), None);
+    }
+
+    #[test]
+    fn ordered_map_1() {
+        let mut map = OrderedMap::new();
+        assert_eq!(map.insert(number(2.0), "a"), None);
+        assert_eq!(map.insert(number(1.0), "b"), None);
+        assert_eq!(map.insert(number(2.0), "c"), Some("a"));
+        assert_eq!(map.get(&number(2.0)), Some(&"c"));
+        assert_eq!(map.keys().cloned().collect::<Vec<HashKey>>(), vec![number(2.0), number(1.0)]);
+
+        assert_eq!(map.remove(&number(2.0)), Some("c"));
+        assert_eq!(map.remove(&number(2.0)), None);
+        assert_eq!(map.get(&number(1.0)), Some(&"b"));
+        assert_eq!(map.get_index(0), Some(&(number(1.0), "b")));
+    }
+
+    #[test]
+    fn set_order_1() {
+        let mut set = KaramelSet::new();
+        assert!(set.insert(number(3.0)));
+        assert!(set.insert(number(1.0)));
+        assert!(!set.insert(number(3.0)));
+        assert!(set.insert(number(2.0)));
+        assert!(set.remove(&number(1.0)));
+        assert!(!set.remove(&number(1.0)));
+        assert!(set.insert(number(1.0)));
+
+        let items: Vec<HashKey> = set.iter().cloned().collect();
+        assert_eq!(items, vec![number(3.0), number(2.0), number(1.0)]);
+        assert!(set.contains(&number(2.0)));
+    }
+
+    #[test]
+    fn set_operations_1() {
+        let mut left = KaramelSet::new();
+        let mut right = KaramelSet::new();
+        [1.0, 2.0, 3.0].iter().for_each(|item| { left.insert(number(*item)); });
+        [3.0, 4.0].iter().for_each(|item| { right.insert(number(*item)); });
+
+        assert_eq!(left.union(&right).len(), 4);
+        assert_eq!(left.intersection(&right).iter().cloned().collect::<Vec<HashKey>>(), vec![number(3.0)]);
+        assert_eq!(left.difference(&right).len(), 2);
+        assert!(left.intersection(&right).is_subset(&right));
+        assert!(!left.is_subset(&right));
+        assert!(left.union(&right) == right.union(&left));
+    }
+}
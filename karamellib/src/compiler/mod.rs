@@ -6,9 +6,14 @@ pub mod function;
 pub mod value;
 pub mod ast;
 pub mod module;
+pub mod class;
 pub mod scope;
 pub mod context;
 pub mod generator;
+pub mod pattern;
+pub mod type_checker;
+pub mod hash;
+pub mod bigint;
 
 pub use self::compiler::*;
 pub use self::static_storage::*;
@@ -68,8 +73,15 @@ pub enum VmOpCode {
     Multiply,
     Division,
     Module,
-    And,
-    Or,
+    Power,
+    IntegerDivision,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    BitwiseNot,
+    LeftShift,
+    RightShift,
+    In,
     Equal,
     NotEqual,
     GreaterThan,
@@ -79,6 +91,8 @@ pub enum VmOpCode {
 
     Func,
     InitArguments,
+    InitDefault,
+    KeywordArguments,
     Call,
     CallStack,
     Return,
@@ -89,9 +103,14 @@ pub enum VmOpCode {
 
     Compare,
     Jump,
+    JumpIfFalse,
+    JumpIfTrue,
 
     InitList,
+    InitTuple,
     InitDict,
+    InitSet,
+    Unpack,
 
     Load,
     Store,
@@ -100,5 +119,18 @@ pub enum VmOpCode {
     Dublicate,
     GetItem,
     SetItem,
+    GetSlice,
+    Append,
+    Yield,
+
+    Closure,
+    LoadCell,
+    StoreCell,
+    Iterate,
+    RangeIterate,
+    Try,
+    EndTry,
+    Throw,
+    Format,
     Halt
 }
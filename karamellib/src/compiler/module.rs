@@ -11,6 +11,7 @@ use crate::error::{KaramelError};
 use crate::file::read_module_or_script;
 use crate::parser::Parser;
 use crate::syntax::SyntaxParser;
+use crate::syntax::load_module::USE_ALL;
 use crate::types::CompilerResult;
 
 use super::context::KaramelCompilerContext;
@@ -121,7 +122,7 @@ pub fn load_module(params: &[String], modules: &mut Vec<Rc<OpcodeModule>>, optio
             module.storage_index = module_storage;
 
             let module = Rc::new(module);
-            find_load_type(module.main_ast.clone(), options, modules, module.storage_index)?;
+            find_load_type(module.main_ast.clone(), options, modules, module.storage_index, &module.path)?;
             find_function_definition_type(module.clone(), ast.clone(), options, module_storage, true).map_err(KaramelErrorType::from)?;
             Ok(module.clone())
         },
@@ -129,7 +130,7 @@ pub fn load_module(params: &[String], modules: &mut Vec<Rc<OpcodeModule>>, optio
     };
 }
 
-fn find_load_type(ast: Rc<KaramelAstType>, options: &mut KaramelCompilerContext, modules: &mut Vec<Rc<OpcodeModule>>, upper_storage_index: usize) -> CompilerResult {
+fn find_load_type(ast: Rc<KaramelAstType>, options: &mut KaramelCompilerContext, modules: &mut Vec<Rc<OpcodeModule>>, upper_storage_index: usize, module_path: &Vec<String>) -> CompilerResult {
     match &*ast {
         KaramelAstType::Load(module_name) => {
             if !options.has_module(&module_name) {
@@ -138,9 +139,36 @@ fn find_load_type(ast: Rc<KaramelAstType>, options: &mut KaramelCompilerContext,
                 modules.push(module.clone());
             }
         },
+        KaramelAstType::Use(path) => {
+            let module_name = path[0..(path.len() - 1)].to_vec();
+            if !options.has_module(&module_name) {
+                let module = load_module(&module_name, modules, options, upper_storage_index)?;
+                options.add_module(module.clone());
+                modules.push(module.clone());
+            }
+
+            let module = match options.modules.get_module(&module_name) {
+                Some(module) => module,
+                None => return Err(KaramelErrorType::ImportNotFound(module_name.join("::")))
+            };
+
+            let name = &path[path.len() - 1];
+            let references = match &name[..] {
+                /* Generated functions, like lambdas, are not visible */
+                USE_ALL => module.get_methods().into_iter().filter(|reference| !reference.name.contains('#')).collect(),
+                _ => match module.get_method(name) {
+                    Some(reference) => vec![reference],
+                    None => return Err(KaramelErrorType::ImportNotFound(name.to_string()))
+                }
+            };
+
+            for reference in references {
+                options.add_import(module_path, reference)?;
+            }
+        },
         KaramelAstType::Block(blocks) => {
             for block in blocks {
-                find_load_type(block.clone(), options, modules, upper_storage_index)?;
+                find_load_type(block.clone(), options, modules, upper_storage_index, module_path)?;
             }
         },
         _ => ()
@@ -151,7 +179,7 @@ fn find_load_type(ast: Rc<KaramelAstType>, options: &mut KaramelCompilerContext,
 
 pub fn get_modules(main_ast: Rc<KaramelAstType>, options: &mut KaramelCompilerContext) -> Result<Vec<Rc<OpcodeModule>>, KaramelError> {
     let mut modules: Vec<Rc<OpcodeModule>> = Vec::new();
-    match find_load_type(main_ast, options, &mut modules, 0) {
+    match find_load_type(main_ast, options, &mut modules, 0, &Vec::new()) {
         Ok(()) => Ok(modules),
         Err(error) => Err(KaramelError::new(0, 0, error))
     }
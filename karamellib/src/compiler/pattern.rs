@@ -0,0 +1,162 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use crate::compiler::ast::{KaramelAstType, KaramelPattern};
+use crate::compiler::value::KaramelPrimative;
+use crate::types::KaramelOperatorType;
+
+/*
+'eşleştir' patterns are converted to ordinary expressions.
+Checks are executed in order and each one jumps to the next arm when failed,
+so the shape of the value is validated before its items are accessed.
+Bindings are assigned after all checks are passed.
+*/
+pub struct LoweredPattern {
+    pub checks: Vec<Rc<KaramelAstType>>,
+    pub bindings: Vec<Rc<KaramelAstType>>
+}
+
+impl LoweredPattern {
+    pub fn new(pattern: &KaramelPattern, subject: Rc<KaramelAstType>) -> LoweredPattern {
+        let mut lowered = LoweredPattern {
+            checks: Vec::new(),
+            bindings: Vec::new()
+        };
+
+        lowered.lower(pattern, subject);
+        lowered
+    }
+
+    fn lower(&mut self, pattern: &KaramelPattern, subject: Rc<KaramelAstType>) {
+        match pattern {
+            KaramelPattern::Any => (),
+            KaramelPattern::Binding(name) => self.bindings.push(Rc::new(KaramelAstType::Assignment {
+                variable: Rc::new(KaramelAstType::Symbol(name.to_string())),
+                operator: KaramelOperatorType::Assign,
+                expression: subject
+            })),
+            KaramelPattern::Value(value) => self.checks.push(control(subject, KaramelOperatorType::Equal, value.clone())),
+            KaramelPattern::Range { start, end } => {
+                self.checks.push(type_check(subject.clone(), "sayı"));
+                self.checks.push(control(subject.clone(), KaramelOperatorType::GreaterEqualThan, start.clone()));
+                self.checks.push(control(subject, KaramelOperatorType::LessEqualThan, end.clone()));
+            },
+            KaramelPattern::Type(name) => self.checks.push(type_check(subject, name)),
+            KaramelPattern::List { items, rest } => {
+                self.checks.push(type_check(subject.clone(), "liste"));
+
+                let length_operator = match rest {
+                    Some(_) => KaramelOperatorType::GreaterEqualThan,
+                    None => KaramelOperatorType::Equal
+                };
+                self.checks.push(control(method_call(subject.clone(), "uzunluk", Vec::new()), length_operator, number(items.len())));
+
+                for (position, item) in items.iter().enumerate() {
+                    self.lower(item, Rc::new(KaramelAstType::Indexer {
+                        body: subject.clone(),
+                        indexer: number(position)
+                    }));
+                }
+
+                if let Some(rest) = rest {
+                    self.lower(&KaramelPattern::Binding(rest.to_string()), method_call(subject, "parçagetir", vec![number(items.len())]));
+                }
+            },
+            KaramelPattern::Dict(items) => {
+                self.checks.push(type_check(subject.clone(), "sözlük"));
+
+                for (key, _) in items {
+                    self.checks.push(method_call(subject.clone(), "içeriyormu", vec![Rc::new(KaramelAstType::Primative(key.clone()))]));
+                }
+
+                for (key, item) in items {
+                    self.lower(item, Rc::new(KaramelAstType::Indexer {
+                        body: subject.clone(),
+                        indexer: Rc::new(KaramelAstType::Primative(key.clone()))
+                    }));
+                }
+            }
+        };
+    }
+}
+
+impl KaramelPattern {
+    /// Pattern matches every value.
+    pub fn is_catch_all(&self) -> bool {
+        matches!(self, KaramelPattern::Any | KaramelPattern::Binding(_))
+    }
+}
+
+fn number(value: usize) -> Rc<KaramelAstType> {
+    Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(value as f64))))
+}
+
+fn control(left: Rc<KaramelAstType>, operator: KaramelOperatorType, right: Rc<KaramelAstType>) -> Rc<KaramelAstType> {
+    Rc::new(KaramelAstType::Control { left, operator, right })
+}
+
+fn method_call(source: Rc<KaramelAstType>, name: &str, arguments: Vec<Rc<KaramelAstType>>) -> Rc<KaramelAstType> {
+    Rc::new(KaramelAstType::AccessorFuncCall {
+        source,
+        indexer: Rc::new(KaramelAstType::FuncCall {
+            func_name_expression: Rc::new(KaramelAstType::Symbol(name.to_string())),
+            arguments,
+            assign_to_temp: Cell::new(true)
+        }),
+        assign_to_temp: Cell::new(true)
+    })
+}
+
+/* 'baz::tür_bilgisi(değer) == tür' */
+fn type_check(subject: Rc<KaramelAstType>, name: &str) -> Rc<KaramelAstType> {
+    let type_info = Rc::new(KaramelAstType::FuncCall {
+        func_name_expression: Rc::new(KaramelAstType::ModulePath(vec!["baz".to_string(), "tür_bilgisi".to_string()])),
+        arguments: vec![subject],
+        assign_to_temp: Cell::new(true)
+    });
+
+    control(type_info, KaramelOperatorType::Equal, Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new(name.to_string()))))))
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::compiler::ast::{KaramelAstType, KaramelPattern};
+    use crate::compiler::pattern::LoweredPattern;
+    use crate::compiler::value::KaramelPrimative;
+
+    fn subject() -> Rc<KaramelAstType> {
+        Rc::new(KaramelAstType::Symbol("#eşleştir0".to_string()))
+    }
+
+    #[test]
+    fn lower_binding() {
+        let lowered = LoweredPattern::new(&KaramelPattern::Binding("a".to_string()), subject());
+        assert!(lowered.checks.is_empty());
+        assert_eq!(lowered.bindings.len(), 1);
+    }
+
+    #[test]
+    fn lower_list() {
+        let pattern = KaramelPattern::List {
+            items: vec![KaramelPattern::Value(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))), KaramelPattern::Binding("a".to_string())],
+            rest: Some("kalan".to_string())
+        };
+
+        /* Type, length and first item checks */
+        let lowered = LoweredPattern::new(&pattern, subject());
+        assert_eq!(lowered.checks.len(), 3);
+        assert_eq!(lowered.bindings.len(), 2);
+    }
+
+    #[test]
+    fn lower_dict() {
+        let pattern = KaramelPattern::Dict(vec![(Rc::new(KaramelPrimative::Text(Rc::new("ad".to_string()))), KaramelPattern::Type("yazı".to_string()))]);
+
+        /* Type, key and value type checks */
+        let lowered = LoweredPattern::new(&pattern, subject());
+        assert_eq!(lowered.checks.len(), 3);
+        assert!(lowered.bindings.is_empty());
+    }
+}
@@ -1,4 +1,6 @@
 use std::ptr;
+use std::rc::Rc;
+use std::cell::{Cell, RefCell};
 
 use crate::types::VmObject;
 
@@ -12,7 +14,16 @@ pub struct Scope {
     pub const_size: u8,
     pub stack_ptr: *mut VmObject,
     pub memory_ptr: *mut VmObject ,
-    pub storage_index: isize
+    pub storage_index: isize,
+
+    /* Variables that shared with closures */
+    pub cells: Vec<Rc<Cell<VmObject>>>,
+
+    /* Arguments that not passed by caller, default values are used */
+    pub missing_arguments: Vec<bool>,
+
+    /* Generator that owns the scope, 'üret' moves scope back to it */
+    pub generator: Option<Rc<RefCell<Generator>>>
 }
 
 impl Scope {
@@ -31,7 +42,46 @@ impl Scope {
             memory_ptr: memory_ptr,
             stack: stack,
             stack_ptr: stack_ptr,
-            storage_index: -1
+            storage_index: -1,
+            cells: Vec::new(),
+            missing_arguments: Vec::new(),
+            generator: None
+        }
+    }
+}
+/* Active 'dene' block. Runtime errors unwind scopes back to the owner of the handler */
+#[derive(Clone)]
+pub struct ErrorHandler {
+    pub scope_index: usize,
+    pub stack_ptr: *mut VmObject,
+    pub location: usize
+}
+
+/* Suspended function. Scope is kept here while generator is not running */
+pub struct Generator {
+    pub name: String,
+    pub scope: Option<Scope>,
+    pub location: *mut u8,
+    pub finished: bool,
+
+    /* 'dene' blocks that were active at the last 'üret' */
+    pub error_handlers: Vec<ErrorHandler>,
+
+    /* Value waiting for 'her' loop, 'sonraki' gives the value directly */
+    pub value: Option<VmObject>,
+    pub iterating: bool
+}
+
+impl Generator {
+    pub fn new(name: String, scope: Scope, location: *mut u8) -> Generator {
+        Generator {
+            name,
+            scope: Some(scope),
+            location,
+            finished: false,
+            error_handlers: Vec::new(),
+            value: None,
+            iterating: false
         }
     }
-}
\ No newline at end of file
+}
@@ -21,6 +21,13 @@ unsafe fn from_buf_raw<T>(ptr: *const T, elts: usize) -> Vec<T> {
     dst
 }
 
+/* Equal big integer, float and integer are different constants */
+fn is_same_constant(constant: &VmObject, value: &KaramelPrimative) -> bool {
+    *constant.deref() == *value &&
+    matches!(*constant.deref(), KaramelPrimative::BigInt(_)) == matches!(*value, KaramelPrimative::BigInt(_)) &&
+    constant.is_float() == matches!(*value, KaramelPrimative::Float(_))
+}
+
 pub struct StaticStorage {
     pub index                 : usize,
     pub constants             : Vec<VmObject>,
@@ -31,7 +38,12 @@ pub struct StaticStorage {
     pub memory                : Vec<VmObject>,
     pub stack                 : Vec<VmObject>,
     pub total_const_variables : u8,
-    pub parent_location       : Option<usize>
+    pub parent_location       : Option<usize>,
+
+    /* Closure informations */
+    pub local_variables       : Vec<String>,
+    pub closure_variables     : Vec<String>,
+    pub captured_variables    : Vec<String>
 }
 
 /*
@@ -56,7 +68,10 @@ impl StaticStorage {
             memory: Vec::new(),
             stack: Vec::new(),
             variables: Vec::new(),
-            parent_location: None
+            parent_location: None,
+            local_variables: Vec::new(),
+            closure_variables: Vec::new(),
+            captured_variables: Vec::new()
         }
     }
 
@@ -127,9 +142,7 @@ impl StaticStorage {
     }
 
     pub fn add_constant(&mut self, value: Rc<KaramelPrimative>) -> usize {
-        let constant_position = self.constants.iter().position(|x| {
-            *x.deref() == *value
-        });
+        let constant_position = self.constants.iter().position(|x| is_same_constant(x, &value));
         
         match constant_position {
             Some(position) => position,
@@ -159,6 +172,30 @@ impl StaticStorage {
         }
     }
 
+    /// Variable used by inner function, it should live in a cell.
+    pub fn add_closure_variable(&mut self, name: &str) {
+        if !self.closure_variables.iter().any(|item| item == name) {
+            self.closure_variables.push(name.to_string());
+        }
+    }
+
+    /// Variable defined at outer function, it will be received from closure.
+    pub fn add_captured_variable(&mut self, name: &str) {
+        if !self.captured_variables.iter().any(|item| item == name) {
+            self.captured_variables.push(name.to_string());
+        }
+    }
+
+    /// Cell location of variable. Closure variables placed before captured variables.
+    pub fn get_cell_location(&self, name: &str) -> Option<u8> {
+        match self.closure_variables.iter().position(|item| item == name) {
+            Some(location) => Some(location as u8),
+            None => self.captured_variables.iter().position(|item| item == name).map(|location| (self.closure_variables.len() + location) as u8)
+        }
+    }
+
+    pub fn get_closure_size(&self) -> u8 { self.closure_variables.len() as u8 }
+
     #[allow(dead_code)]
     pub fn get_variable_value(&self, name: &str) -> Option<Rc<KaramelPrimative>> {
         match self.get_variable_location(name) {
@@ -168,7 +205,7 @@ impl StaticStorage {
     }
 
     pub fn get_constant_location(&self, value: Rc<KaramelPrimative>) -> Option<u8> {
-        return match self.memory.iter().position(|x| { *x.deref() == *value }) {
+        return match self.memory.iter().position(|x| is_same_constant(x, &value)) {
             Some(number) => Some(number as u8),
             _ => None
         };
@@ -188,7 +225,7 @@ impl StaticStorage {
         None
     }
 
-    pub fn get_class_constant(&self, name: String, _module_path: Rc<dyn Module>) -> Option<u8> {
+    pub fn get_class_constant(&self, name: String, _module_path: &Vec<String>) -> Option<u8> {
         
         for (index, item) in self.memory.iter().enumerate() {
             if let KaramelPrimative::Class(reference) = &*item.deref() {
@@ -1,5 +1,5 @@
 use std::rc::Rc;
-use std::cmp::max;
+use std::cmp::{max, min};
 
 use crate::error::KaramelErrorType;
 use crate::compiler::ast::KaramelAstType;
@@ -7,6 +7,10 @@ use crate::compiler::value::KaramelPrimative;
 use crate::compiler::context::KaramelCompilerContext;
 use crate::types::KaramelOperatorType;
 use crate::syntax::loops::LoopType;
+use crate::syntax::try_catch::TryParser;
+use crate::syntax::pattern_match::MatchParser;
+use crate::syntax::comprehension::ComprehensionParser;
+use crate::compiler::pattern::LoweredPattern;
 
 use super::module::OpcodeModule;
 pub struct StorageBuilder;
@@ -33,8 +37,8 @@ impl StorageBuilder {
         let temp_count = match ast {
             KaramelAstType::Binary {
                 left,
-                operator: _,
-                right} => {
+                right,
+                ..} => {
                     let total =  self.get_temp_count_from_ast(module.clone(),left, ast, options, storage_index, compiler_option)? + self.get_temp_count_from_ast(module.clone(),right, ast, options, storage_index, compiler_option)?;
                     compiler_option.max_stack = max(total, compiler_option.max_stack);
                     total
@@ -116,13 +120,16 @@ impl StorageBuilder {
                 let stack_size = self.get_temp_count_from_ast(module.clone(),expression, ast, options, storage_index, compiler_option)?;
                 compiler_option.max_stack = max(stack_size + var_stack_size, compiler_option.max_stack);
                 
+                /* Compound assignment loads current value to stack */
                 let size = match *operator {
                     KaramelOperatorType::Assign => 0,
-                    _ => 2
+                    _ => max(2, (var_stack_size * 2) + stack_size)
                 };
                 compiler_option.max_stack = max(size, compiler_option.max_stack);
                 0
             },
+
+            KaramelAstType::TypedAssignment { type_name: _, assignment } => self.get_temp_count_from_ast(module.clone(), assignment, ast, options, storage_index, compiler_option)?,
             
             KaramelAstType::Block(asts) => {
                 let mut list_temp_count = 0;
@@ -178,6 +185,9 @@ impl StorageBuilder {
                         if let Some(reference) = function_search {
                             options.storages.get_mut(storage_index).unwrap().add_constant(Rc::new(KaramelPrimative::Function(reference, None)));
                         }
+                        else if let Some(reference) = options.find_class(function_name.to_string(), module.get_path(), storage_index) {
+                            options.storages.get_mut(storage_index).unwrap().add_constant(Rc::new(KaramelPrimative::Class(reference)));
+                        }
                         else {
                             options.storages.get_mut(storage_index).unwrap().add_constant(Rc::new(KaramelPrimative::Text(Rc::new(function_name.to_string()))));
                         }
@@ -207,7 +217,9 @@ impl StorageBuilder {
                 size
             },
 
-            KaramelAstType::Return(expression) => {
+            KaramelAstType::Return(expression) |
+            KaramelAstType::Yield(expression) |
+            KaramelAstType::Throw(expression) => {
                 self.get_temp_count_from_ast(module.clone(),expression, ast, options, storage_index, compiler_option)?;
                 compiler_option.max_stack = max(1, compiler_option.max_stack);
                 1
@@ -227,6 +239,36 @@ impl StorageBuilder {
                     LoopType::Simple(control) => {
                         total = self.get_temp_count_from_ast(module.clone(),&*control, ast, options, storage_index, compiler_option)?
                     },
+                    LoopType::ForEach { id, variables, iterable } => {
+                        let (source, position) = LoopType::get_iterator_variables(*id);
+                        let storage = options.storages.get_mut(storage_index).unwrap();
+                        storage.add_variable(&source);
+                        storage.add_variable(&position);
+                        storage.add_constant(Rc::new(KaramelPrimative::Number(0.0)));
+
+                        for variable in variables {
+                            storage.add_variable(variable);
+                        }
+
+                        /* Iterator pushes one value for each variable */
+                        total = max(variables.len() as u8, self.get_temp_count_from_ast(module.clone(),&*iterable, ast, options, storage_index, compiler_option)?);
+                    },
+                    LoopType::Range { id, variable, start, end, step } => {
+                        let (next, last, step_variable) = LoopType::get_range_variables(*id);
+                        let storage = options.storages.get_mut(storage_index).unwrap();
+                        storage.add_variable(&next);
+                        storage.add_variable(&last);
+                        storage.add_variable(&step_variable);
+                        storage.add_variable(variable);
+                        storage.add_constant(Rc::new(KaramelPrimative::Number(0.0)));
+
+                        /* Range pushes current value for loop variable */
+                        total = max(1, self.get_temp_count_from_ast(module.clone(),&*start, ast, options, storage_index, compiler_option)?);
+                        total = max(total, self.get_temp_count_from_ast(module.clone(),&*end, ast, options, storage_index, compiler_option)?);
+                        if let Some(step) = step {
+                            total = max(total, self.get_temp_count_from_ast(module.clone(),&*step, ast, options, storage_index, compiler_option)?);
+                        }
+                    },
                     LoopType::Endless => {}
                 };
                 total = max(total, self.get_temp_count_from_ast(module.clone(),&*body, ast, options, storage_index, compiler_option)?);
@@ -234,6 +276,39 @@ impl StorageBuilder {
                 total
             },
 
+            KaramelAstType::Try { id, body, catch_variable, catch_body, finally_body } => {
+                let storage = options.storages.get_mut(storage_index).unwrap();
+                storage.add_variable(&TryParser::get_error_variable(*id));
+                if let Some(variable) = catch_variable {
+                    storage.add_variable(variable);
+                }
+
+                /* Catched error pushed to stack before stored into variable */
+                let mut total = max(1, self.get_temp_count_from_ast(module.clone(),&*body, ast, options, storage_index, compiler_option)?);
+                for item in catch_body.iter().chain(finally_body.iter()) {
+                    total = max(total, self.get_temp_count_from_ast(module.clone(),&*item, ast, options, storage_index, compiler_option)?);
+                }
+                compiler_option.max_stack = max(total, compiler_option.max_stack);
+                total
+            },
+
+            KaramelAstType::TextTemplate(parts) => {
+                /* Previous parts are already concatenated and waiting at stack */
+                let mut total = 0;
+                for (index, part) in parts.iter().enumerate() {
+                    total = max(total, min(index, 1) as u8 + self.get_temp_count_from_ast(module.clone(),&*part, ast, options, storage_index, compiler_option)?);
+                }
+                compiler_option.max_stack = max(total, compiler_option.max_stack);
+                total
+            },
+
+            KaramelAstType::TextFormat { expression, format } => {
+                options.storages.get_mut(storage_index).unwrap().add_constant(Rc::new(KaramelPrimative::Text(format.clone())));
+                let total = max(1, self.get_temp_count_from_ast(module.clone(),&*expression, ast, options, storage_index, compiler_option)?);
+                compiler_option.max_stack = max(total, compiler_option.max_stack);
+                total
+            },
+
             KaramelAstType::Primative(primative) => {
                 options.storages.get_mut(storage_index).unwrap().add_constant(Rc::clone(primative));
                 compiler_option.max_stack = max(1, compiler_option.max_stack);
@@ -249,6 +324,15 @@ impl StorageBuilder {
                 return Ok(total_size)
             },
 
+            KaramelAstType::Tuple(items) | KaramelAstType::Set(items) => {
+                let mut total_size = 1;
+                for item in items {
+                    total_size += self.get_temp_count_from_ast(module.clone(),&*item, ast, options, storage_index, compiler_option)?;
+                }
+                compiler_option.max_stack = max(total_size, compiler_option.max_stack);
+                return Ok(total_size)
+            },
+
             KaramelAstType::Dict(dict) => {
                 let mut total_size = 1;
                 for dict_item in dict {
@@ -268,12 +352,94 @@ impl StorageBuilder {
                 indexer_size + body_size
             },
 
-            KaramelAstType::FunctionDefination { name: _, arguments, body } => {
+            /* Missing slice positions are loaded as empty */
+            KaramelAstType::Slice { body, start, end, step } => {
+                let mut total_size = self.get_temp_count_from_ast(module.clone(),body, ast, options, storage_index, compiler_option)?;
+                for part in [start, end, step] {
+                    total_size += match part {
+                        Some(part) => self.get_temp_count_from_ast(module.clone(),part, ast, options, storage_index, compiler_option)?,
+                        None => self.get_temp_count_from_ast(module.clone(),&KaramelAstType::None, ast, options, storage_index, compiler_option)?
+                    };
+                }
+
+                compiler_option.max_stack = max(total_size, compiler_option.max_stack);
+                total_size
+            },
+
+            /* Function already added as constant */
+            KaramelAstType::Lambda(_) => {
+                compiler_option.max_stack = max(1, compiler_option.max_stack);
+                1
+            },
+
+            KaramelAstType::FunctionDefination { name: _, arguments, defaults, variadic: _, types: _, body } => {
+                /* Default values calculated before function body */
+                for default in defaults {
+                    self.get_temp_count_from_ast(module.clone(),default, ast, options, storage_index, compiler_option)?;
+                }
+
                 self.get_temp_count_from_ast(module.clone(),body, ast, options, storage_index, compiler_option)?;
                 compiler_option.max_stack = max(arguments.len() as u8, compiler_option.max_stack);
                 0
             },
 
+            KaramelAstType::KeywordArgument { name, expression } => {
+                options.storages.get_mut(storage_index).unwrap().add_constant(Rc::new(KaramelPrimative::Text(Rc::new(name.to_string()))));
+                self.get_temp_count_from_ast(module.clone(),expression, ast, options, storage_index, compiler_option)?
+            },
+
+            KaramelAstType::Comprehension { id, key, value, variables, iterable, condition } => {
+                let assignment = ComprehensionParser::get_result_assignment(*id, key.is_some());
+                let loop_ast = ComprehensionParser::get_loop(*id, key, value, variables, iterable, condition);
+
+                let mut total = self.get_temp_count_from_ast(module.clone(),&assignment, ast, options, storage_index, compiler_option)?;
+                total = max(total, self.get_temp_count_from_ast(module.clone(),&loop_ast, ast, options, storage_index, compiler_option)?);
+                total = max(total, 1);
+
+                compiler_option.max_stack = max(total, compiler_option.max_stack);
+                total
+            },
+
+            KaramelAstType::ComprehensionItem { id, key, value } => {
+                let result = KaramelAstType::Symbol(ComprehensionParser::get_result_variable(*id));
+                let mut total = self.get_temp_count_from_ast(module.clone(),&result, ast, options, storage_index, compiler_option)?;
+                if let Some(key) = key {
+                    total += self.get_temp_count_from_ast(module.clone(),key, ast, options, storage_index, compiler_option)?;
+                }
+                total += self.get_temp_count_from_ast(module.clone(),value, ast, options, storage_index, compiler_option)?;
+
+                compiler_option.max_stack = max(total, compiler_option.max_stack);
+                total
+            },
+
+            KaramelAstType::Match { id, value, arms, default, .. } => {
+                let subject = Rc::new(KaramelAstType::Symbol(MatchParser::get_value_variable(*id)));
+                let mut total = self.get_temp_count_from_ast(module.clone(),&MatchParser::get_value_assignment(*id, value.clone()), ast, options, storage_index, compiler_option)?;
+
+                for arm in arms {
+                    let lowered = LoweredPattern::new(&arm.pattern, subject.clone());
+                    for item in lowered.checks.iter().chain(lowered.bindings.iter()).chain(arm.guard.iter()) {
+                        total = max(total, self.get_temp_count_from_ast(module.clone(),item, ast, options, storage_index, compiler_option)?);
+                    }
+                    total = max(total, self.get_temp_count_from_ast(module.clone(),&arm.body, ast, options, storage_index, compiler_option)?);
+                }
+
+                if let Some(default) = default {
+                    total = max(total, self.get_temp_count_from_ast(module.clone(),default, ast, options, storage_index, compiler_option)?);
+                }
+
+                compiler_option.max_stack = max(total, compiler_option.max_stack);
+                total
+            },
+
+            KaramelAstType::Conditional { condition, true_expression, false_expression } => {
+                let mut total = self.get_temp_count_from_ast(module.clone(),condition, ast, options, storage_index, compiler_option)?;
+                total = max(total, self.get_temp_count_from_ast(module.clone(),true_expression, ast, options, storage_index, compiler_option)?);
+                total = max(total, self.get_temp_count_from_ast(module.clone(),false_expression, ast, options, storage_index, compiler_option)?);
+                compiler_option.max_stack = max(total, compiler_option.max_stack);
+                total
+            },
+
             KaramelAstType::IfStatement {
                 condition, body, else_body, else_if} => {
                     let mut total = self.get_temp_count_from_ast(module.clone(),condition, ast, options, storage_index, compiler_option)?;
@@ -0,0 +1,681 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::compiler::ast::{KaramelAstType, KaramelFunctionType};
+use crate::compiler::context::KaramelCompilerContext;
+use crate::compiler::GetType;
+use crate::compiler::value::KaramelPrimative;
+use crate::error::{KaramelError, KaramelErrorType};
+use crate::syntax::loops::LoopType;
+use crate::types::KaramelOperatorType;
+
+/*
+Optional type annotations are checked before compilation.
+Only known types are compared, values without annotation or with unknown type are accepted,
+so programs without annotation behave as before. Operators are only checked when one of the
+operand types comes from annotation, operands without annotation are dynamic.
+*/
+
+/* Type name that accepts every value */
+const ANY_TYPE: &str = "herhangi";
+
+/* Types that could be produced by primitive values, operators of other types are handled by classes */
+const PRIMATIVE_TYPES: [&str; 10] = ["sayı", "yazı", "bool", "liste", "sözlük", "demet", "küme", "fonksiyon", "üreteç", "boş"];
+
+/* Type names that can be written at annotations */
+const TYPE_ALIASES: [(&str, &str); 16] = [
+    ("sayı", "sayı"), ("sayi", "sayı"),
+    ("yazı", "yazı"), ("yazi", "yazı"),
+    ("bool", "bool"),
+    ("liste", "liste"),
+    ("sözlük", "sözlük"), ("sozluk", "sözlük"),
+    ("demet", "demet"),
+    ("küme", "küme"), ("kume", "küme"),
+    ("fonksiyon", "fonksiyon"),
+    ("üreteç", "üreteç"), ("uretec", "üreteç"),
+    ("yok", "boş"), ("boş", "boş")
+];
+
+/* Signature of build-in module functions and primitive class methods */
+struct Signature {
+    owner: &'static str,
+    names: &'static [&'static str],
+    arguments: &'static [&'static str],
+    required: usize,
+    variadic: bool,
+    result: &'static str
+}
+
+const SIGNATURES: &[Signature] = &[
+    /* gç */
+    Signature { owner: "gç", names: &["satıryaz", "satiryaz", "yaz"], arguments: &[ANY_TYPE], required: 0, variadic: true, result: "boş" },
+    Signature { owner: "gç", names: &["satıroku", "satiroku"], arguments: &[], required: 0, variadic: false, result: "yazı" },
+    Signature { owner: "gç", names: &["biçimlendir", "bicimlendir"], arguments: &[ANY_TYPE, "yazı"], required: 1, variadic: false, result: "yazı" },
+
+    /* Modules */
+    Signature { owner: "sayı", names: &["oku"], arguments: &[ANY_TYPE], required: 0, variadic: false, result: "sayı" },
+    Signature { owner: "hataayıklama", names: &["doğrula"], arguments: &[ANY_TYPE, ANY_TYPE], required: 1, variadic: false, result: "boş" },
+    Signature { owner: "baz", names: &["aralık"], arguments: &["sayı", "sayı", "sayı"], required: 1, variadic: false, result: ANY_TYPE },
+    Signature { owner: "baz", names: &["tür_bilgisi"], arguments: &[ANY_TYPE], required: 0, variadic: false, result: "yazı" },
+
+    /* yazı */
+    Signature { owner: "yazı", names: &["uzunluk"], arguments: &[], required: 0, variadic: false, result: "sayı" },
+    Signature { owner: "yazı", names: &["harfleriküçült", "harflerikucult", "harfleribüyült", "harfleribuyult"], arguments: &[], required: 0, variadic: false, result: "yazı" },
+    Signature { owner: "yazı", names: &["içeriyormu", "iceriyormu"], arguments: &["yazı"], required: 1, variadic: false, result: "bool" },
+    Signature { owner: "yazı", names: &["satırlar", "satirlar"], arguments: &[], required: 0, variadic: false, result: "liste" },
+    Signature { owner: "yazı", names: &["parçala", "parcala"], arguments: &["yazı"], required: 1, variadic: false, result: "liste" },
+    Signature { owner: "yazı", names: &["ara"], arguments: &["yazı"], required: 1, variadic: false, result: ANY_TYPE },
+    Signature { owner: "yazı", names: &["değiştir", "degistir"], arguments: &["yazı", "yazı"], required: 2, variadic: false, result: "yazı" },
+    Signature { owner: "yazı", names: &["kırp", "kirp", "sonukırp", "sonukirp", "başıkırp", "basikirp"], arguments: &[], required: 0, variadic: false, result: "yazı" },
+    Signature { owner: "yazı", names: &["parçagetir", "parcagetir"], arguments: &["sayı", "sayı"], required: 2, variadic: false, result: "yazı" },
+    Signature { owner: "yazı", names: &["sayı", "sayi"], arguments: &[], required: 0, variadic: false, result: ANY_TYPE },
+
+    /* sayı */
+    Signature { owner: "sayı", names: &["hex", "yazı", "yazi"], arguments: &[], required: 0, variadic: false, result: "yazı" },
+    Signature { owner: "sayı", names: &["yuvarla", "tavan", "taban", "tamsayı", "kesir"], arguments: &[], required: 0, variadic: false, result: "sayı" },
+
+    /* liste */
+    Signature { owner: "liste", names: &["uzunluk"], arguments: &[], required: 0, variadic: false, result: "sayı" },
+    Signature { owner: "liste", names: &["getir"], arguments: &["sayı"], required: 1, variadic: false, result: ANY_TYPE },
+    Signature { owner: "liste", names: &["güncelle", "guncelle"], arguments: &["sayı", ANY_TYPE], required: 2, variadic: false, result: ANY_TYPE },
+    Signature { owner: "liste", names: &["ekle"], arguments: &[ANY_TYPE], required: 1, variadic: false, result: ANY_TYPE },
+    Signature { owner: "liste", names: &["arayaekle"], arguments: &["sayı", ANY_TYPE], required: 2, variadic: false, result: ANY_TYPE },
+    Signature { owner: "liste", names: &["sil"], arguments: &["sayı"], required: 1, variadic: false, result: ANY_TYPE },
+    Signature { owner: "liste", names: &["parçagetir", "parcagetir"], arguments: &["sayı", "sayı"], required: 1, variadic: false, result: "liste" },
+
+    /* sözlük */
+    Signature { owner: "sözlük", names: &["uzunluk"], arguments: &[], required: 0, variadic: false, result: "sayı" },
+    Signature { owner: "sözlük", names: &["getir", "sil"], arguments: &[ANY_TYPE], required: 1, variadic: false, result: ANY_TYPE },
+    Signature { owner: "sözlük", names: &["güncelle", "guncelle", "ekle"], arguments: &[ANY_TYPE, ANY_TYPE], required: 2, variadic: false, result: ANY_TYPE },
+    Signature { owner: "sözlük", names: &["içeriyormu", "iceriyormu"], arguments: &[ANY_TYPE], required: 1, variadic: false, result: "bool" },
+    Signature { owner: "sözlük", names: &["anahtarlar"], arguments: &[], required: 0, variadic: false, result: "liste" },
+
+    /* küme */
+    Signature { owner: "küme", names: &["uzunluk"], arguments: &[], required: 0, variadic: false, result: "sayı" },
+    Signature { owner: "küme", names: &["içeriyormu", "iceriyormu"], arguments: &[ANY_TYPE], required: 1, variadic: false, result: "bool" },
+    Signature { owner: "küme", names: &["birleşim", "birlesim", "kesişim", "kesisim", "fark"], arguments: &["küme"], required: 1, variadic: false, result: "küme" },
+    Signature { owner: "küme", names: &["alt_kümesimi", "alt_kumesimi"], arguments: &["küme"], required: 1, variadic: false, result: "bool" },
+    Signature { owner: "küme", names: &["listele"], arguments: &[], required: 0, variadic: false, result: "liste" },
+
+    /* demet */
+    Signature { owner: "demet", names: &["uzunluk"], arguments: &[], required: 0, variadic: false, result: "sayı" },
+    Signature { owner: "demet", names: &["liste"], arguments: &[], required: 0, variadic: false, result: "liste" }
+];
+
+/* Resolved types of user function, None means type is not known */
+struct FunctionSignature {
+    arguments: Vec<String>,
+    types: Vec<Option<String>>,
+    variadic: bool,
+    result: Option<String>
+}
+
+pub struct TypeChecker<'a> {
+    context: &'a KaramelCompilerContext,
+    classes: Vec<String>,
+    functions: HashMap<String, FunctionSignature>,
+
+    /* Each function has own variables, annotated variables are kept with their types */
+    scopes: Vec<HashMap<String, String>>,
+
+    /* Return type of the functions that currently checked */
+    results: Vec<Option<String>>,
+    line: u32,
+    column: u32
+}
+
+impl<'a> TypeChecker<'a> {
+    pub fn check(ast: &KaramelAstType, context: &'a KaramelCompilerContext) -> Result<(), KaramelError> {
+        let mut checker = TypeChecker {
+            context,
+            classes: Vec::new(),
+            functions: HashMap::new(),
+            scopes: vec![HashMap::new()],
+            results: Vec::new(),
+            line: 0,
+            column: 0
+        };
+
+        match checker.check_program(ast) {
+            Ok(_) => Ok(()),
+            Err(error) => Err(KaramelError::new(checker.line, checker.column + 1, error))
+        }
+    }
+
+    fn check_program(&mut self, ast: &KaramelAstType) -> Result<(), KaramelErrorType> {
+        let statements = match ast {
+            KaramelAstType::Block(statements) => statements.to_vec(),
+            _ => vec![Rc::new(ast.clone())]
+        };
+
+        /* Classes could be used as type, so they are collected before function signatures */
+        for statement in statements.iter() {
+            if let KaramelAstType::ClassDefination { name, fields: _, methods: _ } = &**statement {
+                self.classes.push(name.to_string());
+            }
+        }
+
+        /* Functions could be called before their defination, same named functions are not checked.
+        Invalid types are reported when defination is checked */
+        let mut duplicates = Vec::new();
+        for statement in statements.iter() {
+            if let KaramelAstType::FunctionDefination { name, arguments, defaults: _, variadic, types, body: _ } = &**statement {
+                let signature = FunctionSignature {
+                    arguments: arguments.to_vec(),
+                    types: types.arguments.iter().map(|name| self.resolve_optional_type(name).unwrap_or(None)).collect(),
+                    variadic: *variadic,
+                    result: self.resolve_optional_type(&types.result).unwrap_or(None)
+                };
+
+                if self.functions.insert(name.to_string(), signature).is_some() {
+                    duplicates.push(name.to_string());
+                }
+            }
+        }
+
+        for name in duplicates.iter() {
+            self.functions.remove(name);
+        }
+
+        self.check_statement(ast)
+    }
+
+    fn resolve_type(&self, name: &str) -> Result<Option<String>, KaramelErrorType> {
+        if name == ANY_TYPE {
+            return Ok(None);
+        }
+
+        if let Some((_, type_name)) = TYPE_ALIASES.iter().find(|(alias, _)| *alias == name) {
+            return Ok(Some(type_name.to_string()));
+        }
+
+        match self.classes.iter().any(|class| class == name) {
+            true => Ok(Some(name.to_string())),
+            false => Err(KaramelErrorType::TypeNotFound(name.to_string()))
+        }
+    }
+
+    fn resolve_optional_type(&self, name: &Option<String>) -> Result<Option<String>, KaramelErrorType> {
+        match name {
+            Some(name) => self.resolve_type(name),
+            None => Ok(None)
+        }
+    }
+
+    fn resolve_types(&self, names: &[Option<String>]) -> Result<Vec<Option<String>>, KaramelErrorType> {
+        names.iter().map(|name| self.resolve_optional_type(name)).collect()
+    }
+
+    fn scope(&mut self) -> &mut HashMap<String, String> {
+        self.scopes.last_mut().unwrap()
+    }
+
+    fn get_variable_type(&self, name: &str) -> Option<String> {
+        self.scopes.last().unwrap().get(name).cloned()
+    }
+
+    fn check_statement(&mut self, ast: &KaramelAstType) -> Result<(), KaramelErrorType> {
+        match ast {
+            KaramelAstType::Location { line, column } => {
+                self.line = *line;
+                self.column = *column;
+            },
+            KaramelAstType::TypedAssignment { type_name, assignment } => {
+                let expected = self.resolve_type(type_name)?;
+                if let KaramelAstType::Assignment { variable, operator: _, expression } = &**assignment {
+                    let found = self.infer(expression)?;
+                    check_assignable(&expected, &found)?;
+
+                    if let (KaramelAstType::Symbol(name), Some(expected)) = (&**variable, expected) {
+                        self.scope().insert(name.to_string(), expected);
+                    }
+                }
+            },
+            KaramelAstType::Assignment { variable, operator, expression } => {
+                let found = self.infer(expression)?;
+                let expected = match &**variable {
+                    KaramelAstType::Symbol(name) => self.get_variable_type(name),
+                    _ => {
+                        self.infer(variable)?;
+                        None
+                    }
+                };
+
+                let found = match get_assignment_operator(operator) {
+                    Some(operator) => binary_type(&operator, &expected, &found)?,
+                    None => found
+                };
+                check_assignable(&expected, &found)?;
+            },
+            KaramelAstType::FunctionDefination { name: _, arguments, defaults, variadic, types, body } => self.check_function(arguments, defaults, *variadic, types, body)?,
+            KaramelAstType::ClassDefination { name: _, fields: _, methods } => {
+                for method in methods.iter() {
+                    self.check_statement(method)?;
+                }
+            },
+            KaramelAstType::Return(expression) => {
+                let found = self.infer(expression)?;
+
+                /* 'döndür' without value is added to end of the every function */
+                if let (Some(expected), false) = (self.results.last().cloned(), **expression == KaramelAstType::None) {
+                    check_assignable(&expected, &found)?;
+                }
+            },
+            KaramelAstType::Loop { loop_type, body: _ } => {
+                /* Loop variables are not annotated, their values are not known */
+                match loop_type {
+                    LoopType::ForEach { id: _, variables, iterable: _ } => variables.iter().for_each(|variable| { self.scope().remove(variable); }),
+                    LoopType::Range { id: _, variable, start: _, end: _, step: _ } => { self.scope().remove(variable); },
+                    _ => ()
+                };
+                self.check_children(ast)?;
+            },
+            KaramelAstType::Try { id: _, body: _, catch_variable, catch_body: _, finally_body: _ } => {
+                if let Some(variable) = catch_variable {
+                    self.scope().remove(variable);
+                }
+                self.check_children(ast)?;
+            },
+            _ => { self.infer(ast)?; }
+        };
+        Ok(())
+    }
+
+    fn check_children(&mut self, ast: &KaramelAstType) -> Result<(), KaramelErrorType> {
+        for child in ast.children().iter() {
+            self.check_statement(child)?;
+        }
+        Ok(())
+    }
+
+    fn check_function(&mut self, arguments: &[String], defaults: &[Rc<KaramelAstType>], variadic: bool, types: &KaramelFunctionType, body: &KaramelAstType) -> Result<(), KaramelErrorType> {
+        let argument_types = self.resolve_types(&types.arguments)?;
+        let result = self.resolve_optional_type(&types.result)?;
+
+        /* Default values are belong to the last arguments */
+        let first_default = arguments.len() - variadic as usize - defaults.len();
+        for (index, default) in defaults.iter().enumerate() {
+            let found = self.infer(default)?;
+            check_assignable(&argument_types.get(first_default + index).cloned().flatten(), &found)?;
+        }
+
+        let mut scope = HashMap::new();
+        for (index, (argument, argument_type)) in arguments.iter().zip(argument_types.iter()).enumerate() {
+            match (variadic && index == arguments.len() - 1, argument_type) {
+                (true, _) => scope.insert(argument.to_string(), "liste".to_string()),
+                (false, Some(argument_type)) => scope.insert(argument.to_string(), argument_type.to_string()),
+                (false, None) => None
+            };
+        }
+
+        let (line, column) = (self.line, self.column);
+        self.scopes.push(scope);
+        self.results.push(result);
+
+        let status = self.check_statement(body);
+
+        self.scopes.pop();
+        self.results.pop();
+
+        /* Lambda is a part of the statement, next errors should point to statement again */
+        if status.is_ok() {
+            self.line = line;
+            self.column = column;
+        }
+        status
+    }
+
+    /* Type of the expression, inner expressions are checked too */
+    fn infer(&mut self, ast: &KaramelAstType) -> Result<Option<String>, KaramelErrorType> {
+        Ok(match ast {
+            KaramelAstType::Primative(primative) => Some(primative.get_type()),
+            KaramelAstType::List(_) => self.infer_children(ast, "liste")?,
+            KaramelAstType::Tuple(_) => self.infer_children(ast, "demet")?,
+            KaramelAstType::Set(_) => self.infer_children(ast, "küme")?,
+            KaramelAstType::Dict(_) => self.infer_children(ast, "sözlük")?,
+            KaramelAstType::TextTemplate(_) | KaramelAstType::TextFormat { expression: _, format: _ } => self.infer_children(ast, "yazı")?,
+            KaramelAstType::Comprehension { id: _, key, value: _, variables: _, iterable: _, condition: _ } => self.infer_children(ast, if key.is_some() { "sözlük" } else { "liste" })?,
+            KaramelAstType::Symbol(name) => self.get_variable_type(name),
+            KaramelAstType::Binary { left, operator, right, line, column } => {
+                let left_type = self.infer(left)?;
+                let right_type = self.infer(right)?;
+                match binary_type(operator, &left_type, &right_type) {
+                    Ok(result) => result,
+                    Err(error) if self.is_annotated(left) || self.is_annotated(right) => {
+                        self.line = *line;
+                        self.column = *column;
+                        return Err(error);
+                    },
+                    Err(_) => None
+                }
+            },
+            KaramelAstType::Control { left, operator, right } => {
+                let left = self.infer(left)?;
+                let right = self.infer(right)?;
+                match operator {
+                    KaramelOperatorType::And | KaramelOperatorType::Or if left == right => left,
+                    KaramelOperatorType::And | KaramelOperatorType::Or => None,
+                    _ => Some("bool".to_string())
+                }
+            },
+            KaramelAstType::PrefixUnary { operator, expression, assign_to_temp: _ } => {
+                let found = self.infer(expression)?;
+                match operator {
+                    KaramelOperatorType::Not => Some("bool".to_string()),
+                    _ if found.as_deref() == Some("sayı") => found,
+                    _ => None
+                }
+            },
+            KaramelAstType::Conditional { condition, true_expression, false_expression } => {
+                self.infer(condition)?;
+                let true_type = self.infer(true_expression)?;
+                let false_type = self.infer(false_expression)?;
+                match true_type == false_type {
+                    true => true_type,
+                    false => None
+                }
+            },
+            KaramelAstType::FuncCall { func_name_expression, arguments, assign_to_temp: _ } => self.infer_func_call(func_name_expression, arguments)?,
+            KaramelAstType::AccessorFuncCall { source, indexer, assign_to_temp: _ } => self.infer_accessor_func_call(source, indexer)?,
+            KaramelAstType::Lambda(defination) => {
+                if let KaramelAstType::FunctionDefination { name: _, arguments, defaults, variadic, types, body } = &**defination {
+                    self.check_function(arguments, defaults, *variadic, types, body)?;
+                }
+                Some("fonksiyon".to_string())
+            },
+            KaramelAstType::FunctionDefination { name: _, arguments: _, defaults: _, variadic: _, types: _, body: _ } |
+            KaramelAstType::ClassDefination { name: _, fields: _, methods: _ } |
+            KaramelAstType::TypedAssignment { type_name: _, assignment: _ } |
+            KaramelAstType::Assignment { variable: _, operator: _, expression: _ } |
+            KaramelAstType::Return(_) |
+            KaramelAstType::Loop { loop_type: _, body: _ } |
+            KaramelAstType::Try { id: _, body: _, catch_variable: _, catch_body: _, finally_body: _ } |
+            KaramelAstType::Location { line: _, column: _ } => {
+                self.check_statement(ast)?;
+                None
+            },
+            _ => {
+                for child in ast.children().iter() {
+                    self.check_statement(child)?;
+                }
+                None
+            }
+        })
+    }
+
+    /* Type of the expression comes from annotation of variable, argument or function result */
+    fn is_annotated(&self, ast: &KaramelAstType) -> bool {
+        match ast {
+            KaramelAstType::Symbol(name) => self.get_variable_type(name).is_some(),
+            KaramelAstType::FuncCall { func_name_expression, arguments: _, assign_to_temp: _ } => match &**func_name_expression {
+                KaramelAstType::Symbol(name) if self.get_variable_type(name).is_none() => self.functions.get(name).is_some_and(|signature| signature.result.is_some()),
+                _ => false
+            },
+            KaramelAstType::Binary { left, right, .. } => self.is_annotated(left) || self.is_annotated(right),
+            _ => false
+        }
+    }
+
+    fn infer_children(&mut self, ast: &KaramelAstType, type_name: &str) -> Result<Option<String>, KaramelErrorType> {
+        self.check_children(ast)?;
+        Ok(Some(type_name.to_string()))
+    }
+
+    fn infer_func_call(&mut self, func_name_expression: &KaramelAstType, arguments: &[Rc<KaramelAstType>]) -> Result<Option<String>, KaramelErrorType> {
+        let mut argument_types = Vec::new();
+        for argument in arguments.iter() {
+            argument_types.push(self.infer(argument)?);
+        }
+
+        match func_name_expression {
+            /* Local variables hide functions with same name */
+            KaramelAstType::Symbol(name) if self.get_variable_type(name).is_some() => Ok(None),
+            KaramelAstType::Symbol(name) if self.classes.contains(name) => Ok(Some(name.to_string())),
+            KaramelAstType::Symbol(name) => match self.functions.get(name) {
+                Some(signature) => {
+                    check_function_arguments(name, signature, arguments, &argument_types)?;
+                    Ok(signature.result.clone())
+                },
+                None => Ok(None)
+            },
+            KaramelAstType::ModulePath(path) if path.len() == 2 => match find_signature(&path[0], &path[1]) {
+                Some(signature) => check_signature(&path[1], signature, arguments, &argument_types),
+                None => Ok(None)
+            },
+
+            /* Method call of primitive value, '"erhan".uzunluk()' */
+            KaramelAstType::Indexer { body, indexer } => match &**indexer {
+                KaramelAstType::Primative(name) => match &**name {
+                    KaramelPrimative::Text(name) => {
+                        /* 'sözlük["anahtar"]()' has same ast, so dictionaries are skipped */
+                        let source_type = self.infer(body)?.filter(|source_type| source_type != "sözlük");
+                        self.check_method(source_type, name, arguments, &argument_types)
+                    },
+                    _ => self.infer(func_name_expression).map(|_| None)
+                },
+                _ => self.infer(func_name_expression).map(|_| None)
+            },
+            _ => {
+                self.infer(func_name_expression)?;
+                Ok(None)
+            }
+        }
+    }
+
+    fn infer_accessor_func_call(&mut self, source: &KaramelAstType, indexer: &KaramelAstType) -> Result<Option<String>, KaramelErrorType> {
+        let source_type = self.infer(source)?;
+        let (name, arguments) = match indexer {
+            KaramelAstType::FuncCall { func_name_expression, arguments, assign_to_temp: _ } => match &**func_name_expression {
+                KaramelAstType::Symbol(name) => (name, arguments),
+                _ => return self.infer(indexer).map(|_| None)
+            },
+            _ => return self.infer(indexer).map(|_| None)
+        };
+
+        let mut argument_types = Vec::new();
+        for argument in arguments.iter() {
+            argument_types.push(self.infer(argument)?);
+        }
+
+        self.check_method(source_type, name, arguments, &argument_types)
+    }
+
+    fn check_method(&self, source_type: Option<String>, name: &str, arguments: &[Rc<KaramelAstType>], argument_types: &[Option<String>]) -> Result<Option<String>, KaramelErrorType> {
+        let source_type = match source_type {
+            Some(source_type) if PRIMATIVE_TYPES.contains(&&source_type[..]) => source_type,
+            _ => return Ok(None)
+        };
+
+        let has_method = match self.context.find_class(source_type.to_string(), &Vec::new(), 0) {
+            Some(class) => class.has_element(None, Rc::new(name.to_string())),
+            None => true
+        };
+
+        if !has_method {
+            return Err(KaramelErrorType::MethodNotFound {
+                type_name: source_type,
+                method: name.to_string()
+            });
+        }
+
+        match find_signature(&source_type, name) {
+            Some(signature) => check_signature(name, signature, arguments, argument_types),
+            None => Ok(None)
+        }
+    }
+}
+
+fn find_signature(owner: &str, name: &str) -> Option<&'static Signature> {
+    SIGNATURES.iter().find(|signature| signature.owner == owner && signature.names.contains(&name))
+}
+
+fn check_signature(name: &str, signature: &Signature, arguments: &[Rc<KaramelAstType>], argument_types: &[Option<String>]) -> Result<Option<String>, KaramelErrorType> {
+    let too_many = !signature.variadic && arguments.len() > signature.arguments.len();
+    if arguments.len() < signature.required || too_many {
+        return Err(KaramelErrorType::FunctionArgumentNotMatching {
+            function: name.to_string(),
+            expected: match too_many {
+                true => signature.arguments.len(),
+                false => signature.required
+            } as u8,
+            found: arguments.len() as u8
+        });
+    }
+
+    for (index, found) in argument_types.iter().enumerate() {
+        let expected = match signature.arguments.get(index).or_else(|| signature.arguments.last()) {
+            Some(expected) if *expected != ANY_TYPE => Some(expected.to_string()),
+            _ => None
+        };
+
+        if !is_assignable(&expected, found) {
+            return Err(KaramelErrorType::FunctionExpectedThatParameterType {
+                function: name.to_string(),
+                expected: expected.unwrap()
+            });
+        }
+    }
+
+    Ok(match signature.result {
+        ANY_TYPE => None,
+        result => Some(result.to_string())
+    })
+}
+
+fn check_function_arguments(name: &str, signature: &FunctionSignature, arguments: &[Rc<KaramelAstType>], argument_types: &[Option<String>]) -> Result<(), KaramelErrorType> {
+    for (index, (argument, found)) in arguments.iter().zip(argument_types.iter()).enumerate() {
+        let position = match &**argument {
+            KaramelAstType::KeywordArgument { name, expression: _ } => signature.arguments.iter().position(|argument| argument == name),
+
+            /* Remaining values are collected by variadic argument */
+            _ if signature.variadic && index >= signature.arguments.len() - 1 => Some(signature.arguments.len() - 1),
+            _ => Some(index)
+        };
+
+        let expected = match position {
+            Some(position) => signature.types.get(position).cloned().flatten(),
+            None => None
+        };
+
+        if !is_assignable(&expected, found) {
+            return Err(KaramelErrorType::FunctionExpectedThatParameterType {
+                function: name.to_string(),
+                expected: expected.unwrap()
+            });
+        }
+    }
+    Ok(())
+}
+
+fn is_assignable(expected: &Option<String>, found: &Option<String>) -> bool {
+    match (expected, found) {
+        (Some(expected), Some(found)) => expected == found,
+        _ => true
+    }
+}
+
+fn check_assignable(expected: &Option<String>, found: &Option<String>) -> Result<(), KaramelErrorType> {
+    match is_assignable(expected, found) {
+        true => Ok(()),
+        false => Err(KaramelErrorType::TypeMismatch {
+            expected: expected.clone().unwrap(),
+            found: found.clone().unwrap()
+        })
+    }
+}
+
+fn get_assignment_operator(operator: &KaramelOperatorType) -> Option<KaramelOperatorType> {
+    match operator {
+        KaramelOperatorType::AssignAddition       => Some(KaramelOperatorType::Addition),
+        KaramelOperatorType::AssignSubtraction    => Some(KaramelOperatorType::Subtraction),
+        KaramelOperatorType::AssignMultiplication => Some(KaramelOperatorType::Multiplication),
+        KaramelOperatorType::AssignDivision       => Some(KaramelOperatorType::Division),
+        KaramelOperatorType::AssignPower          => Some(KaramelOperatorType::Power),
+        KaramelOperatorType::AssignIntegerDivision => Some(KaramelOperatorType::IntegerDivision),
+        KaramelOperatorType::AssignBitwiseAnd     => Some(KaramelOperatorType::BitwiseAnd),
+        KaramelOperatorType::AssignBitwiseOr      => Some(KaramelOperatorType::BitwiseOr),
+        KaramelOperatorType::AssignBitwiseXor     => Some(KaramelOperatorType::BitwiseXor),
+        KaramelOperatorType::AssignLeftShift      => Some(KaramelOperatorType::LeftShift),
+        KaramelOperatorType::AssignRightShift     => Some(KaramelOperatorType::RightShift),
+        _ => None
+    }
+}
+
+/* Result of the operator, values that interpreter could not calculate are reported */
+fn binary_type(operator: &KaramelOperatorType, left: &Option<String>, right: &Option<String>) -> Result<Option<String>, KaramelErrorType> {
+    let (left, right) = match (left, right) {
+        (Some(left), Some(right)) if PRIMATIVE_TYPES.contains(&&left[..]) && PRIMATIVE_TYPES.contains(&&right[..]) => (left, right),
+        _ => return Ok(None)
+    };
+
+    let result = match (operator, &left[..], &right[..]) {
+        (_, "sayı", "sayı") => "sayı",
+        (KaramelOperatorType::Addition, "yazı", "yazı") => "yazı",
+        (KaramelOperatorType::Multiplication, "yazı", "sayı") => "yazı",
+        _ => return Err(KaramelErrorType::OperatorTypesNotValid {
+            left: left.to_string(),
+            right: right.to_string()
+        })
+    };
+
+    Ok(Some(result.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compiler::context::KaramelCompilerContext;
+    use crate::error::{KaramelError, KaramelErrorType};
+    use crate::parser::Parser;
+    use crate::syntax::SyntaxParser;
+    use super::TypeChecker;
+
+    fn check_with_location(code: &str) -> Result<(), KaramelError> {
+        let mut parser = Parser::new(code);
+        parser.parse().unwrap();
+
+        let syntax = SyntaxParser::with_locations(parser.tokens().to_vec());
+        let ast = syntax.parse().unwrap();
+        let context = KaramelCompilerContext::new();
+        TypeChecker::check(&ast, &context)
+    }
+
+    fn check(code: &str) -> Result<(), KaramelErrorType> {
+        check_with_location(code).map_err(|error| error.error_type)
+    }
+
+    #[test]
+    fn annotated_program() {
+        assert_eq!(check("fonk topla(a: sayı, b: sayı) -> sayı:\n    döndür a + b\nsonuç: sayı = topla(1, 2)"), Ok(()));
+        assert_eq!(check("ad: yazı = 'erhan'\nad += ' barış'\nuzunluk: sayı = ad.uzunluk()"), Ok(()));
+        assert_eq!(check("değer = 'yazı'\ndeğer = 10\ngç::satıryaz(değer + 1)"), Ok(()));
+    }
+
+    #[test]
+    fn dynamic_operators() {
+        assert_eq!(check("a = 'a' + 1\nb = [1] + [2]\nc = 2 * 'ab'"), Ok(()));
+        assert_eq!(check("fonk f(): döndür 'a'\nsonuç = f() * 2"), Ok(()));
+        assert_eq!(check("ad = 'erhan'\nsonuç = ad + 1"), Ok(()));
+    }
+
+    #[test]
+    fn operator_location() {
+        let error = check_with_location("ad: yazı = 'erhan'\nyaş: sayı = 1\ngç::satıryaz(1, ad + yaş)").unwrap_err();
+        assert_eq!(error, KaramelError::new(2, 17, KaramelErrorType::OperatorTypesNotValid { left: "yazı".to_string(), right: "sayı".to_string() }));
+    }
+
+    #[test]
+    fn mismatches() {
+        assert_eq!(check("ad: sayı = 'erhan'"), Err(KaramelErrorType::TypeMismatch { expected: "sayı".to_string(), found: "yazı".to_string() }));
+        assert_eq!(check("ad: yazı = 'erhan'\nyaş: sayı = 1\na = ad + yaş"), Err(KaramelErrorType::OperatorTypesNotValid { left: "yazı".to_string(), right: "sayı".to_string() }));
+        assert_eq!(check("ad: yazı = 'erhan'\nsonuç = ad + 1"), Err(KaramelErrorType::OperatorTypesNotValid { left: "yazı".to_string(), right: "sayı".to_string() }));
+        assert_eq!(check("fonk f(a: sayı): döndür [1] * a"), Err(KaramelErrorType::OperatorTypesNotValid { left: "liste".to_string(), right: "sayı".to_string() }));
+        assert_eq!(check("ad: yazı = 'erhan'\nad += 1"), Err(KaramelErrorType::OperatorTypesNotValid { left: "yazı".to_string(), right: "sayı".to_string() }));
+        assert_eq!(check("fonk f() -> sayı: döndür 1\nad: yazı = 'erhan'\nad += f()"), Err(KaramelErrorType::OperatorTypesNotValid { left: "yazı".to_string(), right: "sayı".to_string() }));
+        assert_eq!(check("fonk f() -> yazı:\n    döndür 1"), Err(KaramelErrorType::TypeMismatch { expected: "yazı".to_string(), found: "sayı".to_string() }));
+        assert_eq!(check("fonk f(a: sayı): döndür a\nf('a')"), Err(KaramelErrorType::FunctionExpectedThatParameterType { function: "f".to_string(), expected: "sayı".to_string() }));
+        assert_eq!(check("a: tip = 1"), Err(KaramelErrorType::TypeNotFound("tip".to_string())));
+        assert_eq!(check("'erhan'.uçur()"), Err(KaramelErrorType::MethodNotFound { type_name: "yazı".to_string(), method: "uçur".to_string() }));
+        assert_eq!(check("ad: yazı = 'erhan'\nad.uçur()"), Err(KaramelErrorType::MethodNotFound { type_name: "yazı".to_string(), method: "uçur".to_string() }));
+        assert_eq!(check("'erhan'.parçala(1)"), Err(KaramelErrorType::FunctionExpectedThatParameterType { function: "parçala".to_string(), expected: "yazı".to_string() }));
+    }
+}
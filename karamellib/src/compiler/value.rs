@@ -3,11 +3,13 @@ use std::rc::Rc;
 use std::cell::RefCell;
 use std::mem::ManuallyDrop;
 use std::fmt;
-use std::collections::HashMap;
 
 
 use crate::{buildin::Class, types::*};
 use crate::compiler::function::FunctionReference;
+use crate::compiler::scope::Generator;
+use crate::compiler::hash::{KaramelDict, KaramelSet};
+use crate::compiler::bigint::{BigInt, is_safe_integer};
 use crate::compiler::GetType;
 
 pub const EMPTY_OBJECT: VmObject = VmObject(QNAN | EMPTY_FLAG);
@@ -22,10 +24,17 @@ pub enum KaramelPrimative {
     Number(f64),
     Bool(bool),
     List(RefCell<Vec<VmObject>>),
-    Dict(RefCell<HashMap<String, VmObject>>),
+    Tuple(Rc<Vec<VmObject>>),
+    Dict(RefCell<KaramelDict>),
     Text(Rc<String>),
     Function(Rc<FunctionReference>, Option<VmObject>),
-    Class(Rc<dyn Class>)
+    Class(Rc<dyn Class>),
+    Generator(Rc<RefCell<Generator>>),
+    Set(RefCell<KaramelSet>),
+    BigInt(Rc<BigInt>),
+
+    /* Float literal with integral value, it is not used as integer. Object of it gives 'Number' */
+    Float(f64)
 }
 
 unsafe impl Send for KaramelPrimative {}
@@ -43,9 +52,9 @@ impl KaramelPrimative {
     pub fn format(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             KaramelPrimative::Empty => write!(f, "boş"),
-            KaramelPrimative::Number(number) => {
-                if *number == (*number as u64) as f64 {
-                    write!(f, "{:?}", (*number as u64))
+            KaramelPrimative::Number(number) | KaramelPrimative::Float(number) => {
+                if number.abs() == (number.abs() as u64) as f64 {
+                    write!(f, "{}{:?}", if *number < 0.0 { "-" } else { "" }, (number.abs() as u64))
                 } else {
                     write!(f, "{:?}", number)
                 }
@@ -55,23 +64,51 @@ impl KaramelPrimative {
                 false => write!(f, "yanlış")
             },
             KaramelPrimative::List(b) => write!(f, "{:?}", b.borrow()),
-            KaramelPrimative::Dict(b) => write!(f, "{:?}", b.borrow()),
+            KaramelPrimative::Tuple(items) => {
+                let items: Vec<String> = items.iter().map(|item| format!("{:?}", item)).collect();
+                write!(f, "({})", items.join(", "))
+            },
+            KaramelPrimative::Dict(items) => {
+                let items: Vec<String> = items.borrow().iter().map(|(key, value)| format!("{:?}: {:?}", key.to_object(), value)).collect();
+                write!(f, "{{{}}}", items.join(", "))
+            },
             KaramelPrimative::Text(b) => write!(f, "\"{}\"", b),
             KaramelPrimative::Function(func, _) => write!(f, "<Fonksiyon='{}'>", func.name),
-            KaramelPrimative::Class(class) => write!(f, "<Sınıf='{}'>", class.get_type())
+            KaramelPrimative::Class(class) => match class.to_text() {
+                Some(text) => write!(f, "{}", text),
+                None => write!(f, "<Sınıf='{}'>", class.get_type())
+            },
+            KaramelPrimative::Generator(generator) => write!(f, "<Üreteç='{}'>", generator.borrow().name),
+            KaramelPrimative::Set(items) => {
+                let items: Vec<String> = items.borrow().iter().map(|item| format!("{:?}", item.to_object())).collect();
+                write!(f, "{{{}}}", items.join(", "))
+            },
+            KaramelPrimative::BigInt(number) => write!(f, "{}", number)
         }
     }
 
     pub fn is_true(&self) -> bool {
         match self {
             KaramelPrimative::Text(value)       => !value.is_empty(),
-            KaramelPrimative::Number(value)     => *value > 0.0,
+            KaramelPrimative::Number(value) | KaramelPrimative::Float(value) => *value > 0.0,
             KaramelPrimative::Bool(value)       => *value,
             KaramelPrimative::List(items)       => !items.borrow().is_empty(),
+            KaramelPrimative::Tuple(items)      => !items.is_empty(),
             KaramelPrimative::Dict(items) => !items.borrow().is_empty(),
             KaramelPrimative::Empty             => false,
             KaramelPrimative::Function(_, _) => true,
-            KaramelPrimative::Class(_) => true
+            KaramelPrimative::Class(_) => true,
+            KaramelPrimative::Generator(_) => true,
+            KaramelPrimative::Set(items) => !items.borrow().is_empty(),
+            KaramelPrimative::BigInt(number) => !number.is_negative()
+        }
+    }
+
+    /// Float literal, integral value is kept as 'Float'.
+    pub fn from_float(number: f64) -> KaramelPrimative {
+        match is_safe_integer(number) {
+            true => KaramelPrimative::Float(number),
+            false => KaramelPrimative::Number(number)
         }
     }
 
@@ -84,7 +121,7 @@ impl KaramelPrimative {
 
     pub fn discriminant(&self) -> usize {
         match self {
-            KaramelPrimative::Number(_) => 0,
+            KaramelPrimative::Number(_) | KaramelPrimative::BigInt(_) | KaramelPrimative::Float(_) => 0,
             KaramelPrimative::Text(_) => 1,
             KaramelPrimative::List(_) => 2,
             KaramelPrimative::Dict(_) => 3,
@@ -92,7 +129,10 @@ impl KaramelPrimative {
             KaramelPrimative::Empty => 4,
             KaramelPrimative::Bool(_) => 5,
             KaramelPrimative::Function(_, _) => 6,
-            KaramelPrimative::Class(_) => 7
+            KaramelPrimative::Class(_) => 7,
+            KaramelPrimative::Tuple(_) => 8,
+            KaramelPrimative::Generator(_) => 9,
+            KaramelPrimative::Set(_) => 10
         }
     }
 }
@@ -101,13 +141,16 @@ impl GetType for KaramelPrimative {
     fn get_type(&self) -> String {
         match self {
             KaramelPrimative::Text(_)     => "yazı".to_string(),
-            KaramelPrimative::Number(_)   => "sayı".to_string(),
+            KaramelPrimative::Number(_) | KaramelPrimative::BigInt(_) | KaramelPrimative::Float(_) => "sayı".to_string(),
             KaramelPrimative::Bool(_)     => "bool".to_string(),
             KaramelPrimative::List(_)     => "liste".to_string(),
+            KaramelPrimative::Tuple(_)    => "demet".to_string(),
             KaramelPrimative::Dict(_)     => "sözlük".to_string(),
             KaramelPrimative::Empty       => "boş".to_string(),
             KaramelPrimative::Function(_, _) => "fonksiyon".to_string(),
-            KaramelPrimative::Class(_)    => "sınıf".to_string()
+            KaramelPrimative::Class(_)    => "sınıf".to_string(),
+            KaramelPrimative::Generator(_) => "üreteç".to_string(),
+            KaramelPrimative::Set(_)      => "küme".to_string()
         }
     }
 }
@@ -163,8 +206,8 @@ impl From<Rc<KaramelPrimative>> for VmObject {
     }
 }
 
-impl From<HashMap<String, VmObject>> for VmObject {
-    fn from(source: HashMap<String, VmObject>) -> Self {
+impl From<KaramelDict> for VmObject {
+    fn from(source: KaramelDict) -> Self {
         VmObject::convert(Rc::new(KaramelPrimative::Dict(RefCell::new(source))))
     }
 }
@@ -205,6 +248,12 @@ impl PartialEq for KaramelPrimative {
             (KaramelPrimative::Bool(lvalue),            KaramelPrimative::Bool(rvalue)) => lvalue == rvalue,
             (KaramelPrimative::Empty,                   KaramelPrimative::Empty)        => true,
             (KaramelPrimative::Number(n),               KaramelPrimative::Number(m))    => if n.is_nan() && m.is_nan() { true } else { n == m },
+            (KaramelPrimative::BigInt(n),               KaramelPrimative::BigInt(m))    => n == m,
+            (KaramelPrimative::Float(n),                KaramelPrimative::Float(m))     |
+            (KaramelPrimative::Number(n),               KaramelPrimative::Float(m))     |
+            (KaramelPrimative::Float(n),                KaramelPrimative::Number(m))    => n == m,
+            (KaramelPrimative::Number(n),               KaramelPrimative::BigInt(m)) |
+            (KaramelPrimative::BigInt(m),               KaramelPrimative::Number(n))    => BigInt::from_f64(*n).map_or(false, |n| n == **m),
             (KaramelPrimative::Text(lvalue),            KaramelPrimative::Text(rvalue)) => lvalue == rvalue,
             (KaramelPrimative::List(l_value),           KaramelPrimative::List(r_value))       => {
                 if (*l_value).borrow().len() != (*r_value).borrow().len() {
@@ -218,6 +267,9 @@ impl PartialEq for KaramelPrimative {
                 }
                 true
             },
+            (KaramelPrimative::Tuple(l_value),          KaramelPrimative::Tuple(r_value))      => {
+                l_value.len() == r_value.len() && l_value.iter().zip(r_value.iter()).all(|(l_item, r_item)| l_item.deref() == r_item.deref())
+            },
             (KaramelPrimative::Function(l_value, _), KaramelPrimative::Function(r_value, _)) => {
                 if l_value.name != r_value.name ||
                    l_value.module.get_path() != r_value.module.get_path() {
@@ -225,9 +277,15 @@ impl PartialEq for KaramelPrimative {
                 }
                 true
             },
+            (KaramelPrimative::Generator(l_value), KaramelPrimative::Generator(r_value)) => Rc::ptr_eq(l_value, r_value),
+            (KaramelPrimative::Set(l_value), KaramelPrimative::Set(r_value)) => *l_value.borrow() == *r_value.borrow(),
             (KaramelPrimative::Class(l_value), KaramelPrimative::Class(r_value)) => {
-                l_value.get_type() == r_value.get_type()
+                match l_value.equals(self, other) {
+                    Some(result) => result,
+                    None => l_value.get_type() == r_value.get_type()
+                }
             },
+            (KaramelPrimative::Class(class), _) | (_, KaramelPrimative::Class(class)) => class.equals(self, other).unwrap_or(false),
             (KaramelPrimative::Dict(l_value),           KaramelPrimative::Dict(r_value))       => {
                 if (*l_value).borrow().len() != (*r_value).borrow().len() {
                     return false;
@@ -259,6 +317,7 @@ impl VmObject {
         match *primative {
             KaramelPrimative::Empty            => VmObject(QNAN | EMPTY_FLAG),
             KaramelPrimative::Number(number)   => VmObject(number.to_bits()),
+            KaramelPrimative::Float(number)    => VmObject::float(number),
             KaramelPrimative::Bool(true)       => TRUE_OBJECT,
             KaramelPrimative::Bool(false)      => FALSE_OBJECT,
             _                                => {
@@ -271,6 +330,7 @@ impl VmObject {
         match primative {
             KaramelPrimative::Empty            => VmObject(QNAN | EMPTY_FLAG),
             KaramelPrimative::Number(number)   => VmObject(number.to_bits()),
+            KaramelPrimative::Float(number)    => VmObject::float(number),
             KaramelPrimative::Bool(true)       => TRUE_OBJECT,
             KaramelPrimative::Bool(false)      => FALSE_OBJECT,
             _                                => {
@@ -283,6 +343,7 @@ impl VmObject {
         match &*primative {
             KaramelPrimative::Empty            => VmObject(QNAN | EMPTY_FLAG),
             KaramelPrimative::Number(number)   => VmObject(number.to_bits()),
+            KaramelPrimative::Float(number)    => VmObject::float(*number),
             KaramelPrimative::Bool(true)       => TRUE_OBJECT,
             KaramelPrimative::Bool(false)      => FALSE_OBJECT,
             _                                => {
@@ -318,9 +379,14 @@ impl VmObject {
                 match &**data {
                     KaramelPrimative::Text(text) => KaramelPrimative::Text(text.clone()),
                     KaramelPrimative::List(list) => KaramelPrimative::List(list.clone()),
+                    KaramelPrimative::Tuple(items) => KaramelPrimative::Tuple(items.clone()),
                     KaramelPrimative::Dict(dict) => KaramelPrimative::Dict(dict.clone()),
                     KaramelPrimative::Function(func, base) => KaramelPrimative::Function(func.clone(), *base),
                     KaramelPrimative::Class(klass) => KaramelPrimative::Class(klass.clone()),
+                    KaramelPrimative::Generator(generator) => KaramelPrimative::Generator(generator.clone()),
+                    KaramelPrimative::Set(items) => KaramelPrimative::Set(items.clone()),
+                    KaramelPrimative::BigInt(number) => KaramelPrimative::BigInt(number.clone()),
+                    KaramelPrimative::Number(number) => KaramelPrimative::Number(*number),
                     _ => KaramelPrimative::Empty
                 }
             },
@@ -328,10 +394,32 @@ impl VmObject {
         }
     }
 
+    /// Unboxed number. Float with integral value is boxed, it is received from 'deref'.
     pub fn as_number(&self) -> Option<f64> {
         match (self.0 & QNAN) != QNAN {
             true => Some(f64::from_bits(self.0)),
             false => None
         }
     }
+
+    /// Float result. Integral value is boxed, so it is not used as integer at the next calculations.
+    pub fn float(number: f64) -> VmObject {
+        match is_safe_integer(number) {
+            true => VmObject(QNAN | POINTER_FLAG | (POINTER_MASK & (Rc::into_raw(Rc::new(KaramelPrimative::Number(number)))) as u64)),
+            false => VmObject::from(number)
+        }
+    }
+
+    /// Integral number that is not a float, or a big integer.
+    pub fn is_integer(&self) -> bool {
+        match self.as_number() {
+            Some(number) => is_safe_integer(number),
+            None => matches!(*self.deref(), KaramelPrimative::BigInt(_))
+        }
+    }
+
+    /// Number that is boxed as float.
+    pub fn is_float(&self) -> bool {
+        self.as_number().is_none() && matches!(*self.deref(), KaramelPrimative::Number(_))
+    }
 }
\ No newline at end of file
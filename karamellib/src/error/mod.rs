@@ -250,7 +250,198 @@ pub enum KaramelErrorType {
 
     #[error("',' eksik")]
     #[strum(message = "152")]
-    CommaIsMissing
+    CommaIsMissing,
+
+    #[error("Sınıf adı tanımlanmamış")]
+    #[strum(message = "153")]
+    ClassNameNotDefined,
+
+    #[error("Sınıf içi kodlar bulunamadı")]
+    #[strum(message = "154")]
+    ClassConditionBodyNotFound,
+
+    #[error("Sınıf içinde sadece alan ve fonksiyon tanımlanabilir")]
+    #[strum(message = "155")]
+    ClassDefinationNotValid,
+
+    #[error("'{0}' sınıfı önceden tanımlanmış")]
+    #[strum(message = "156")]
+    ClassAlreadyDefined(String),
+
+    #[error("'{0}' sınıfı bulunamadı")]
+    #[strum(message = "157")]
+    ClassNotFound(String),
+
+    #[error("'{0:?}' üzerinde döngü kurulamaz")]
+    #[strum(message = "158")]
+    NotIterable(Rc<KaramelPrimative>),
+
+    #[error("Aralık adımı sıfır olamaz")]
+    #[strum(message = "159")]
+    RangeStepCannotBeZero,
+
+    #[error("Aralık değeri sayı olmalı, fakat '{0:?}' bulundu")]
+    #[strum(message = "160")]
+    RangeValueMustBeNumber(Rc<KaramelPrimative>),
+
+    #[error("'kullan' ifadesi düzgün tanımlanmamış")]
+    #[strum(message = "161")]
+    UseStatementNotValid,
+
+    #[error("'{0}' modülde bulunamadı")]
+    #[strum(message = "162")]
+    ImportNotFound(String),
+
+    #[error("'{0}' birden fazla modülden kullanılıyor")]
+    #[strum(message = "163")]
+    ImportConflict(String),
+
+    #[error("'dene' ifadesi düzgün tanımlanmamış, 'yakala' veya 'sonunda' bloğu gerekli")]
+    #[strum(message = "164")]
+    TryStatementNotValid,
+
+    #[error("'fırlat' ile fırlatılacak değer bulunamadı")]
+    #[strum(message = "165")]
+    ThrowValueNotFound,
+
+    #[error("Yakalanmamış hata: {0}")]
+    #[strum(message = "166")]
+    ThrownError(Rc<KaramelPrimative>),
+
+    #[error("Yazı içindeki ifade düzgün tanımlanmamış")]
+    #[strum(message = "167")]
+    TextInterpolationNotValid,
+
+    #[error("Kaçış karakteri geçerli değil")]
+    #[strum(message = "168")]
+    EscapeSequenceNotValid,
+
+    #[error("Fonksiyon parametre tanımı geçerli değil")]
+    #[strum(message = "169")]
+    ArgumentDefinationNotValid,
+
+    #[error("'{function}' fonksiyonunda '{argument}' isimli parametre kullanılamaz")]
+    #[strum(message = "170")]
+    KeywordArgumentNotValid {
+        function: String,
+        argument: String
+    },
+
+    #[error("İsimli parametreden sonra sıralı parametre kullanılamaz")]
+    #[strum(message = "171")]
+    PositionalArgumentAfterKeyword,
+
+    #[error("Koşullu ifadede 'yoksa' bulunamadı")]
+    #[strum(message = "172")]
+    ConditionalElseMissing,
+
+    #[error("'eşleştir' ifadesi düzgün tanımlanmamış")]
+    #[strum(message = "173")]
+    MatchStatementNotValid,
+
+    #[error("Eşleştirme deseni geçerli değil")]
+    #[strum(message = "174")]
+    PatternNotValid,
+
+    #[error("'eşleştir' ifadesinde 'yoksa' bloğu yok, hiçbir desene uymayan değerler için işlem yapılmayacak")]
+    #[strum(message = "175")]
+    MatchCatchAllMissing,
+
+    #[error("Parçalama adımı sıfır olamaz")]
+    #[strum(message = "176")]
+    SliceStepCannotBeZero,
+
+    #[error("Parçalama sınırları düzgün tanımlanmamış")]
+    #[strum(message = "177")]
+    SliceNotValid,
+
+    #[error("Çoklu atamada {expected} adet değer beklenirken {found} adet bulundu")]
+    #[strum(message = "178")]
+    DestructuringCountNotMatching {
+        expected: usize,
+        found: usize
+    },
+
+    #[error("Çoklu atama düzgün tanımlanmamış, sadece değişkenlere '=' ile atama yapılabilir")]
+    #[strum(message = "179")]
+    DestructuringNotValid,
+
+    #[error("'{0:?}' parçalanamaz, liste ya da demet olması gerekiyor")]
+    #[strum(message = "180")]
+    DestructuringValueNotValid(Rc<KaramelPrimative>),
+
+    #[error("Demet değiştirilemez")]
+    #[strum(message = "181")]
+    TupleIsImmutable,
+
+    #[error("Liste ya da sözlük üreteci düzgün tanımlanmamış")]
+    #[strum(message = "182")]
+    ComprehensionNotValid,
+
+    #[error("Üret komutu fonksiyon içinde kullanılmalıdır")]
+    #[strum(message = "183")]
+    YieldMustBeUsedInFunction,
+
+    #[error("Üreteç tamamlandı, yeni değer üretilemez")]
+    #[strum(message = "184")]
+    GeneratorFinished,
+
+    #[error("Üreteç zaten çalışıyor")]
+    #[strum(message = "185")]
+    GeneratorAlreadyRunning,
+
+    #[error("Bütün görevler bekliyor, çalıştırılacak görev kalmadı")]
+    #[strum(message = "186")]
+    TaskDeadlock,
+
+    #[error("'{0:?}' anahtar olarak kullanılamaz, sadece sayı, yazı, bool ve demet kullanılabilir")]
+    #[strum(message = "187")]
+    ValueNotHashable(Rc<KaramelPrimative>),
+
+    #[error("Küme düzgün kapatılmamış")]
+    #[strum(message = "188")]
+    SetNotClosed,
+
+    #[error("'{0:?}' içinde arama yapılamaz")]
+    #[strum(message = "189")]
+    ContainsNotSupported(Rc<KaramelPrimative>),
+
+    #[error("Tip tanımı geçerli değil")]
+    #[strum(message = "190")]
+    TypeAnnotationNotValid,
+
+    #[error("'{0}' tipi bulunamadı")]
+    #[strum(message = "191")]
+    TypeNotFound(String),
+
+    #[error("'{expected}' tipi bekleniyordu, '{found}' bulundu")]
+    #[strum(message = "192")]
+    TypeMismatch {
+        expected: String,
+        found: String
+    },
+
+    #[error("'{left}' ve '{right}' tipleri ile bu işlem yapılamaz")]
+    #[strum(message = "193")]
+    OperatorTypesNotValid {
+        left: String,
+        right: String
+    },
+
+    #[error("'{type_name}' tipinde '{method}' fonksiyonu bulunmuyor")]
+    #[strum(message = "194")]
+    MethodNotFound {
+        type_name: String,
+        method: String
+    },
+
+    #[error("Bit operatörleri sadece tam sayılar ile çalışır, fakat '{0:?}' bulundu")]
+    #[strum(message = "195")]
+    BitwiseWorksWithInteger(Rc<KaramelPrimative>),
+
+    #[error("Aralık adımı döngü yönü ile uyumlu değil")]
+    #[strum(message = "196")]
+    RangeStepDirectionNotValid
 }
 
 impl From<KaramelErrorType> for KaramelError {
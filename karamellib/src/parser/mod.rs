@@ -5,18 +5,20 @@ mod symbol;
 mod line;
 mod whitespace;
 mod comment;
+mod suffix;
 
 use std::str;
 use std::collections::HashMap;
 
 use crate::{error::KaramelError, types::*};
 use self::number::NumberParser;
-use self::text::TextParser;
+use self::text::{TextParser, RawTextParser};
 use self::operator::OperatorParser;
 use self::symbol::SymbolParser;
 use self::line::LineParser;
 use self::whitespace::WhitespaceParser;
 use self::comment::CommentParser;
+use self::suffix::SuffixParser;
 use crate::error::KaramelErrorType;
 
 pub struct Parser<'a> {
@@ -54,8 +56,10 @@ impl<'a> Parser<'a> {
         let comment_parser      = CommentParser    {};
         let whitespace_parser   = WhitespaceParser {};
         let number_parser       = NumberParser     {};
+        let suffix_parser       = SuffixParser     {};
         let text_parser_single  = TextParser       { tag:'\'' };
         let text_parser_double  = TextParser       { tag:'"' };
+        let raw_text_parser     = RawTextParser    {};
         let operator_parser     = OperatorParser   {};
         let mut symbol_parser   = SymbolParser     {
             keywords: HashMap::new()
@@ -75,9 +79,15 @@ impl<'a> Parser<'a> {
             else if comment_parser.check(&mut self.tokinizer) {
                 status = comment_parser.parse(&mut self.tokinizer);
             }
+            else if raw_text_parser.check(&mut self.tokinizer) {
+                status = raw_text_parser.parse(&mut self.tokinizer);
+            }
             else if symbol_parser.check(&mut self.tokinizer) {
                 status = symbol_parser.parse(&mut self.tokinizer);
             }
+            else if suffix_parser.check(&mut self.tokinizer) {
+                status = suffix_parser.parse(&mut self.tokinizer);
+            }
             else if text_parser_single.check(&mut self.tokinizer) {
                 status = text_parser_single.parse(&mut self.tokinizer);
             }
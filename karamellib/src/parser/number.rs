@@ -1,5 +1,7 @@
 use crate::types::*;
 use crate::error::KaramelErrorType;
+use crate::compiler::bigint::BigInt;
+use std::rc::Rc;
 
 pub struct NumberParser;
 
@@ -9,23 +11,38 @@ impl NumberParser {
         tokinizer.get_char()
     }
 
-    fn get_digits(&self, tokinizer: &mut Tokinizer) -> (u8, u64) {
+    /* Digits that do not fit in 64 bits continue on big integer */
+    fn get_digits(&self, tokinizer: &mut Tokinizer) -> (u8, u64, Option<BigInt>) {
         let mut number: u64    = 0;
         let mut num_count: u8  = 0;
+        let mut big_number     = None;
         let mut ch :char       = tokinizer.get_char();
 
         while !tokinizer.is_end() && (ch.is_ascii_digit() || ch == '_') {
             if ch != '_' {
-                num_count += 1;
-
-                number *= u64::pow(10, 1);
-                number += ch as u64 - '0' as u64;
+                num_count = num_count.saturating_add(1);
+
+                let digit = ch as u64 - '0' as u64;
+                match number.checked_mul(10).and_then(|number| number.checked_add(digit)) {
+                    Some(next) if big_number.is_none() => number = next,
+                    _ => {
+                        let current = big_number.take().unwrap_or_else(|| BigInt::from_u64(number));
+                        big_number = Some(current.mul(&BigInt::from_u64(10)).add(&BigInt::from_u64(digit)));
+                    }
+                };
             }
 
             ch = self.increase(tokinizer);
         }
 
-        (num_count, number)
+        (num_count, number, big_number)
+    }
+
+    fn digits_as_f64(number: u64, big_number: &Option<BigInt>) -> f64 {
+        match big_number {
+            Some(big_number) => big_number.to_f64(),
+            None => number as f64
+        }
     }
 
     fn detect_number_system(&self, tokinizer: &mut Tokinizer) -> KaramelNumberSystem {
@@ -111,8 +128,8 @@ impl NumberParser {
         [NUMBER](.[NUMBER](E(-+)[NUMBER]))
         */
 
-        let (_, digits)  = self.get_digits(tokinizer);
-        let before_comma = digits;
+        let (_, digits, big_digits) = self.get_digits(tokinizer);
+        let before_comma = Self::digits_as_f64(digits, &big_digits);
         let mut ch       = tokinizer.get_char();
         let ch_next = tokinizer.get_next_char();
 
@@ -120,8 +137,8 @@ impl NumberParser {
         if !tokinizer.is_end() && ch == '.' && (ch_next >= '0' && ch_next <= '9') {
             self.increase(tokinizer);
 
-            let (digit_num, digits) = self.get_digits(tokinizer);
-            let after_comma = digits;
+            let (digit_num, digits, big_digits) = self.get_digits(tokinizer);
+            let after_comma = Self::digits_as_f64(digits, &big_digits);
             let dot_place   = digit_num;
             ch          = tokinizer.get_char();
 
@@ -142,11 +159,11 @@ impl NumberParser {
                     }
                 }
 
-                let (_, digits) = self.get_digits(tokinizer);
+                let (_, digits, _) = self.get_digits(tokinizer);
                 let e_after    = digits;
                 self.increase(tokinizer);
 
-                let num = before_comma as f64 + (after_comma as f64 * f64::powi(10.0, -1 * dot_place as i32));
+                let num = before_comma + (after_comma * f64::powi(10.0, -1 * dot_place as i32));
 
                 return match is_minus {
                     true  => KaramelTokenType::Double(num / f64::powi(10.0, e_after as i32)),
@@ -154,11 +171,16 @@ impl NumberParser {
                 }
             }
 
-            let num = before_comma as f64 + (after_comma as f64 * f64::powi(10.0, -1 * dot_place as i32));
+            let num = before_comma + (after_comma * f64::powi(10.0, -1 * dot_place as i32));
             return KaramelTokenType::Double(num)
         }
 
-        KaramelTokenType::Integer(before_comma as i64)
+        /* Integers larger than i64 are kept exact */
+        match big_digits {
+            Some(big_digits) => KaramelTokenType::BigInteger(Rc::new(big_digits)),
+            None if digits > i64::MAX as u64 => KaramelTokenType::BigInteger(Rc::new(BigInt::from_u64(digits))),
+            None => KaramelTokenType::Integer(digits as i64)
+        }
     }
 }
 
@@ -24,16 +24,36 @@ impl TokenParser for OperatorParser {
             ('+', '=') => KaramelOperatorType::AssignAddition,
             ('-', '-') => KaramelOperatorType::Deccrement,
             ('-', '=') => KaramelOperatorType::AssignSubtraction,
+            ('-', '>') => KaramelOperatorType::Arrow,
             ('<', '=') => KaramelOperatorType::LessEqualThan,
             ('>', '=') => KaramelOperatorType::GreaterEqualThan,
             ('*', '=') => KaramelOperatorType::AssignMultiplication,
             ('*', '/') => KaramelOperatorType::CommentMultilineEnd,
             ('=', '=') => KaramelOperatorType::Equal,
+            ('*', '*') => KaramelOperatorType::Power,
+            ('<', '<') => KaramelOperatorType::LeftShift,
+            ('>', '>') => KaramelOperatorType::RightShift,
+            ('&', '=') => KaramelOperatorType::AssignBitwiseAnd,
+            ('|', '=') => KaramelOperatorType::AssignBitwiseOr,
+            ('^', '=') => KaramelOperatorType::AssignBitwiseXor,
             _ =>  KaramelOperatorType::None
         };
 
         if operator_type != KaramelOperatorType::None {
             tokinizer.increase_index();
+
+            /* '**=', '<<=' and '>>=' */
+            let assign_type = match operator_type {
+                KaramelOperatorType::Power      => KaramelOperatorType::AssignPower,
+                KaramelOperatorType::LeftShift  => KaramelOperatorType::AssignLeftShift,
+                KaramelOperatorType::RightShift => KaramelOperatorType::AssignRightShift,
+                _ => KaramelOperatorType::None
+            };
+
+            if assign_type != KaramelOperatorType::None && !tokinizer.is_end() && tokinizer.get_char() == '=' {
+                tokinizer.increase_index();
+                operator_type = assign_type;
+            }
         }
         else {
             operator_type = match ch {
@@ -56,6 +76,10 @@ impl TokenParser for OperatorParser {
                 ';' => KaramelOperatorType::Semicolon,
                 '.' => KaramelOperatorType::Dot,
                 '!' => KaramelOperatorType::Not,
+                '&' => KaramelOperatorType::BitwiseAnd,
+                '|' => KaramelOperatorType::BitwiseOr,
+                '^' => KaramelOperatorType::BitwiseXor,
+                '~' => KaramelOperatorType::BitwiseNot,
                 _ => KaramelOperatorType::None
             };
         }
@@ -0,0 +1,44 @@
+use crate::types::*;
+use crate::error::KaramelErrorType;
+
+/* 
+Turkish suffixes written with apostrophe are only for readability, they are not part of the code.
+Example: liste'de, 1'den, 10'a
+*/
+pub struct SuffixParser;
+
+impl TokenParser for SuffixParser {
+    fn check(&self, tokinizer: &mut Tokinizer) -> bool {
+        if tokinizer.get_char() != '\'' || !tokinizer.get_next_char().is_alphabetic() {
+            return false;
+        }
+
+        /* Suffix should be attached to previous token */
+        let attached = match tokinizer.tokens.last() {
+            Some(token) => token.line == tokinizer.line && token.end == tokinizer.column && match &token.token_type {
+                KaramelTokenType::Symbol(_) |
+                KaramelTokenType::Integer(_) |
+                KaramelTokenType::BigInteger(_) |
+                KaramelTokenType::Double(_) |
+                KaramelTokenType::Operator(KaramelOperatorType::RightParentheses) |
+                KaramelTokenType::Operator(KaramelOperatorType::SquareBracketEnd) => true,
+                _ => false
+            },
+            None => false
+        };
+
+        /* 'a'bc' is not a suffix */
+        let mut suffix = tokinizer.data[tokinizer.index as usize..].chars().skip(1).skip_while(|ch| ch.is_alphabetic());
+        attached && suffix.next() != Some('\'')
+    }
+
+    fn parse(&self, tokinizer: &mut Tokinizer) -> Result<(), KaramelErrorType> {
+        tokinizer.increase_index();
+
+        while !tokinizer.is_end() && tokinizer.get_char().is_alphabetic() {
+            tokinizer.increase_index();
+        }
+
+        Ok(())
+    }
+}
@@ -48,6 +48,12 @@ impl TokenParser for SymbolParser {
 
             let token_type = match keyword.to_operator() {
                 KaramelOperatorType::None => KaramelTokenType::Keyword(*keyword),
+
+                /* 'tambölüm=' */
+                KaramelOperatorType::IntegerDivision if tokinizer.get_char() == '=' && tokinizer.get_next_char() != '=' => {
+                    tokinizer.increase_index();
+                    KaramelTokenType::Operator(KaramelOperatorType::AssignIntegerDivision)
+                },
                 _                       => KaramelTokenType::Operator(keyword.to_operator())
             };
             tokinizer.add_token(start_column as u32, token_type);
@@ -1,55 +1,362 @@
 use std::rc::Rc;
 use crate::types::*;
 use crate::error::KaramelErrorType;
+use crate::buildin::format::TextFormat;
+use super::Parser;
+
+/* Prefix for raw texts, 'ham' */
+const RAW_TEXT_PREFIX: char = 'h';
 
 pub struct TextParser {
     pub tag: char
 }
 
-impl TokenParser for TextParser {
-    fn check(&self, tokinizer: &mut Tokinizer) -> bool {
-        let ch = tokinizer.get_char();
-        return ch == self.tag;
+/* Multi-line text information, calculated before parsing text */
+struct TextBlock {
+    indentation: usize,
+    first_line_empty: bool,
+    last_line_empty: bool,
+    line_count: usize
+}
+
+impl TextParser {
+    /* Only double quoted texts support interpolation */
+    fn is_template(&self) -> bool {
+        self.tag == '"'
     }
 
-    fn parse(&self, tokinizer: &mut Tokinizer) -> Result<(), KaramelErrorType> {
+    fn is_text_block(&self, tokinizer: &Tokinizer) -> bool {
+        let closing: String = [self.tag; 3].iter().collect();
+        tokinizer.data[tokinizer.index as usize..].starts_with(&closing)
+    }
+
+    /*
+    Common indentation of the lines are removed from multi-line text.
+    Empty line after opening and before closing quotes are not part of the text.
+    */
+    fn scan_text_block(&self, tokinizer: &Tokinizer, raw: bool) -> Option<TextBlock> {
+        let data = &tokinizer.data[tokinizer.index as usize..];
+        let closing: String = [self.tag; 3].iter().collect();
+
+        let mut chars = data.char_indices();
+        let mut end = None;
+        while let Some((index, ch)) = chars.next() {
+            if ch == '\\' && !raw {
+                chars.next();
+            }
+            else if data[index..].starts_with(&closing) {
+                end = Some(index);
+                break;
+            }
+        }
+
+        let lines: Vec<&str> = data[..end?].split('\n').collect();
+        let is_empty = |line: &str| line.trim().is_empty();
+        let indentation = lines.iter().skip(1).filter(|line| !is_empty(line)).map(|line| line.len() - line.trim_start_matches(|ch| ch == ' ' || ch == '\t').len()).min();
+
+        Some(TextBlock {
+            indentation: indentation.unwrap_or_default(),
+            first_line_empty: lines.len() > 1 && is_empty(lines[0]),
+            last_line_empty: lines.len() > 1 && is_empty(lines[lines.len() - 1]),
+            line_count: lines.len()
+        })
+    }
+
+    /* Example: \n, \t, \\, \', \", \{, \u{1F600} */
+    fn parse_escape(&self, tokinizer: &mut Tokinizer) -> Result<char, KaramelErrorType> {
+        let ch = match tokinizer.get_next_char() {
+            'n'  => '\n',
+            't'  => '\t',
+            'r'  => '\r',
+            '0'  => '\0',
+            '\\' => '\\',
+            '\'' => '\'',
+            '"'  => '"',
+            '{'  => '{',
+            '}'  => '}',
+            'u'  => {
+                tokinizer.increase_index();
+                tokinizer.increase_index();
+                if tokinizer.get_char() != '{' {
+                    return Err(KaramelErrorType::EscapeSequenceNotValid);
+                }
+                tokinizer.increase_index();
+
+                let mut code = String::new();
+                while !tokinizer.is_end() && tokinizer.get_char().is_ascii_hexdigit() && code.len() < 6 {
+                    code.push(tokinizer.get_char());
+                    tokinizer.increase_index();
+                }
+
+                if tokinizer.get_char() != '}' || code.is_empty() {
+                    return Err(KaramelErrorType::EscapeSequenceNotValid);
+                }
+                tokinizer.increase_index();
+
+                return match u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+                    Some(ch) => Ok(ch),
+                    None => Err(KaramelErrorType::EscapeSequenceNotValid)
+                };
+            },
+            _ => return Err(KaramelErrorType::EscapeSequenceNotValid)
+        };
+
+        tokinizer.increase_index();
         tokinizer.increase_index();
+        Ok(ch)
+    }
 
-        let mut ch: char      = '\0';
-        let mut ch_next: char;
-        let start             = tokinizer.index as usize;
-        let start_column = tokinizer.column;
-        let mut end           = start;
+    fn parse_text(&self, tokinizer: &mut Tokinizer, start_column: u32, raw: bool) -> Result<(), KaramelErrorType> {
+        let start_line   = tokinizer.line;
+        let text_block   = match self.is_text_block(tokinizer) {
+            true => {
+                for _ in 0..3 {
+                    tokinizer.increase_index();
+                }
+
+                match self.scan_text_block(tokinizer, raw) {
+                    Some(text_block) => Some(text_block),
+                    None => return Err(KaramelErrorType::MissingStringDeliminator)
+                }
+            },
+            false => {
+                tokinizer.increase_index();
+                None
+            }
+        };
+
+        let template       = self.is_template() && !raw;
+        let mut text       = String::new();
+        let mut parts      = Vec::new();
+        let mut closed     = false;
+        let mut line_index = 0;
+        let mut indentation = 0;
 
         while !tokinizer.is_end() {
-            ch      = tokinizer.get_char();
-            ch_next = tokinizer.get_next_char();
+            let ch      = tokinizer.get_char();
+            let ch_next = tokinizer.get_next_char();
 
-            if ch == '\\' && ch_next == self.tag {
-                end += ch.len_utf8();
-                end += 1; // for tag char
-                tokinizer.increase_index();
+            if let Some(text_block) = &text_block {
+                if self.is_text_block(tokinizer) {
+                    for _ in 0..3 {
+                        tokinizer.increase_index();
+                    }
+                    closed = true;
+                    break;
+                }
+
+                let empty_line = (line_index == 0 && text_block.first_line_empty) || (line_index == text_block.line_count - 1 && text_block.last_line_empty);
+
+                if ch.is_new_line() {
+                    if !empty_line && !(line_index + 2 == text_block.line_count && text_block.last_line_empty) {
+                        text.push(ch);
+                    }
+
+                    tokinizer.increase_index();
+                    tokinizer.increate_line();
+                    line_index += 1;
+                    indentation = text_block.indentation;
+                    continue;
+                }
+
+                if empty_line || (indentation > 0 && (ch == ' ' || ch == '\t')) {
+                    indentation = indentation.saturating_sub(1);
+                    tokinizer.increase_index();
+                    continue;
+                }
+                indentation = 0;
             }
             else if ch == self.tag {
                 tokinizer.increase_index();
+                closed = true;
                 break;
             }
+            else if ch.is_new_line() {
+                break;
+            }
+
+            if ch == '\\' && !raw {
+                text.push(self.parse_escape(tokinizer)?);
+                continue;
+            }
+            else if template && (ch == '{' || ch == '}') && ch_next == ch {
+                /* '{{' and '}}' are not interpolation */
+                text.push(ch);
+                tokinizer.increase_index();
+            }
+            else if template && ch == '{' {
+                if !text.is_empty() {
+                    parts.push(KaramelTextPart::Text(Rc::new(text)));
+                    text = String::new();
+                }
+
+                parts.push(self.parse_expression(tokinizer)?);
+                continue;
+            }
+            else if template && ch == '}' {
+                return Err(KaramelErrorType::TextInterpolationNotValid);
+            }
             else {
-                end += ch.len_utf8();
+                text.push(ch);
             }
 
             tokinizer.increase_index();
         }
 
-        if ch != self.tag {
+        if !closed {
             return Err(KaramelErrorType::MissingStringDeliminator);
         }
 
-        tokinizer.add_token(start_column - 1, KaramelTokenType::Text(Rc::new(tokinizer.data[start..end].to_string())));
+        let token_type = match parts.is_empty() {
+            true => KaramelTokenType::Text(Rc::new(text)),
+            false => {
+                if !text.is_empty() {
+                    parts.push(KaramelTextPart::Text(Rc::new(text)));
+                }
+                KaramelTokenType::TextTemplate(Rc::new(parts))
+            }
+        };
+
+        tokinizer.tokens.push(Token {
+            line: start_line,
+            start: start_column,
+            end: tokinizer.column,
+            token_type
+        });
         return Ok(());
     }
+
+    /*
+    Expression between curly brackets with optional format.
+    Example:
+        "Merhaba {ad}, yaşın {yaş + 1}"
+        "Toplam: {fiyat:>10.2}"
+    */
+    fn parse_expression(&self, tokinizer: &mut Tokinizer) -> Result<KaramelTextPart, KaramelErrorType> {
+        tokinizer.increase_index();
+
+        /* '{ {...}' is written with space to not be an escaped curly bracket */
+        while !tokinizer.is_end() && (tokinizer.get_char() == ' ' || tokinizer.get_char() == '\t') {
+            tokinizer.increase_index();
+        }
+
+        let column = tokinizer.column;
+        let mut source = String::new();
+        let mut format: Option<String> = None;
+        let mut depth = 0;
+        let mut conditional = 0;
+        let mut in_text = false;
+
+        loop {
+            if tokinizer.is_end() {
+                return Err(KaramelErrorType::MissingStringDeliminator);
+            }
+
+            let ch      = tokinizer.get_char();
+            let ch_next = tokinizer.get_next_char();
+
+            if ch == self.tag || ch.is_new_line() {
+                return Err(KaramelErrorType::TextInterpolationNotValid);
+            }
+
+            match &mut format {
+                Some(format) => match ch {
+                    '}' => break,
+                    _ => format.push(ch)
+                },
+                None => match ch {
+                    '\'' => {
+                        in_text = !in_text;
+                        source.push(ch);
+                    },
+                    _ if in_text => source.push(ch),
+                    '{' | '[' | '(' => {
+                        depth += 1;
+                        source.push(ch);
+                    },
+                    '}' | ']' | ')' if depth > 0 => {
+                        depth -= 1;
+                        source.push(ch);
+                    },
+                    '}' => break,
+
+                    /* Module path is not a format */
+                    ':' if ch_next == ':' => {
+                        source.push_str("::");
+                        tokinizer.increase_index();
+                    },
+                    '?' if depth == 0 => {
+                        conditional += 1;
+                        source.push(ch);
+                    },
+
+                    /* ':' of 'koşul ? a : b' is not a format */
+                    ':' if depth == 0 && conditional > 0 => {
+                        conditional -= 1;
+                        source.push(ch);
+                    },
+                    ':' if depth == 0 => format = Some(String::new()),
+                    _ => source.push(ch)
+                }
+            };
+
+            tokinizer.increase_index();
+        }
+
+        /* Closing curly bracket */
+        tokinizer.increase_index();
+
+        let format = format.unwrap_or_default();
+        if source.trim().is_empty() || TextFormat::parse(&format).is_none() {
+            return Err(KaramelErrorType::TextInterpolationNotValid);
+        }
+
+        let mut parser = Parser::new(&source);
+        if let Err(error) = parser.parse() {
+            return Err(error.error_type);
+        }
+
+        let line = tokinizer.line;
+        let tokens = parser.tokens().into_iter().map(|mut token| {
+            token.line   = line;
+            token.start += column;
+            token.end   += column;
+            token
+        }).collect();
+
+        Ok(KaramelTextPart::Expression { tokens, format: Rc::new(format) })
+    }
+}
+
+impl TokenParser for TextParser {
+    fn check(&self, tokinizer: &mut Tokinizer) -> bool {
+        let ch = tokinizer.get_char();
+        return ch == self.tag;
+    }
+
+    fn parse(&self, tokinizer: &mut Tokinizer) -> Result<(), KaramelErrorType> {
+        let start_column = tokinizer.column;
+        self.parse_text(tokinizer, start_column, false)
+    }
 }
 
+/*
+Raw text, escape characters and interpolation are not processed.
+Example: h"C:\dosyalar\{ad}"
+*/
+pub struct RawTextParser;
+
+impl TokenParser for RawTextParser {
+    fn check(&self, tokinizer: &mut Tokinizer) -> bool {
+        tokinizer.get_char() == RAW_TEXT_PREFIX && tokinizer.get_next_char() == '"'
+    }
+
+    fn parse(&self, tokinizer: &mut Tokinizer) -> Result<(), KaramelErrorType> {
+        let start_column = tokinizer.column;
+        tokinizer.increase_index();
+        TextParser { tag: '"' }.parse_text(tokinizer, start_column, true)
+    }
+}
 
 #[cfg(test)]
 #[test]
@@ -114,3 +421,101 @@ fn text_parse_test_2() {
         _ => assert_eq!(true, false)
     };
 }
+
+#[cfg(test)]
+#[test]
+fn text_parse_test_3() {
+    use crate::parser::Parser;
+
+    let mut parser = Parser::new("\"merhaba {ad:>10}!\"");
+    assert_eq!(parser.parse().is_ok(), true);
+
+    let tokens = parser.tokens();
+    assert_eq!(tokens.len(), 1);
+
+    match &tokens[0].token_type {
+        KaramelTokenType::TextTemplate(parts) => {
+            assert_eq!(parts.len(), 3);
+            assert_eq!(parts[0], KaramelTextPart::Text(Rc::new("merhaba ".to_string())));
+            match &parts[1] {
+                KaramelTextPart::Expression { tokens, format } => {
+                    assert_eq!(tokens.len(), 1);
+                    assert_eq!(tokens[0].start, 10);
+                    assert_eq!(&**format, ">10");
+                },
+                _ => assert_eq!(true, false)
+            };
+            assert_eq!(parts[2], KaramelTextPart::Text(Rc::new("!".to_string())));
+        },
+        _ => assert_eq!(true, false)
+    };
+}
+
+#[cfg(test)]
+#[test]
+fn text_parse_test_4() {
+    use crate::parser::Parser;
+
+    let mut parser = Parser::new("\"{{merhaba}}\" + '{dünya}'");
+    assert_eq!(parser.parse().is_ok(), true);
+
+    let tokens = parser.tokens();
+    assert_eq!(tokens[0].token_type, KaramelTokenType::Text(Rc::new("{merhaba}".to_string())));
+    assert_eq!(tokens[4].token_type, KaramelTokenType::Text(Rc::new("{dünya}".to_string())));
+}
+
+#[cfg(test)]
+#[test]
+fn text_parse_test_5() {
+    use crate::parser::Parser;
+
+    assert_eq!(Parser::new("\"merhaba {ad\"").parse().unwrap_err().error_type, KaramelErrorType::TextInterpolationNotValid);
+    assert_eq!(Parser::new("\"merhaba {}\"").parse().unwrap_err().error_type, KaramelErrorType::TextInterpolationNotValid);
+    assert_eq!(Parser::new("\"merhaba }\"").parse().unwrap_err().error_type, KaramelErrorType::TextInterpolationNotValid);
+    assert_eq!(Parser::new("\"merhaba {ad:?}\"").parse().unwrap_err().error_type, KaramelErrorType::TextInterpolationNotValid);
+}
+
+#[cfg(test)]
+#[test]
+fn text_parse_test_6() {
+    use crate::parser::Parser;
+
+    let mut parser = Parser::new("\"a\\nb\\t\\\\\\\"\\u{11F}\" + h\"C:\\dosya\\{ad}\"");
+    assert_eq!(parser.parse().is_ok(), true);
+
+    let tokens = parser.tokens();
+    assert_eq!(tokens[0].token_type, KaramelTokenType::Text(Rc::new("a\nb\t\\\"ğ".to_string())));
+    assert_eq!(tokens[4].token_type, KaramelTokenType::Text(Rc::new("C:\\dosya\\{ad}".to_string())));
+}
+
+#[cfg(test)]
+#[test]
+fn text_parse_test_7() {
+    use crate::parser::Parser;
+
+    let mut parser = Parser::new("a = '''\n    merhaba\n      dünya\n    '''\nb = 1");
+    assert_eq!(parser.parse().is_ok(), true);
+
+    let tokens = parser.tokens();
+    assert_eq!(tokens[4].token_type, KaramelTokenType::Text(Rc::new("merhaba\n  dünya".to_string())));
+    assert_eq!(tokens[4].line, 0);
+    assert_eq!(tokens[6].line, 4);
+}
+
+#[cfg(test)]
+#[test]
+fn text_parse_test_8() {
+    use crate::parser::Parser;
+
+    let error = Parser::new("a = \"merhaba\nb = 1").parse().unwrap_err();
+    assert_eq!((error.line, error.column, error.error_type), (0, 12, KaramelErrorType::MissingStringDeliminator));
+
+    let error = Parser::new("a = 1\nb = '''merhaba\n dünya").parse().unwrap_err();
+    assert_eq!((error.line, error.column, error.error_type), (1, 7, KaramelErrorType::MissingStringDeliminator));
+
+    let error = Parser::new("a = \"a\\qb\"").parse().unwrap_err();
+    assert_eq!((error.line, error.column, error.error_type), (0, 6, KaramelErrorType::EscapeSequenceNotValid));
+
+    assert_eq!(Parser::new("\"\\u{110000}\"").parse().unwrap_err().error_type, KaramelErrorType::EscapeSequenceNotValid);
+    assert_eq!(Parser::new("\"\\u{}\"").parse().unwrap_err().error_type, KaramelErrorType::EscapeSequenceNotValid);
+}
@@ -4,8 +4,9 @@ use crate::types::*;
 use crate::syntax::{SyntaxParser, SyntaxParserTrait, SyntaxFlag};
 use crate::syntax::expression::ExpressionParser;
 use crate::compiler::ast::KaramelAstType;
+use crate::error::KaramelErrorType;
 
-use super::util::with_flag;
+use super::util::{with_flag, parse_type_name};
 
 pub struct AssignmentParser;
 
@@ -16,25 +17,48 @@ impl SyntaxParserTrait for AssignmentParser {
 
         let variable = ExpressionParser::parse(parser)?;
 
-        match variable {
-            KaramelAstType::Symbol(_) => (),
-            KaramelAstType::Indexer{ body: _, indexer: _ } => (),
+        let variable = match variable {
+            KaramelAstType::Symbol(_) | KaramelAstType::Indexer{ body: _, indexer: _ } => Self::parse_targets(variable, parser)?,
+
+            /* '[ilk, ikinci] = liste' */
+            KaramelAstType::List(_) => variable,
             _ =>  {
                 parser.set_index(index_backup);
                 return Ok(KaramelAstType::None);
             }
         };
 
+        /* 'ad: sayı = 10' */
+        let type_name = match &variable {
+            KaramelAstType::Symbol(_) => Self::parse_type(parser),
+            _ => None
+        };
+
         parser.cleanup_whitespaces();
 
         if let Some(operator) = parser.match_operator(&[KaramelOperatorType::Assign, 
             KaramelOperatorType::AssignAddition,
             KaramelOperatorType::AssignDivision,
             KaramelOperatorType::AssignMultiplication,
-            KaramelOperatorType::AssignSubtraction]) {
+            KaramelOperatorType::AssignSubtraction,
+            KaramelOperatorType::AssignPower,
+            KaramelOperatorType::AssignIntegerDivision,
+            KaramelOperatorType::AssignBitwiseAnd,
+            KaramelOperatorType::AssignBitwiseOr,
+            KaramelOperatorType::AssignBitwiseXor,
+            KaramelOperatorType::AssignLeftShift,
+            KaramelOperatorType::AssignRightShift]) {
+            if type_name.is_some() && operator != KaramelOperatorType::Assign {
+                parser.set_index(index_backup);
+                return Ok(KaramelAstType::None);
+            }
+
             parser.cleanup_whitespaces();
 
-            let expression = with_flag(SyntaxFlag::IN_ASSIGNMENT, parser, || ExpressionParser::parse(parser));            
+            let expression = match &variable {
+                KaramelAstType::Tuple(targets) | KaramelAstType::List(targets) => Self::parse_destructuring(targets, &operator, parser),
+                _ => with_flag(SyntaxFlag::IN_ASSIGNMENT, parser, || ExpressionParser::parse(parser))
+            };
             match expression {
                 Ok(KaramelAstType::None) => return expression,
                 Ok(_) => (),
@@ -47,9 +71,92 @@ impl SyntaxParserTrait for AssignmentParser {
                 expression: Rc::new(expression.unwrap())
             };
 
-            return Ok(assignment_ast);
+            return Ok(match type_name {
+                Some(type_name) => KaramelAstType::TypedAssignment {
+                    type_name,
+                    assignment: Rc::new(assignment_ast)
+                },
+                None => assignment_ast
+            });
         }
         parser.set_index(index_backup);
         return Ok(KaramelAstType::None);
     }
 }
+
+impl AssignmentParser {
+    /* Optional ': sayı' after variable name, nothing consumed if there is no valid annotation */
+    fn parse_type(parser: &SyntaxParser) -> Option<String> {
+        let index_backup = parser.get_index();
+        parser.cleanup_whitespaces();
+
+        if parser.match_operator(&[KaramelOperatorType::ColonMark]).is_some() {
+            if let Some(type_name) = parse_type_name(parser) {
+                return Some(type_name);
+            }
+        }
+
+        parser.set_index(index_backup);
+        None
+    }
+
+    /* 'x, y = ...' targets, single target returned as it is */
+    fn parse_targets(variable: KaramelAstType, parser: &SyntaxParser) -> AstResult {
+        let mut targets = vec![Rc::new(variable)];
+
+        loop {
+            let index_backup = parser.get_index();
+            parser.cleanup_whitespaces();
+
+            if parser.match_operator(&[KaramelOperatorType::Comma]).is_none() {
+                parser.set_index(index_backup);
+                break;
+            }
+
+            parser.cleanup_whitespaces();
+            match ExpressionParser::parse(parser)? {
+                KaramelAstType::None => {
+                    parser.set_index(index_backup);
+                    break;
+                },
+                target => targets.push(Rc::new(target))
+            };
+        }
+
+        Ok(match targets.len() {
+            1 => (*targets.remove(0)).clone(),
+            _ => KaramelAstType::Tuple(targets)
+        })
+    }
+
+    /* Right side of the 'a, b = b, a', multiple values are packed into tuple */
+    fn parse_destructuring(targets: &[Rc<KaramelAstType>], operator: &KaramelOperatorType, parser: &SyntaxParser) -> AstResult {
+        let is_valid = *operator == KaramelOperatorType::Assign && targets.iter().all(|target| matches!(**target, KaramelAstType::Symbol(_)));
+        if !is_valid {
+            return Err(KaramelErrorType::DestructuringNotValid);
+        }
+
+        let mut values = Vec::new();
+        loop {
+            match with_flag(SyntaxFlag::IN_ASSIGNMENT, parser, || ExpressionParser::parse(parser))? {
+                KaramelAstType::None => return Err(KaramelErrorType::RightSideOfExpressionNotFound),
+                value => values.push(Rc::new(value))
+            };
+
+            parser.cleanup_whitespaces();
+            if parser.match_operator(&[KaramelOperatorType::Comma]).is_none() {
+                break;
+            }
+            parser.cleanup_whitespaces();
+        }
+
+        match values.len() {
+            1 => Ok((*values.remove(0)).clone()),
+            length if length == targets.len() => Ok(KaramelAstType::Tuple(values)),
+            length => Err(KaramelErrorType::DestructuringCountNotMatching {
+                expected: targets.len(),
+                found: length
+            })
+        }
+    }
+}
@@ -9,9 +9,38 @@ use crate::error::KaramelErrorType;
 
 use super::util::with_flag;
 
+pub struct BitwiseOrParser;
+pub struct BitwiseXorParser;
+pub struct BitwiseAndParser;
+pub struct ShiftParser;
 pub struct ModuloParser;
 pub struct MultiplyDivideParser;
 pub struct AddSubtractParser;
+pub struct PowerParser;
+
+impl SyntaxParserTrait for BitwiseOrParser {
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        parse_binary::<BitwiseXorParser>(parser, &[KaramelOperatorType::BitwiseOr])
+    }
+}
+
+impl SyntaxParserTrait for BitwiseXorParser {
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        parse_binary::<BitwiseAndParser>(parser, &[KaramelOperatorType::BitwiseXor])
+    }
+}
+
+impl SyntaxParserTrait for BitwiseAndParser {
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        parse_binary::<ShiftParser>(parser, &[KaramelOperatorType::BitwiseAnd])
+    }
+}
+
+impl SyntaxParserTrait for ShiftParser {
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        parse_binary::<AddSubtractParser>(parser, &[KaramelOperatorType::LeftShift, KaramelOperatorType::RightShift])
+    }
+}
 
 impl SyntaxParserTrait for ModuloParser {
     fn parse(parser: &SyntaxParser) -> AstResult {
@@ -21,7 +50,40 @@ impl SyntaxParserTrait for ModuloParser {
 
 impl SyntaxParserTrait for MultiplyDivideParser {
     fn parse(parser: &SyntaxParser) -> AstResult {
-        return parse_binary::<UnaryParser>(parser, &[KaramelOperatorType::Multiplication, KaramelOperatorType::Division]);
+        return parse_binary::<PowerParser>(parser, &[KaramelOperatorType::Multiplication, KaramelOperatorType::Division, KaramelOperatorType::IntegerDivision]);
+    }
+}
+
+/* '2 ** 3 ** 2' is '2 ** (3 ** 2)' */
+impl SyntaxParserTrait for PowerParser {
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        let (line, column) = parser.get_location();
+        let left_expr = UnaryParser::parse(parser)?;
+        if let KaramelAstType::None = left_expr {
+            return Ok(left_expr);
+        }
+
+        let index_backup = parser.get_index();
+        parser.cleanup_whitespaces();
+
+        if parser.match_operator(&[KaramelOperatorType::Power]).is_none() {
+            parser.set_index(index_backup);
+            return Ok(left_expr);
+        }
+
+        update_functions_for_temp_return(&left_expr);
+        parser.cleanup_whitespaces();
+
+        match with_flag(SyntaxFlag::IN_EXPRESSION, parser, || PowerParser::parse(parser))? {
+            KaramelAstType::None => Err(KaramelErrorType::RightSideOfExpressionNotFound),
+            right_expr => Ok(KaramelAstType::Binary {
+                left: Rc::new(left_expr),
+                operator: KaramelOperatorType::Power,
+                right: Rc::new(right_expr),
+                line,
+                column
+            })
+        }
     }
 }
 
@@ -33,6 +95,7 @@ impl SyntaxParserTrait for AddSubtractParser {
 
 pub fn parse_binary<T: SyntaxParserTrait>(parser: &SyntaxParser, operators: &[KaramelOperatorType]) -> AstResult {
     let mut functions_updated_for_temp = false;
+    let (line, column) = parser.get_location();
     let mut left_expr = T::parse(parser)?;
     match left_expr {
         KaramelAstType::None => return Ok(left_expr),
@@ -61,7 +124,9 @@ pub fn parse_binary<T: SyntaxParserTrait>(parser: &SyntaxParser, operators: &[Ka
             left_expr = KaramelAstType::Binary {
                 left: Rc::new(left_expr),
                 operator,
-                right: Rc::new(right_expr.unwrap())
+                right: Rc::new(right_expr.unwrap()),
+                line,
+                column
             };
         }
         else {
@@ -4,10 +4,13 @@ use crate::types::*;
 use crate::syntax::{SyntaxParser, SyntaxParserTrait};
 use crate::syntax::expression::ExpressionParser;
 use crate::syntax::newline::NewlineParser;
-use crate::syntax::util::map_parser;
+use crate::syntax::util::{map_parser, with_flag};
+use crate::syntax::SyntaxFlag;
+use crate::error::KaramelErrorType;
 use crate::compiler::ast::KaramelAstType;
 use crate::syntax::statement::StatementParser;
 use crate::syntax::function_defination::FunctionDefinationParser;
+use crate::syntax::class_defination::ClassDefinationParser;
 
 struct BlockParser;
 pub struct SingleLineBlockParser;
@@ -32,14 +35,32 @@ impl BlockParser {
         let mut block_asts: Vec<Rc<KaramelAstType>> = Vec::new();
         let current_indentation = parser.get_indentation();
 
+        /* Only the first block is module level, modules could not be used inside functions, classes or other blocks */
+        let module_level = !parser.flags.get().contains(SyntaxFlag::IN_BLOCK);
+
         loop {
             parser.indentation_check()?;
-            let ast = map_parser(parser, &[FunctionDefinationParser::parse, StatementParser::parse, ExpressionParser::parse, NewlineParser::parse])?;
+            let location = match parser.peek_token() {
+                Ok(token) if parser.locations && multiline => Some(KaramelAstType::Location { line: token.line, column: token.start }),
+                _ => None
+            };
+
+            let index_backup = parser.get_index();
+            let ast = with_flag(SyntaxFlag::IN_BLOCK, parser, || map_parser(parser, &[FunctionDefinationParser::parse, ClassDefinationParser::parse, StatementParser::parse, ExpressionParser::parse, NewlineParser::parse]))?;
     
             match ast {
                 KaramelAstType::None =>  break,
                 KaramelAstType::NewLine =>  (),
-                _ => block_asts.push(Rc::new(ast))
+                KaramelAstType::Use(_) if !module_level => {
+                    parser.set_index(index_backup);
+                    return Err(KaramelErrorType::UseStatementNotValid);
+                },
+                _ => {
+                    if let Some(location) = location {
+                        block_asts.push(Rc::new(location));
+                    }
+                    block_asts.push(Rc::new(ast))
+                }
             };
 
             if !multiline { break; }
@@ -0,0 +1,74 @@
+use crate::types::*;
+use crate::syntax::{SyntaxParser, SyntaxParserTrait};
+use crate::syntax::primative::PrimativeParser;
+use crate::compiler::ast::{KaramelAstType};
+use crate::syntax::block::{SingleLineBlockParser, MultiLineBlockParser};
+use crate::error::KaramelErrorType;
+use std::rc::Rc;
+
+pub struct ClassDefinationParser;
+
+impl SyntaxParserTrait for ClassDefinationParser {
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        let index_backup = parser.get_index();
+        parser.indentation_check()?;
+
+        if parser.match_keyword(KaramelKeywordType::Class) {
+            let indentation = parser.get_indentation();
+
+            parser.cleanup_whitespaces();
+
+            let name_expression = PrimativeParser::parse_symbol(parser)?;
+            let class_name = match name_expression {
+                KaramelAstType::Symbol(text) => text,
+                _ => return Err(KaramelErrorType::ClassNameNotDefined)
+            };
+
+            parser.cleanup_whitespaces();
+            if let None = parser.match_operator(&[KaramelOperatorType::ColonMark]) {
+                return Err(KaramelErrorType::ColonMarkMissing);
+            }
+
+            parser.cleanup_whitespaces();
+            let body = match parser.get_newline() {
+                (true, _) => {
+                    parser.in_indication()?;
+                    MultiLineBlockParser::parse(parser)
+                },
+                (false, _) => SingleLineBlockParser::parse(parser)
+            }?;
+
+            let items = match body {
+                KaramelAstType::None => return Err(KaramelErrorType::ClassConditionBodyNotFound),
+                KaramelAstType::Block(blocks) => blocks,
+                _ => [Rc::new(body)].to_vec()
+            };
+
+            /* Class body could only have fields and methods */
+            let mut fields  = Vec::new();
+            let mut methods = Vec::new();
+
+            for item in items {
+                match &*item {
+                    KaramelAstType::Assignment { variable, operator: KaramelOperatorType::Assign, expression: _ } => match &**variable {
+                        KaramelAstType::Symbol(_) => fields.push(item.clone()),
+                        _ => return Err(KaramelErrorType::ClassDefinationNotValid)
+                    },
+                    KaramelAstType::FunctionDefination { name: _, arguments: _, defaults: _, variadic: _, types: _, body: _ } => methods.push(item.clone()),
+                    KaramelAstType::Location { line: _, column: _ } => (),
+                    _ => return Err(KaramelErrorType::ClassDefinationNotValid)
+                };
+            }
+
+            parser.set_indentation(indentation);
+            return Ok(KaramelAstType::ClassDefination {
+                name: class_name,
+                fields,
+                methods
+            });
+        }
+
+        parser.set_index(index_backup);
+        return Ok(KaramelAstType::None);
+    }
+}
@@ -0,0 +1,390 @@
+use std::rc::Rc;
+
+use crate::types::*;
+use crate::syntax::{SyntaxParser, SyntaxParserTrait, SyntaxFlag};
+use crate::syntax::expression::ExpressionParser;
+use crate::syntax::loops::{LoopType, WhileLoopParser};
+use crate::syntax::util::with_flag;
+use crate::compiler::ast::{KaramelAstType, KaramelDictItem, KaramelIfStatementElseItem, KaramelMatchArm, KaramelPattern};
+use crate::error::KaramelErrorType;
+
+/*
+List and dict comprehensions.
+Example:
+    [x * 2 her x sayılar ise x > 3]
+    {anahtar: değer * 2 her anahtar, değer sözlük}
+
+Comprehension is compiled as a for each loop that fills a hidden variable.
+*/
+pub struct ComprehensionParser;
+
+impl ComprehensionParser {
+    /// Hidden variable that keeps the generated list or dict.
+    pub fn get_result_variable(id: usize) -> String {
+        format!("#üretilen{}", id)
+    }
+
+    /// Generated list or dict is created empty before the loop.
+    pub fn get_result_assignment(id: usize, is_dict: bool) -> KaramelAstType {
+        KaramelAstType::Assignment {
+            variable: Rc::new(KaramelAstType::Symbol(Self::get_result_variable(id))),
+            operator: KaramelOperatorType::Assign,
+            expression: Rc::new(match is_dict {
+                true => KaramelAstType::Dict(Vec::new()),
+                false => KaramelAstType::List(Vec::new())
+            })
+        }
+    }
+
+    /// Hidden variable of the loop variable, so variable with the same name at outside is not changed.
+    pub fn get_loop_variable(id: usize, name: &str) -> String {
+        format!("#{}.{}", name, id)
+    }
+
+    /// Loop that adds each value to generated list or dict.
+    pub fn get_loop(id: usize, key: &Option<Rc<KaramelAstType>>, value: &Rc<KaramelAstType>, variables: &[String], iterable: &Rc<KaramelAstType>, condition: &Option<Rc<KaramelAstType>>) -> KaramelAstType {
+        let names: Vec<(String, String)> = variables.iter().map(|name| (name.to_string(), Self::get_loop_variable(id, name))).collect();
+        let item = Rc::new(KaramelAstType::ComprehensionItem {
+            id,
+            key: key.as_ref().map(|key| Self::rename(key, &names)),
+            value: Self::rename(value, &names)
+        });
+
+        let body = match condition {
+            Some(condition) => Rc::new(KaramelAstType::IfStatement {
+                condition: Self::rename(condition, &names),
+                body: item,
+                else_body: None,
+                else_if: Vec::new()
+            }),
+            None => item
+        };
+
+        KaramelAstType::Loop {
+            loop_type: LoopType::ForEach {
+                id,
+                variables: names.into_iter().map(|(_, hidden)| hidden).collect(),
+                iterable: iterable.clone()
+            },
+            body
+        }
+    }
+
+    /* Hidden name of the loop variable, other names are not changed */
+    fn rename_variable(name: &str, names: &[(String, String)]) -> String {
+        match names.iter().find(|(variable, _)| variable == name) {
+            Some((_, hidden)) => hidden.to_string(),
+            None => name.to_string()
+        }
+    }
+
+    /* Loop variables are replaced with hidden ones. Arguments of inner functions and variables of inner comprehensions hide them */
+    fn rename(ast: &Rc<KaramelAstType>, names: &[(String, String)]) -> Rc<KaramelAstType> {
+        let rename_all = |items: &Vec<Rc<KaramelAstType>>| items.iter().map(|item| Self::rename(item, names)).collect::<Vec<_>>();
+        let rename_option = |item: &Option<Rc<KaramelAstType>>| item.as_ref().map(|item| Self::rename(item, names));
+        let rename_variables = |variables: &Vec<String>| variables.iter().map(|variable| Self::rename_variable(variable, names)).collect::<Vec<_>>();
+
+        Rc::new(match &**ast {
+            KaramelAstType::None |
+            KaramelAstType::NewLine |
+            KaramelAstType::Primative(_) |
+            KaramelAstType::ModulePath(_) |
+            KaramelAstType::Load(_) |
+            KaramelAstType::Use(_) |
+            KaramelAstType::Break |
+            KaramelAstType::Continue |
+            KaramelAstType::ClassDefination { .. } |
+            KaramelAstType::Location { .. } => return ast.clone(),
+
+            KaramelAstType::Symbol(name) => KaramelAstType::Symbol(Self::rename_variable(name, names)),
+            KaramelAstType::Block(items) => KaramelAstType::Block(rename_all(items)),
+            KaramelAstType::FuncCall { func_name_expression, arguments, assign_to_temp } => KaramelAstType::FuncCall {
+                func_name_expression: Self::rename(func_name_expression, names),
+                arguments: rename_all(arguments),
+                assign_to_temp: assign_to_temp.clone()
+            },
+            KaramelAstType::AccessorFuncCall { source, indexer, assign_to_temp } => KaramelAstType::AccessorFuncCall {
+                source: Self::rename(source, names),
+                indexer: Self::rename(indexer, names),
+                assign_to_temp: assign_to_temp.clone()
+            },
+            KaramelAstType::Binary { left, operator, right, line, column } => KaramelAstType::Binary {
+                left: Self::rename(left, names),
+                operator: *operator,
+                right: Self::rename(right, names),
+                line: *line,
+                column: *column
+            },
+            KaramelAstType::Control { left, operator, right } => KaramelAstType::Control {
+                left: Self::rename(left, names),
+                operator: *operator,
+                right: Self::rename(right, names)
+            },
+            KaramelAstType::PrefixUnary { operator, expression, assign_to_temp } => KaramelAstType::PrefixUnary {
+                operator: *operator,
+                expression: Self::rename(expression, names),
+                assign_to_temp: assign_to_temp.clone()
+            },
+            KaramelAstType::SuffixUnary(operator, expression) => KaramelAstType::SuffixUnary(*operator, Self::rename(expression, names)),
+            KaramelAstType::TypedAssignment { type_name, assignment } => KaramelAstType::TypedAssignment {
+                type_name: type_name.to_string(),
+                assignment: Self::rename(assignment, names)
+            },
+            KaramelAstType::Assignment { variable, operator, expression } => KaramelAstType::Assignment {
+                variable: Self::rename(variable, names),
+                operator: *operator,
+                expression: Self::rename(expression, names)
+            },
+            KaramelAstType::IfStatement { condition, body, else_body, else_if } => KaramelAstType::IfStatement {
+                condition: Self::rename(condition, names),
+                body: Self::rename(body, names),
+                else_body: rename_option(else_body),
+                else_if: else_if.iter().map(|item| Rc::new(KaramelIfStatementElseItem::new(Self::rename(&item.condition, names), Self::rename(&item.body, names)))).collect()
+            },
+
+            /* Default values are calculated at caller, arguments are only visible in the body */
+            KaramelAstType::FunctionDefination { name, arguments, defaults, variadic, types, body } => {
+                let inner_names: Vec<(String, String)> = names.iter().filter(|(name, _)| !arguments.contains(name)).cloned().collect();
+                KaramelAstType::FunctionDefination {
+                    name: name.to_string(),
+                    arguments: arguments.to_vec(),
+                    defaults: rename_all(defaults),
+                    variadic: *variadic,
+                    types: types.clone(),
+                    body: Self::rename(body, &inner_names)
+                }
+            },
+            KaramelAstType::Lambda(defination) => KaramelAstType::Lambda(Self::rename(defination, names)),
+            KaramelAstType::List(items) => KaramelAstType::List(rename_all(items)),
+            KaramelAstType::Tuple(items) => KaramelAstType::Tuple(rename_all(items)),
+            KaramelAstType::Set(items) => KaramelAstType::Set(rename_all(items)),
+            KaramelAstType::TextTemplate(items) => KaramelAstType::TextTemplate(rename_all(items)),
+            KaramelAstType::Dict(items) => KaramelAstType::Dict(items.iter().map(|item| Rc::new(KaramelDictItem {
+                key: item.key.clone(),
+                value: Self::rename(&item.value, names)
+            })).collect()),
+            KaramelAstType::Indexer { body, indexer } => KaramelAstType::Indexer {
+                body: Self::rename(body, names),
+                indexer: Self::rename(indexer, names)
+            },
+            KaramelAstType::Slice { body, start, end, step } => KaramelAstType::Slice {
+                body: Self::rename(body, names),
+                start: rename_option(start),
+                end: rename_option(end),
+                step: rename_option(step)
+            },
+            KaramelAstType::Return(expression) => KaramelAstType::Return(Self::rename(expression, names)),
+            KaramelAstType::Yield(expression) => KaramelAstType::Yield(Self::rename(expression, names)),
+            KaramelAstType::Throw(expression) => KaramelAstType::Throw(Self::rename(expression, names)),
+            KaramelAstType::Loop { loop_type, body } => KaramelAstType::Loop {
+                loop_type: match loop_type {
+                    LoopType::Simple(condition) => LoopType::Simple(Self::rename(condition, names)),
+                    LoopType::Scalar { variable, control, increment } => LoopType::Scalar {
+                        variable: Self::rename(variable, names),
+                        control: Self::rename(control, names),
+                        increment: Self::rename(increment, names)
+                    },
+                    LoopType::ForEach { id, variables, iterable } => LoopType::ForEach {
+                        id: *id,
+                        variables: rename_variables(variables),
+                        iterable: Self::rename(iterable, names)
+                    },
+                    LoopType::Range { id, variable, start, end, step } => LoopType::Range {
+                        id: *id,
+                        variable: Self::rename_variable(variable, names),
+                        start: Self::rename(start, names),
+                        end: Self::rename(end, names),
+                        step: rename_option(step)
+                    },
+                    LoopType::Endless => LoopType::Endless
+                },
+                body: Self::rename(body, names)
+            },
+            KaramelAstType::Try { id, body, catch_variable, catch_body, finally_body } => KaramelAstType::Try {
+                id: *id,
+                body: Self::rename(body, names),
+                catch_variable: catch_variable.as_ref().map(|variable| Self::rename_variable(variable, names)),
+                catch_body: rename_option(catch_body),
+                finally_body: rename_option(finally_body)
+            },
+
+            /* Variables of the inner comprehension hide the outer ones, except at iterated expression */
+            KaramelAstType::Comprehension { id, key, value, variables, iterable, condition } => {
+                let inner_names: Vec<(String, String)> = names.iter().filter(|(name, _)| !variables.contains(name)).cloned().collect();
+                KaramelAstType::Comprehension {
+                    id: *id,
+                    key: key.as_ref().map(|key| Self::rename(key, &inner_names)),
+                    value: Self::rename(value, &inner_names),
+                    variables: variables.to_vec(),
+                    iterable: Self::rename(iterable, names),
+                    condition: condition.as_ref().map(|condition| Self::rename(condition, &inner_names))
+                }
+            },
+            KaramelAstType::ComprehensionItem { id, key, value } => KaramelAstType::ComprehensionItem {
+                id: *id,
+                key: rename_option(key),
+                value: Self::rename(value, names)
+            },
+            KaramelAstType::Match { id, line, column, value, arms, default } => KaramelAstType::Match {
+                id: *id,
+                line: *line,
+                column: *column,
+                value: Self::rename(value, names),
+                arms: arms.iter().map(|arm| Rc::new(KaramelMatchArm {
+                    pattern: Self::rename_pattern(&arm.pattern, names),
+                    guard: rename_option(&arm.guard),
+                    body: Self::rename(&arm.body, names)
+                })).collect(),
+                default: rename_option(default)
+            },
+            KaramelAstType::Conditional { condition, true_expression, false_expression } => KaramelAstType::Conditional {
+                condition: Self::rename(condition, names),
+                true_expression: Self::rename(true_expression, names),
+                false_expression: Self::rename(false_expression, names)
+            },
+            KaramelAstType::KeywordArgument { name, expression } => KaramelAstType::KeywordArgument {
+                name: name.to_string(),
+                expression: Self::rename(expression, names)
+            },
+            KaramelAstType::TextFormat { expression, format } => KaramelAstType::TextFormat {
+                expression: Self::rename(expression, names),
+                format: format.clone()
+            }
+        })
+    }
+
+    /* Bindings of 'eşleştir' patterns are variables too */
+    fn rename_pattern(pattern: &KaramelPattern, names: &[(String, String)]) -> KaramelPattern {
+        match pattern {
+            KaramelPattern::Any => KaramelPattern::Any,
+            KaramelPattern::Binding(name) => KaramelPattern::Binding(Self::rename_variable(name, names)),
+            KaramelPattern::Value(value) => KaramelPattern::Value(Self::rename(value, names)),
+            KaramelPattern::Range { start, end } => KaramelPattern::Range {
+                start: Self::rename(start, names),
+                end: Self::rename(end, names)
+            },
+            KaramelPattern::Type(name) => KaramelPattern::Type(name.to_string()),
+            KaramelPattern::List { items, rest } => KaramelPattern::List {
+                items: items.iter().map(|item| Self::rename_pattern(item, names)).collect(),
+                rest: rest.as_ref().map(|rest| Self::rename_variable(rest, names))
+            },
+            KaramelPattern::Dict(items) => KaramelPattern::Dict(items.iter().map(|(key, item)| (key.clone(), Self::rename_pattern(item, names))).collect())
+        }
+    }
+
+    /// Parses the 'her x kaynak ise koşul' part after the first item. Returns None if there is no 'her' keyword.
+    pub fn parse(parser: &SyntaxParser, key: Option<Rc<KaramelAstType>>, value: Rc<KaramelAstType>) -> AstResult {
+        let id = parser.get_index();
+        if !parser.match_keyword(KaramelKeywordType::Each) {
+            return Ok(KaramelAstType::None);
+        }
+
+        let variables = WhileLoopParser::parse_each_variables(parser)?;
+
+        parser.cleanup_whitespaces();
+        let iterable = match with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser))? {
+            KaramelAstType::None => return Err(KaramelErrorType::ComprehensionNotValid),
+            iterable => Rc::new(iterable)
+        };
+
+        parser.cleanup_whitespaces();
+        let condition = match parser.match_keyword(KaramelKeywordType::If) {
+            true => {
+                parser.cleanup_whitespaces();
+                match with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser))? {
+                    KaramelAstType::None => return Err(KaramelErrorType::ComprehensionNotValid),
+                    condition => Some(Rc::new(condition))
+                }
+            },
+            false => None
+        };
+
+        parser.cleanup_whitespaces();
+        Ok(KaramelAstType::Comprehension {
+            id,
+            key,
+            value,
+            variables,
+            iterable,
+            condition
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{KaramelError, KaramelErrorType};
+
+    use crate::types::*;
+    use crate::parser::*;
+    use crate::syntax::*;
+    use crate::compiler::value::KaramelPrimative;
+    use crate::compiler::ast::KaramelAstType;
+    use std::rc::Rc;
+
+    #[warn(unused_macros)]
+    macro_rules! test_compare {
+        ($name:ident, $text:expr, $result:expr) => {
+            #[test]
+            fn $name () {
+                let mut parser = Parser::new($text);
+                match parser.parse() {
+                    Err(_) => assert_eq!(true, false),
+                    _ => ()
+                };
+
+                let syntax = SyntaxParser::new(parser.tokens().to_vec());
+                assert_eq!(syntax.parse(), $result);
+            }
+        };
+    }
+
+    test_compare!(comprehension_1, "[x her x liste]", Ok(Rc::new(KaramelAstType::Comprehension {
+        id: 3,
+        key: None,
+        value: Rc::new(KaramelAstType::Symbol("x".to_string())),
+        variables: vec!["x".to_string()],
+        iterable: Rc::new(KaramelAstType::Symbol("liste".to_string())),
+        condition: None
+    })));
+
+    test_compare!(comprehension_2, "[x * 2 her x liste ise x > 3]", Ok(Rc::new(KaramelAstType::Comprehension {
+        id: 7,
+        key: None,
+        value: Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Symbol("x".to_string())),
+            operator: KaramelOperatorType::Multiplication,
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            line: 0,
+            column: 0
+        }),
+        variables: vec!["x".to_string()],
+        iterable: Rc::new(KaramelAstType::Symbol("liste".to_string())),
+        condition: Some(Rc::new(KaramelAstType::Control {
+            left: Rc::new(KaramelAstType::Symbol("x".to_string())),
+            operator: KaramelOperatorType::GreaterThan,
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0))))
+        }))
+    })));
+
+    test_compare!(comprehension_3, "{a: b her a, b sözlük}", Ok(Rc::new(KaramelAstType::Comprehension {
+        id: 6,
+        key: Some(Rc::new(KaramelAstType::Symbol("a".to_string()))),
+        value: Rc::new(KaramelAstType::Symbol("b".to_string())),
+        variables: vec!["a".to_string(), "b".to_string()],
+        iterable: Rc::new(KaramelAstType::Symbol("sözlük".to_string())),
+        condition: None
+    })));
+
+    test_compare!(comprehension_4, "[x her x]", Err(KaramelError {
+        error_type: KaramelErrorType::ComprehensionNotValid,
+        column: 9,
+        line: 0
+    }));
+
+    test_compare!(comprehension_5, "[x her x liste ise]", Err(KaramelError {
+        error_type: KaramelErrorType::ComprehensionNotValid,
+        column: 19,
+        line: 0
+    }));
+}
@@ -2,7 +2,7 @@ use std::rc::Rc;
 
 use crate::types::*;
 use crate::syntax::{SyntaxParser, SyntaxParserTrait, SyntaxFlag};
-use crate::syntax::binary::AddSubtractParser;
+use crate::syntax::binary::BitwiseOrParser;
 use crate::syntax::util::update_functions_for_temp_return;
 use crate::compiler::ast::KaramelAstType;
 use crate::error::KaramelErrorType;
@@ -34,10 +34,11 @@ impl SyntaxParserTrait for EqualityParser {
 
 impl SyntaxParserTrait for ControlParser {
     fn parse(parser: &SyntaxParser) -> AstResult {
-        parse_control::<AddSubtractParser>(parser, &[KaramelOperatorType::GreaterEqualThan, 
+        parse_control::<BitwiseOrParser>(parser, &[KaramelOperatorType::GreaterEqualThan, 
             KaramelOperatorType::GreaterThan,
             KaramelOperatorType::LessEqualThan, 
-            KaramelOperatorType::LessThan])
+            KaramelOperatorType::LessThan,
+            KaramelOperatorType::In])
     }
 }
 
@@ -52,7 +53,17 @@ pub fn parse_control<T: SyntaxParserTrait>(parser: &SyntaxParser, operators: &[K
     loop {
         let index_backup = parser.get_index();
         parser.cleanup_whitespaces();
-        if let Some(operator) = parser.match_operator(operators) {
+        if let Some(mut operator) = parser.match_operator(operators) {
+            /* 'içinde değil' is single operator */
+            if operator == KaramelOperatorType::In {
+                let not_backup = parser.get_index();
+                parser.cleanup_whitespaces();
+                match parser.match_operator(&[KaramelOperatorType::Not]) {
+                    Some(_) => operator = KaramelOperatorType::NotIn,
+                    None => parser.set_index(not_backup)
+                };
+            }
+
             if !functions_updated_for_temp {
                 update_functions_for_temp_return(&left_expr);
                 functions_updated_for_temp = true;
@@ -3,10 +3,11 @@ use std::rc::Rc;
 use crate::types::*;
 use crate::syntax::{SyntaxParser, SyntaxParserTrait, SyntaxFlag, ExtensionSyntaxParser};
 use crate::syntax::func_call::FuncCallParser;
+use crate::syntax::function_defination::FunctionDefinationParser;
 use crate::syntax::unary::UnaryParser;
 use crate::syntax::control::OrParser;
 use crate::syntax::util::update_functions_for_temp_return;
-use crate::compiler::ast::KaramelAstType;
+use crate::compiler::ast::{KaramelAstType, KaramelFunctionType};
 use crate::compiler::value::KaramelPrimative;
 use crate::error::KaramelErrorType;
 
@@ -16,6 +17,18 @@ pub struct ExpressionParser;
 
 impl SyntaxParserTrait for ExpressionParser {
     fn parse(parser: &SyntaxParser) -> AstResult {
+        let lambda = Self::parse_lambda(parser)?;
+        if lambda != KaramelAstType::None {
+            return Ok(lambda);
+        }
+
+        let ast = Self::parse_operand(parser)?;
+        Self::parse_conditional(ast, parser)
+    }
+}
+
+impl ExpressionParser {
+    fn parse_operand(parser: &SyntaxParser) -> AstResult {
         let mut ast = OrParser::parse(parser)?;
     
         loop {
@@ -30,7 +43,7 @@ impl SyntaxParserTrait for ExpressionParser {
             /* parse for 'object.method' */
             else if let Some(_) = parser.match_operator(&[KaramelOperatorType::Dot]) {
 
-                let sub_ast = with_flag(SyntaxFlag::IN_DICT_INDEXER, parser, || ExpressionParser::parse(parser))?;
+                let sub_ast = with_flag(SyntaxFlag::IN_DICT_INDEXER, parser, || ExpressionParser::parse_operand(parser))?;
                 ast = match &sub_ast {
                     KaramelAstType::Symbol(symbol) => {
                         KaramelAstType::Indexer 
@@ -56,4 +69,98 @@ impl SyntaxParserTrait for ExpressionParser {
 
         Ok(ast)
     }
+
+    fn parse_branch(parser: &SyntaxParser) -> AstResult {
+        parser.cleanup_whitespaces();
+        match with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser))? {
+            KaramelAstType::None => Err(KaramelErrorType::RightSideOfExpressionNotFound),
+            ast => Ok(ast)
+        }
+    }
+
+    /* parse for 'koşul ? a : b' and 'a eğer koşul yoksa b' */
+    fn parse_conditional(ast: KaramelAstType, parser: &SyntaxParser) -> AstResult {
+        if ast == KaramelAstType::None {
+            return Ok(ast);
+        }
+
+        let index_backup = parser.get_index();
+        parser.cleanup_whitespaces();
+
+        if let Some(_) = parser.match_operator(&[KaramelOperatorType::QuestionMark]) {
+            update_functions_for_temp_return(&ast);
+            let true_expression = Self::parse_branch(parser)?;
+
+            parser.cleanup_whitespaces();
+            if let None = parser.match_operator(&[KaramelOperatorType::ColonMark]) {
+                return Err(KaramelErrorType::ColonMarkMissing);
+            }
+
+            return Ok(KaramelAstType::Conditional {
+                condition: Rc::new(ast),
+                true_expression: Rc::new(true_expression),
+                false_expression: Rc::new(Self::parse_branch(parser)?)
+            });
+        }
+
+        if parser.match_keyword(KaramelKeywordType::When) {
+            update_functions_for_temp_return(&ast);
+            let condition = Self::parse_branch(parser)?;
+
+            parser.cleanup_whitespaces();
+            if !parser.match_keyword(KaramelKeywordType::Else) {
+                return Err(KaramelErrorType::ConditionalElseMissing);
+            }
+
+            return Ok(KaramelAstType::Conditional {
+                condition: Rc::new(condition),
+                true_expression: Rc::new(ast),
+                false_expression: Rc::new(Self::parse_branch(parser)?)
+            });
+        }
+
+        parser.set_index(index_backup);
+        Ok(ast)
+    }
+
+    /* parse for 'fonk(x): x * 2' */
+    fn parse_lambda(parser: &SyntaxParser) -> AstResult {
+        let index_backup = parser.get_index();
+        parser.cleanup_whitespaces();
+
+        /* Function name generated from location, it should not be written by user */
+        let function_name = format!("isimsiz#{}", parser.get_index());
+        if !parser.match_keyword(KaramelKeywordType::Fn) {
+            parser.set_index(index_backup);
+            return Ok(KaramelAstType::None);
+        }
+
+        parser.cleanup_whitespaces();
+        if !parser.check_operator(&KaramelOperatorType::LeftParentheses) {
+            return Err(KaramelErrorType::FunctionNameNotDefined);
+        }
+
+        let arguments = FunctionDefinationParser::parse_arguments(parser)?;
+        let result = FunctionDefinationParser::parse_result_type(parser)?;
+
+        parser.cleanup_whitespaces();
+        if let None = parser.match_operator(&[KaramelOperatorType::ColonMark]) {
+            return Err(KaramelErrorType::ColonMarkMissing);
+        }
+
+        parser.cleanup_whitespaces();
+        let body = ExpressionParser::parse(parser)?;
+        if body == KaramelAstType::None {
+            return Err(KaramelErrorType::FunctionConditionBodyNotFound);
+        }
+
+        Ok(KaramelAstType::Lambda(Rc::new(KaramelAstType::FunctionDefination {
+            name: function_name,
+            arguments: arguments.names,
+            defaults: arguments.defaults,
+            variadic: arguments.variadic,
+            types: KaramelFunctionType { arguments: arguments.types, result },
+            body: Rc::new(KaramelAstType::Return(Rc::new(body)))
+        })))
+    }
 }
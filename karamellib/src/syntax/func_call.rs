@@ -42,6 +42,34 @@ impl SyntaxParserTrait for FuncCallParser {
     }
 }
 
+impl FuncCallParser {
+    /* parse for 'ad=değer' */
+    fn parse_keyword_argument(parser: &SyntaxParser) -> AstResult {
+        let index_backup = parser.get_index();
+
+        if let KaramelAstType::Symbol(name) = PrimativeParser::parse_symbol(parser)? {
+            parser.cleanup_whitespaces();
+
+            if let Some(_) = parser.match_operator(&[KaramelOperatorType::Assign]) {
+                parser.cleanup_whitespaces();
+
+                let expression = ExpressionParser::parse(parser)?;
+                if expression == KaramelAstType::None {
+                    return Err(KaramelErrorType::SyntaxError);
+                }
+
+                return Ok(KaramelAstType::KeywordArgument {
+                    name,
+                    expression: Rc::new(expression)
+                });
+            }
+        }
+
+        parser.set_index(index_backup);
+        Ok(KaramelAstType::None)
+    }
+}
+
 impl ExtensionSyntaxParser for FuncCallParser {
     fn parsable(parser: &SyntaxParser) -> bool {
         if parser.flags.get().contains(SyntaxFlag::IN_DICT_INDEXER) {
@@ -65,12 +93,19 @@ impl ExtensionSyntaxParser for FuncCallParser {
 
             /* Parse function call arguments */
             let mut continue_to_parse = true;
+            let mut has_keyword_argument = false;
             while continue_to_parse {
                 parser.cleanup_whitespaces();
                 
-                let param_expression = ExpressionParser::parse(parser);
-                match param_expression {
+                let param_expression = match FuncCallParser::parse_keyword_argument(parser) {
+                    Ok(KaramelAstType::None) => ExpressionParser::parse(parser),
+                    keyword_argument => keyword_argument
+                };
+                match &param_expression {
                     Err(_) => return param_expression,
+                    Ok(KaramelAstType::KeywordArgument { name: _, expression: _ }) => has_keyword_argument = true,
+                    Ok(KaramelAstType::None) => (),
+                    Ok(_) if has_keyword_argument => return Err(KaramelErrorType::PositionalArgumentAfterKeyword),
                     _ => ()
                 };
                 
@@ -1,13 +1,24 @@
 use crate::types::*;
 use crate::syntax::{SyntaxParser, SyntaxParserTrait, SyntaxFlag};
 use crate::syntax::primative::PrimativeParser;
-use crate::compiler::ast::{KaramelAstType};
+use crate::syntax::expression::ExpressionParser;
+use crate::syntax::util::{with_flag, parse_type_name};
+use crate::compiler::ast::{KaramelAstType, KaramelFunctionType};
 use crate::syntax::block::{SingleLineBlockParser, MultiLineBlockParser};
 use crate::error::KaramelErrorType;
 use std::rc::Rc;
 
 pub struct FunctionDefinationParser;
 
+/* Parsed function arguments */
+#[derive(Default)]
+pub struct FunctionArguments {
+    pub names: Vec<String>,
+    pub defaults: Vec<Rc<KaramelAstType>>,
+    pub types: Vec<Option<String>>,
+    pub variadic: bool
+}
+
 impl SyntaxParserTrait for FunctionDefinationParser {
     fn parse(parser: &SyntaxParser) -> AstResult {
         let index_backup = parser.get_index();
@@ -18,7 +29,6 @@ impl SyntaxParserTrait for FunctionDefinationParser {
 
             parser.cleanup_whitespaces();
 
-            let mut arguments = Vec::new();
             let name_expression = PrimativeParser::parse_symbol(parser)?;
             let function_name = match name_expression {
                 KaramelAstType::Symbol(text) => text,
@@ -30,30 +40,8 @@ impl SyntaxParserTrait for FunctionDefinationParser {
             parser.cleanup_whitespaces();
 
             /* Arguments */
-            if let Some(_) = parser.match_operator(&[KaramelOperatorType::LeftParentheses]) {
-                loop {
-                    parser.cleanup_whitespaces();
-
-                    if parser.check_operator(&KaramelOperatorType::RightParentheses) {
-                        break;
-                    }
-
-                    let argument = PrimativeParser::parse_symbol(parser)?;
-                    match argument {
-                        KaramelAstType::Symbol(text) => arguments.push(text),
-                        _ => return Err(KaramelErrorType::ArgumentMustBeText)
-                    };
-
-                    parser.cleanup_whitespaces();
-                    if let None = parser.match_operator(&[KaramelOperatorType::Comma]) {
-                        break;
-                    }
-                }
-
-                if let None = parser.match_operator(&[KaramelOperatorType::RightParentheses]) {
-                    return Err(KaramelErrorType::RightParanthesesMissing);
-                }
-            }
+            let arguments = Self::parse_arguments(parser)?;
+            let result = Self::parse_result_type(parser)?;
 
             parser.cleanup_whitespaces();
             if let None = parser.match_operator(&[KaramelOperatorType::ColonMark]) {
@@ -101,7 +89,10 @@ impl SyntaxParserTrait for FunctionDefinationParser {
             let function_defination_ast = KaramelAstType::FunctionDefination {
                 name: function_name,
                 body: Rc::new(body),
-                arguments: arguments
+                arguments: arguments.names,
+                defaults: arguments.defaults,
+                variadic: arguments.variadic,
+                types: KaramelFunctionType { arguments: arguments.types, result }
             };
 
             parser.set_indentation(indentation);
@@ -112,3 +103,83 @@ impl SyntaxParserTrait for FunctionDefinationParser {
         return Ok(KaramelAstType::None);
     }
 }
+
+impl FunctionDefinationParser {
+    /* parse for '-> sayı' */
+    pub fn parse_result_type(parser: &SyntaxParser) -> Result<Option<String>, KaramelErrorType> {
+        parser.cleanup_whitespaces();
+        match parser.match_operator(&[KaramelOperatorType::Arrow]) {
+            Some(_) => match parse_type_name(parser) {
+                Some(type_name) => Ok(Some(type_name)),
+                None => Err(KaramelErrorType::TypeAnnotationNotValid)
+            },
+            None => Ok(None)
+        }
+    }
+
+    /* parse for '(a, b, c)', '(a, b=10)', '(a: sayı)' and '(a, *diğerleri)' */
+    pub fn parse_arguments(parser: &SyntaxParser) -> Result<FunctionArguments, KaramelErrorType> {
+        let mut arguments = FunctionArguments::default();
+
+        if let Some(_) = parser.match_operator(&[KaramelOperatorType::LeftParentheses]) {
+            loop {
+                parser.cleanup_whitespaces();
+
+                if parser.check_operator(&KaramelOperatorType::RightParentheses) {
+                    break;
+                }
+
+                /* Nothing can be defined after variadic argument */
+                if arguments.variadic {
+                    return Err(KaramelErrorType::ArgumentDefinationNotValid);
+                }
+
+                arguments.variadic = parser.match_operator(&[KaramelOperatorType::Multiplication]).is_some();
+
+                let argument = PrimativeParser::parse_symbol(parser)?;
+                match argument {
+                    KaramelAstType::Symbol(text) if arguments.names.contains(&text) => return Err(KaramelErrorType::ArgumentDefinationNotValid),
+                    KaramelAstType::Symbol(text) => arguments.names.push(text),
+                    _ => return Err(KaramelErrorType::ArgumentMustBeText)
+                };
+
+                parser.cleanup_whitespaces();
+                match parser.match_operator(&[KaramelOperatorType::ColonMark]) {
+                    Some(_) => match parse_type_name(parser) {
+                        Some(type_name) => arguments.types.push(Some(type_name)),
+                        None => return Err(KaramelErrorType::TypeAnnotationNotValid)
+                    },
+                    None => arguments.types.push(None)
+                };
+
+                parser.cleanup_whitespaces();
+                if let Some(_) = parser.match_operator(&[KaramelOperatorType::Assign]) {
+                    parser.cleanup_whitespaces();
+
+                    let default = with_flag(SyntaxFlag::IN_ASSIGNMENT, parser, || ExpressionParser::parse(parser))?;
+                    if default == KaramelAstType::None || arguments.variadic {
+                        return Err(KaramelErrorType::ArgumentDefinationNotValid);
+                    }
+
+                    arguments.defaults.push(Rc::new(default));
+                    parser.cleanup_whitespaces();
+                }
+
+                /* Arguments without default value can not be after default valued arguments */
+                else if !arguments.defaults.is_empty() && !arguments.variadic {
+                    return Err(KaramelErrorType::ArgumentDefinationNotValid);
+                }
+
+                if let None = parser.match_operator(&[KaramelOperatorType::Comma]) {
+                    break;
+                }
+            }
+
+            if let None = parser.match_operator(&[KaramelOperatorType::RightParentheses]) {
+                return Err(KaramelErrorType::RightParanthesesMissing);
+            }
+        }
+
+        Ok(arguments)
+    }
+}
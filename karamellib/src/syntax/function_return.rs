@@ -7,6 +7,7 @@ use crate::syntax::expression::ExpressionParser;
 use crate::error::KaramelErrorType;
 
 pub struct FunctionReturnParser;
+pub struct YieldParser;
 
 impl SyntaxParserTrait for FunctionReturnParser {
     fn parse(parser: &SyntaxParser) -> AstResult {
@@ -25,6 +26,10 @@ impl SyntaxParserTrait for FunctionReturnParser {
             parser.flags.set(parser_flags | SyntaxFlag::IN_RETURN);
             
             let ast = ExpressionParser::parse(parser)?;
+            let ast = match ast {
+                KaramelAstType::None => ast,
+                _ => Self::parse_tuple(ast, parser)?
+            };
             let return_ast = KaramelAstType::Return(Rc::new(ast));
             parser.flags.set(parser_flags);
 
@@ -34,4 +39,54 @@ impl SyntaxParserTrait for FunctionReturnParser {
         parser.set_index(index_backup);
         return Ok(KaramelAstType::None);
     }
-}
\ No newline at end of file
+}
+
+/* 'üret' suspends the function and gives the value to caller, function becomes generator */
+impl SyntaxParserTrait for YieldParser {
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        let index_backup = parser.get_index();
+        parser.cleanup_whitespaces();
+
+        if parser.match_keyword(KaramelKeywordType::Yield) {
+            if !parser.flags.get().contains(SyntaxFlag::FUNCTION_DEFINATION) {
+                parser.set_index(index_backup);
+                return Err(KaramelErrorType::YieldMustBeUsedInFunction);
+            }
+
+            parser.cleanup_whitespaces();
+            let ast = ExpressionParser::parse(parser)?;
+            return Ok(KaramelAstType::Yield(Rc::new(ast)));
+        }
+
+        parser.set_index(index_backup);
+        return Ok(KaramelAstType::None);
+    }
+}
+
+impl FunctionReturnParser {
+    /* 'döndür a, b' returns tuple */
+    fn parse_tuple(ast: KaramelAstType, parser: &SyntaxParser) -> AstResult {
+        let mut items = vec![Rc::new(ast)];
+
+        loop {
+            let index_backup = parser.get_index();
+            parser.cleanup_whitespaces();
+
+            if parser.match_operator(&[KaramelOperatorType::Comma]).is_none() {
+                parser.set_index(index_backup);
+                break;
+            }
+
+            parser.cleanup_whitespaces();
+            match ExpressionParser::parse(parser)? {
+                KaramelAstType::None => return Err(KaramelErrorType::RightSideOfExpressionNotFound),
+                item => items.push(Rc::new(item))
+            };
+        }
+
+        Ok(match items.len() {
+            1 => (*items.remove(0)).clone(),
+            _ => KaramelAstType::Tuple(items)
+        })
+    }
+}
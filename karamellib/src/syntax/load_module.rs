@@ -1,10 +1,14 @@
 use crate::types::*;
 use crate::syntax::{SyntaxParser, SyntaxParserTrait};
 use crate::compiler::ast::KaramelAstType;
+use crate::error::KaramelErrorType;
 use super::primative::PrimativeParser;
 use super::util::map_parser;
 
+pub const USE_ALL: &str = "*";
+
 pub struct LoadModuleParser;
+pub struct UseModuleParser;
 
 impl SyntaxParserTrait for LoadModuleParser {
     fn parse(parser: &SyntaxParser) -> AstResult {
@@ -44,3 +48,87 @@ impl SyntaxParserTrait for LoadModuleParser {
         return Ok(KaramelAstType::None);
     }
 }
+
+/*
+Import function into current module
+Example:
+    kullan modül::fonksiyon
+    kullan modül::*
+*/
+impl SyntaxParserTrait for UseModuleParser {
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        let index_backup = parser.get_index();
+        parser.indentation_check()?;
+
+        if !parser.match_keyword(KaramelKeywordType::Use) {
+            parser.set_index(index_backup);
+            return Ok(KaramelAstType::None);
+        }
+
+        parser.cleanup_whitespaces();
+        let mut path = Vec::new();
+
+        loop {
+            match &parser.peek_token() {
+                Ok(token) => match &token.token_type {
+                    KaramelTokenType::Symbol(symbol) => path.push(symbol.to_string()),
+                    KaramelTokenType::Operator(KaramelOperatorType::Multiplication) if path.len() > 0 => path.push(USE_ALL.to_string()),
+                    _ => return Err(KaramelErrorType::UseStatementNotValid)
+                },
+                Err(_) => return Err(KaramelErrorType::UseStatementNotValid)
+            };
+            parser.consume_token();
+
+            if path.last().unwrap() == USE_ALL || parser.match_operator(&[KaramelOperatorType::ColonMark]).is_none() {
+                break;
+            }
+
+            if parser.match_operator(&[KaramelOperatorType::ColonMark]).is_none() {
+                return Err(KaramelErrorType::UseStatementNotValid);
+            }
+        }
+
+        /* Module name and function name required */
+        if path.len() < 2 {
+            return Err(KaramelErrorType::UseStatementNotValid);
+        }
+
+        Ok(KaramelAstType::Use(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{KaramelError, KaramelErrorType};
+
+    use crate::parser::*;
+    use crate::syntax::*;
+    use crate::compiler::ast::KaramelAstType;
+    use std::rc::Rc;
+
+    #[warn(unused_macros)]
+    macro_rules! test_compare {
+        ($name:ident, $text:expr, $result:expr) => {
+            #[test]
+            fn $name () {
+                let mut parser = Parser::new($text);
+                match parser.parse() {
+                    Err(_) => assert_eq!(true, false),
+                    _ => ()
+                };
+
+                let syntax = SyntaxParser::new(parser.tokens().to_vec());
+                assert_eq!(syntax.parse(), $result);
+            }
+        };
+    }
+
+    test_compare!(use_1, "kullan baz::aralık", Ok(Rc::new(KaramelAstType::Use(vec!["baz".to_string(), "aralık".to_string()]))));
+    test_compare!(use_2, "kullan foo::bar::baz", Ok(Rc::new(KaramelAstType::Use(vec!["foo".to_string(), "bar".to_string(), "baz".to_string()]))));
+    test_compare!(use_3, "kullan hataayıklama::*", Ok(Rc::new(KaramelAstType::Use(vec!["hataayıklama".to_string(), "*".to_string()]))));
+    test_compare!(use_4, "kullan baz", Err(KaramelError::new(0, 10, KaramelErrorType::UseStatementNotValid)));
+    test_compare!(use_5, "kullan *", Err(KaramelError::new(0, 8, KaramelErrorType::UseStatementNotValid)));
+    test_compare!(use_6, "kullan baz:aralık", Err(KaramelError::new(0, 17, KaramelErrorType::UseStatementNotValid)));
+    test_compare!(use_7, "fonk test():\n    kullan baz::aralık", Err(KaramelError::new(1, 10, KaramelErrorType::UseStatementNotValid)));
+    test_compare!(use_8, "doğru ise: kullan baz::aralık", Err(KaramelError::new(0, 17, KaramelErrorType::UseStatementNotValid)));
+}
@@ -3,8 +3,10 @@ use std::rc::Rc;
 use crate::types::*;
 use crate::syntax::{SyntaxParser, SyntaxParserTrait, SyntaxFlag};
 use crate::compiler::ast::{KaramelAstType};
+use crate::compiler::value::KaramelPrimative;
 use crate::syntax::block::{SingleLineBlockParser, MultiLineBlockParser};
 use crate::syntax::expression::ExpressionParser;
+use crate::syntax::primative::PrimativeParser;
 use crate::error::KaramelErrorType;
 
 use super::assignment::AssignmentParser;
@@ -19,9 +21,129 @@ pub enum LoopType {
         control: Rc<KaramelAstType>,
         increment: Rc<KaramelAstType>
     },
+    ForEach {
+        id: usize,
+        variables: Vec<String>,
+        iterable: Rc<KaramelAstType>
+    },
+    Range {
+        id: usize,
+        variable: String,
+        start: Rc<KaramelAstType>,
+        end: Rc<KaramelAstType>,
+        step: Option<Rc<KaramelAstType>>
+    },
     Endless
 }
 
+impl LoopType {
+    /// Hidden variables that keep iterated object and current position.
+    pub fn get_iterator_variables(id: usize) -> (String, String) {
+        (format!("#kaynak{}", id), format!("#sıra{}", id))
+    }
+
+    /// Hidden variables that keep next value, last value and step of range loop.
+    pub fn get_range_variables(id: usize) -> (String, String, String) {
+        (format!("#sıra{}", id), format!("#bitiş{}", id), format!("#adım{}", id))
+    }
+}
+
+impl WhileLoopParser {
+    /* 'her eleman' or 'her anahtar, değer', also used by list and dict comprehensions */
+    pub fn parse_each_variables(parser: &SyntaxParser) -> Result<Vec<String>, KaramelErrorType> {
+        let mut variables = Vec::new();
+
+        loop {
+            parser.cleanup_whitespaces();
+            match PrimativeParser::parse_symbol(parser)? {
+                KaramelAstType::Symbol(variable) => variables.push(variable),
+                _ => return Err(KaramelErrorType::WhileStatementNotValid)
+            };
+
+            parser.cleanup_whitespaces();
+            if variables.len() == 2 || parser.match_operator(&[KaramelOperatorType::Comma]).is_none() {
+                break;
+            }
+        }
+        Ok(variables)
+    }
+
+    /*
+    Range loop
+    Example:
+        döngü i 1'den 10'a kadar:
+        döngü i 10'dan 0'a kadar, -2:
+    */
+    fn parse_range(parser: &SyntaxParser, loop_id: usize) -> Result<Option<LoopType>, KaramelErrorType> {
+        let index_backup = parser.get_index();
+
+        let variable = match PrimativeParser::parse_symbol(parser)? {
+            KaramelAstType::Symbol(variable) => variable,
+            _ => return Ok(None)
+        };
+
+        let mut range = Vec::new();
+        for _ in 0..2 {
+            parser.cleanup_whitespaces();
+            match with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser)) {
+                Ok(KaramelAstType::None) | Err(_) => {
+                    parser.set_index(index_backup);
+                    return Ok(None);
+                },
+                Ok(expression) => range.push(Rc::new(expression))
+            };
+        }
+
+        parser.cleanup_whitespaces();
+        if !parser.match_keyword(KaramelKeywordType::Until) {
+            parser.set_index(index_backup);
+            return Ok(None);
+        }
+
+        /* From now on, it is a range loop */
+        parser.cleanup_whitespaces();
+        let step = match parser.match_operator(&[KaramelOperatorType::Comma]) {
+            Some(_) => {
+                parser.cleanup_whitespaces();
+                match with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser))? {
+                    KaramelAstType::None => return Err(KaramelErrorType::WhileStatementNotValid),
+                    step => Some(Rc::new(step))
+                }
+            },
+            None => None
+        };
+
+        let end = range.pop().unwrap();
+        let start = range.pop().unwrap();
+
+        /* Constant step that goes away from the end never runs the loop */
+        if let (Some(start), Some(end), Some(step)) = (Self::get_constant_number(&start), Self::get_constant_number(&end), step.as_ref().and_then(|step| Self::get_constant_number(step))) {
+            if (start < end && step < 0.0) || (start > end && step > 0.0) {
+                return Err(KaramelErrorType::RangeStepDirectionNotValid);
+            }
+        }
+
+        Ok(Some(LoopType::Range {
+            id: loop_id,
+            variable,
+            start,
+            end,
+            step
+        }))
+    }
+
+    fn get_constant_number(ast: &KaramelAstType) -> Option<f64> {
+        match ast {
+            KaramelAstType::Primative(primative) => match &**primative {
+                KaramelPrimative::Number(number) | KaramelPrimative::Float(number) => Some(*number),
+                KaramelPrimative::BigInt(number) => Some(number.to_f64()),
+                _ => None
+            },
+            _ => None
+        }
+    }
+}
+
 pub struct WhileLoopParser;
 
 impl SyntaxParserTrait for WhileLoopParser {
@@ -29,72 +151,104 @@ impl SyntaxParserTrait for WhileLoopParser {
         parser.indentation_check()?;
 
         let indentation = parser.get_indentation();
-        let loop_type = match parser.match_keywords(&[KaramelKeywordType::Endless, KaramelKeywordType::While]) {
+        let loop_id = parser.get_index();
+        let loop_type = match parser.match_keywords(&[KaramelKeywordType::Endless, KaramelKeywordType::While, KaramelKeywordType::Each]) {
             // Endless loop
             Some(KaramelKeywordType::Endless) => LoopType::Endless,
 
+            /*
+            For each loop
+            Example:
+                her eleman liste'de ise:
+                her anahtar, değer sözlük'te ise:
+            */
+            Some(KaramelKeywordType::Each) => {
+                let variables = Self::parse_each_variables(parser)?;
+
+                parser.cleanup_whitespaces();
+                let iterable = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser))?;
+                if iterable == KaramelAstType::None {
+                    return Err(KaramelErrorType::WhileStatementNotValid);
+                }
+
+                parser.cleanup_whitespaces();
+                if !parser.match_keyword(KaramelKeywordType::If) {
+                    return Err(KaramelErrorType::MissingIf);
+                }
+
+                LoopType::ForEach {
+                    id: loop_id,
+                    variables,
+                    iterable: Rc::new(iterable)
+                }
+            },
+
             // While loop
             Some(KaramelKeywordType::While) => {
 
                 /* AssignmentParser has indentation check so we need to move indentation forward */
                 parser.cleanup_whitespaces();
 
-                /*
-
-                We need to detect while loop type. We are checking body loop control section to understand while loop type.
-
-                Parser: ExpressionParser::parse
-                Example: 
-                    döngü doğru:
-
-                Parser: AssignmentParser::parse
-                Example:
-                    döngü 1 = 1, a <10, ++a:
-                */
-                let loop_expression = map_parser_with_flag(SyntaxFlag::IN_EXPRESSION, parser, &[AssignmentParser::parse, ExpressionParser::parse])?;
-                let loop_type = match &loop_expression {
-                    KaramelAstType::None =>  {
-                        /* Reset indentation */
-                        parser.set_indentation(indentation);
-                        return Ok(KaramelAstType::None);
-                    },
-
-                    // It is scalar loop
-                    KaramelAstType::Assignment { variable: _, operator, expression: _ } => {
-                        /* Loop just accept assignation operator, other operators are not valid */
-                        if !operator.is_same(KaramelOperatorType::Assign) {
-                            return Err(KaramelErrorType::AssignOperatorRequiredForLoop);
-                        }
-
-                        parser.cleanup_whitespaces();
-                        if parser.match_operator(&[KaramelOperatorType::Comma]).is_none() {
-                            return Err(KaramelErrorType::CommaIsMissing)
-                        }
-
-                        parser.cleanup_whitespaces();
-
-                        let loop_control = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser))?;
-                        parser.cleanup_whitespaces();
-                        if parser.match_operator(&[KaramelOperatorType::Comma]).is_none() {
-                            return Err(KaramelErrorType::CommaIsMissing)
-                        }
-
-                        parser.cleanup_whitespaces();
-                        let loop_increment = ExpressionParser::parse(parser)?;
-                        parser.cleanup_whitespaces();
-
-                        LoopType::Scalar {
-                            variable: Rc::new(loop_expression),
-                            control: Rc::new(loop_control),
-                            increment: Rc::new(loop_increment)
-                        }
-                    },
-
-                    // It is simple loop with condition
-                    _ => LoopType::Simple(Rc::new(loop_expression.clone()))
-                };
-
-                loop_type
+                if let Some(range) = WhileLoopParser::parse_range(parser, loop_id)? {
+                    range
+                } else {
+                    /*
+
+                    We need to detect while loop type. We are checking body loop control section to understand while loop type.
+
+                    Parser: ExpressionParser::parse
+                    Example: 
+                        döngü doğru:
+
+                    Parser: AssignmentParser::parse
+                    Example:
+                        döngü 1 = 1, a <10, ++a:
+                    */
+                    let loop_expression = map_parser_with_flag(SyntaxFlag::IN_EXPRESSION, parser, &[AssignmentParser::parse, ExpressionParser::parse])?;
+                    let loop_type = match &loop_expression {
+                        KaramelAstType::None =>  {
+                            /* Reset indentation */
+                            parser.set_indentation(indentation);
+                            return Ok(KaramelAstType::None);
+                        },
+
+                        // It is scalar loop
+                        KaramelAstType::Assignment { variable: _, operator, expression: _ } => {
+                            /* Loop just accept assignation operator, other operators are not valid */
+                            if !operator.is_same(KaramelOperatorType::Assign) {
+                                return Err(KaramelErrorType::AssignOperatorRequiredForLoop);
+                            }
+
+                            parser.cleanup_whitespaces();
+                            if parser.match_operator(&[KaramelOperatorType::Comma]).is_none() {
+                                return Err(KaramelErrorType::CommaIsMissing)
+                            }
+
+                            parser.cleanup_whitespaces();
+
+                            let loop_control = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser))?;
+                            parser.cleanup_whitespaces();
+                            if parser.match_operator(&[KaramelOperatorType::Comma]).is_none() {
+                                return Err(KaramelErrorType::CommaIsMissing)
+                            }
+
+                            parser.cleanup_whitespaces();
+                            let loop_increment = ExpressionParser::parse(parser)?;
+                            parser.cleanup_whitespaces();
+
+                            LoopType::Scalar {
+                                variable: Rc::new(loop_expression),
+                                control: Rc::new(loop_control),
+                                increment: Rc::new(loop_increment)
+                            }
+                        },
+
+                        // It is simple loop with condition
+                        _ => LoopType::Simple(Rc::new(loop_expression.clone()))
+                    };
+
+                    loop_type
+                }
             },
             _ => {
 
@@ -267,4 +421,63 @@ doğru
 test_compare!(scalar_5, r#"döngü i = 1, i < 1,
 doğru
 "#, Err(KaramelError::new(0, 19, KaramelErrorType::ColonMarkMissing)));
+test_compare!(foreach_1, r#"her eleman dizi'de ise:
+    eleman
+"#, Ok(Rc::new(KaramelAstType::Loop {
+    loop_type: LoopType::ForEach {
+        id: 0,
+        variables: vec!["eleman".to_string()],
+        iterable: Rc::new(KaramelAstType::Symbol("dizi".to_string()))
+    },
+    body: Rc::new(KaramelAstType::Symbol("eleman".to_string()))
+})));
+test_compare!(foreach_2, r#"her anahtar, değer tablo ise:
+    anahtar
+"#, Ok(Rc::new(KaramelAstType::Loop {
+    loop_type: LoopType::ForEach {
+        id: 0,
+        variables: vec!["anahtar".to_string(), "değer".to_string()],
+        iterable: Rc::new(KaramelAstType::Symbol("tablo".to_string()))
+    },
+    body: Rc::new(KaramelAstType::Symbol("anahtar".to_string()))
+})));
+test_compare!(foreach_3, r#"her eleman dizi:
+    eleman
+"#, Err(KaramelError::new(0, 16, KaramelErrorType::MissingIf)));
+test_compare!(foreach_4, r#"her ise:
+    eleman
+"#, Err(KaramelError::new(0, 7, KaramelErrorType::WhileStatementNotValid)));
+test_compare!(range_1, r#"döngü i 1'den 10'a kadar:
+    i
+"#, Ok(Rc::new(KaramelAstType::Loop {
+    loop_type: LoopType::Range {
+        id: 0,
+        variable: "i".to_string(),
+        start: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+        end: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+        step: None
+    },
+    body: Rc::new(KaramelAstType::Symbol("i".to_string()))
+})));
+test_compare!(range_2, r#"döngü i 10'dan son'a kadar, 2:
+    i
+"#, Ok(Rc::new(KaramelAstType::Loop {
+    loop_type: LoopType::Range {
+        id: 0,
+        variable: "i".to_string(),
+        start: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+        end: Rc::new(KaramelAstType::Symbol("son".to_string())),
+        step: Some(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))))
+    },
+    body: Rc::new(KaramelAstType::Symbol("i".to_string()))
+})));
+test_compare!(range_3, r#"döngü i 1'den 10'a kadar, :
+    i
+"#, Err(KaramelError::new(0, 27, KaramelErrorType::WhileStatementNotValid)));
+test_compare!(range_4, r#"döngü i 10'dan 0'a kadar, 2:
+    i
+"#, Err(KaramelError::new(0, 28, KaramelErrorType::RangeStepDirectionNotValid)));
+test_compare!(range_5, r#"döngü i 1'den 10'a kadar, -1:
+    i
+"#, Err(KaramelError::new(0, 29, KaramelErrorType::RangeStepDirectionNotValid)));
 }
@@ -10,11 +10,15 @@ pub mod newline;
 pub mod if_condition;
 pub mod statement;
 pub mod function_defination;
+pub mod class_defination;
 pub mod function_return;
 pub mod loops;
 pub mod loop_item;
 pub mod expression;
 pub mod load_module;
+pub mod try_catch;
+pub mod pattern_match;
+pub mod comprehension;
 
 use std::borrow::Borrow;
 use std::rc::Rc;
@@ -34,7 +38,10 @@ pub struct SyntaxParser {
     pub tokens: Vec<Token>,
     pub index: Cell<usize>,
     pub indentation: Cell<usize>,
-    pub flags: Cell<SyntaxFlag>
+    pub flags: Cell<SyntaxFlag>,
+
+    /* Statement locations are added to ast, type checker uses them for error messages */
+    pub locations: bool
 }
 
 bitflags! {
@@ -47,6 +54,7 @@ bitflags! {
         const IN_FUNCTION_ARG     = 0b00010000;
         const IN_RETURN           = 0b00100000;
         const IN_DICT_INDEXER     = 0b01000000;
+        const IN_BLOCK            = 0b10000000;
     }
 }
 
@@ -65,10 +73,17 @@ impl SyntaxParser {
             tokens,
             index: Cell::new(0),
             indentation: Cell::new(0),
-            flags: Cell::new(SyntaxFlag::NONE)
+            flags: Cell::new(SyntaxFlag::NONE),
+            locations: false
         }
     }
 
+    pub fn with_locations(tokens: Vec<Token>) -> SyntaxParser {
+        let mut parser = SyntaxParser::new(tokens);
+        parser.locations = true;
+        parser
+    }
+
     pub fn parse(&self) -> Result<Rc<KaramelAstType>, KaramelError> {
         return match MultiLineBlockParser::parse(&self) {
             Ok(ast) => {
@@ -103,6 +118,14 @@ impl SyntaxParser {
         };
     }
 
+    /* Position of the next token, it is only kept for type checker */
+    pub fn get_location(&self) -> (u32, u32) {
+        match self.peek_token() {
+            Ok(token) if self.locations => (token.line, token.start),
+            _ => (0, 0)
+        }
+    }
+
     pub fn set_indentation(&self, indentation: usize) {
         self.indentation.set(indentation);
     }
@@ -0,0 +1,351 @@
+use std::rc::Rc;
+
+use crate::types::*;
+use crate::syntax::{SyntaxParser, SyntaxParserTrait, SyntaxFlag};
+use crate::syntax::primative::PrimativeParser;
+use crate::syntax::unary::UnaryParser;
+use crate::syntax::expression::ExpressionParser;
+use crate::syntax::block::{SingleLineBlockParser, MultiLineBlockParser};
+use crate::syntax::util::with_flag;
+use crate::compiler::ast::{KaramelAstType, KaramelMatchArm, KaramelPattern};
+use crate::error::KaramelErrorType;
+
+/* Names that matches with the type of the value instead of binding it */
+static TYPE_NAMES: &[&str] = &["sayı", "yazı", "bool", "liste", "sözlük", "fonksiyon", "sınıf", "boş", "demet", "üreteç", "küme"];
+
+pub struct MatchParser;
+
+impl MatchParser {
+    /// Hidden variable that keeps the matched value.
+    pub fn get_value_variable(id: usize) -> String {
+        format!("#eşleştir{}", id)
+    }
+
+    /// Matched value is calculated once and kept at hidden variable.
+    pub fn get_value_assignment(id: usize, value: Rc<KaramelAstType>) -> KaramelAstType {
+        KaramelAstType::Assignment {
+            variable: Rc::new(KaramelAstType::Symbol(Self::get_value_variable(id))),
+            operator: KaramelOperatorType::Assign,
+            expression: value
+        }
+    }
+
+    fn parse_body(parser: &SyntaxParser, indentation: usize) -> AstResult {
+        parser.cleanup_whitespaces();
+        if parser.match_operator(&[KaramelOperatorType::ColonMark]).is_none() {
+            return Err(KaramelErrorType::ColonMarkMissing);
+        }
+
+        parser.cleanup_whitespaces();
+        let body = match parser.get_newline() {
+            (true, _) => {
+                parser.in_indication()?;
+                MultiLineBlockParser::parse(parser)
+            },
+            (false, _) => SingleLineBlockParser::parse(parser)
+        }?;
+        parser.set_indentation(indentation);
+
+        if body == KaramelAstType::None {
+            return Err(KaramelErrorType::MatchStatementNotValid);
+        }
+
+        Ok(body)
+    }
+
+    /*
+    Example:
+        1
+        1'den 10'a kadar
+        sayı
+        değer
+        [ilk, ...kalan]
+        {'ad': ad}
+    */
+    fn parse_pattern(parser: &SyntaxParser) -> Result<KaramelPattern, KaramelErrorType> {
+        parser.cleanup_whitespaces();
+
+        if parser.match_operator(&[KaramelOperatorType::SquareBracketStart]).is_some() {
+            return Self::parse_list_pattern(parser);
+        }
+
+        if parser.match_operator(&[KaramelOperatorType::CurveBracketStart]).is_some() {
+            return Self::parse_dict_pattern(parser);
+        }
+
+        if let KaramelAstType::Symbol(name) = PrimativeParser::parse_symbol(parser)? {
+            return Ok(match &name[..] {
+                "_" => KaramelPattern::Any,
+                _ if TYPE_NAMES.contains(&&name[..]) => KaramelPattern::Type(name),
+                _ => KaramelPattern::Binding(name)
+            });
+        }
+
+        let start = match with_flag(SyntaxFlag::IN_EXPRESSION, parser, || UnaryParser::parse(parser))? {
+            KaramelAstType::None => return Err(KaramelErrorType::PatternNotValid),
+            start => Rc::new(start)
+        };
+
+        /* Range pattern, last value is included */
+        let index_backup = parser.get_index();
+        parser.cleanup_whitespaces();
+        if let Ok(KaramelAstType::Primative(end)) = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || UnaryParser::parse(parser)) {
+            parser.cleanup_whitespaces();
+            if parser.match_keyword(KaramelKeywordType::Until) {
+                return Ok(KaramelPattern::Range {
+                    start,
+                    end: Rc::new(KaramelAstType::Primative(end))
+                });
+            }
+        }
+
+        parser.set_index(index_backup);
+        Ok(KaramelPattern::Value(start))
+    }
+
+    fn parse_list_pattern(parser: &SyntaxParser) -> Result<KaramelPattern, KaramelErrorType> {
+        let mut items = Vec::new();
+        let mut rest = None;
+
+        loop {
+            parser.cleanup_whitespaces();
+            if items.is_empty() && parser.match_operator(&[KaramelOperatorType::SquareBracketEnd]).is_some() {
+                break;
+            }
+
+            /* '...kalan' should be the last item */
+            if parser.match_operator(&[KaramelOperatorType::Dot]).is_some() {
+                for _ in 0..2 {
+                    if parser.match_operator(&[KaramelOperatorType::Dot]).is_none() {
+                        return Err(KaramelErrorType::PatternNotValid);
+                    }
+                }
+
+                rest = match PrimativeParser::parse_symbol(parser)? {
+                    KaramelAstType::Symbol(name) => Some(name),
+                    _ => return Err(KaramelErrorType::PatternNotValid)
+                };
+            }
+            else {
+                items.push(Self::parse_pattern(parser)?);
+            }
+
+            parser.cleanup_whitespaces();
+            match parser.match_operator(&[KaramelOperatorType::SquareBracketEnd, KaramelOperatorType::Comma]) {
+                Some(KaramelOperatorType::SquareBracketEnd) => break,
+                Some(KaramelOperatorType::Comma) if rest.is_none() => (),
+                _ => return Err(KaramelErrorType::PatternNotValid)
+            };
+        }
+
+        Ok(KaramelPattern::List { items, rest })
+    }
+
+    fn parse_dict_pattern(parser: &SyntaxParser) -> Result<KaramelPattern, KaramelErrorType> {
+        let mut items = Vec::new();
+
+        loop {
+            parser.cleanup_whitespaces();
+            if items.is_empty() && parser.match_operator(&[KaramelOperatorType::CurveBracketEnd]).is_some() {
+                break;
+            }
+
+            let key = match PrimativeParser::parse_basic_primatives(parser)? {
+                KaramelAstType::Primative(key) => key,
+                _ => return Err(KaramelErrorType::PatternNotValid)
+            };
+
+            parser.cleanup_whitespaces();
+            if parser.match_operator(&[KaramelOperatorType::ColonMark]).is_none() {
+                return Err(KaramelErrorType::ColonMarkMissing);
+            }
+
+            items.push((key, Self::parse_pattern(parser)?));
+
+            parser.cleanup_whitespaces();
+            match parser.match_operator(&[KaramelOperatorType::CurveBracketEnd, KaramelOperatorType::Comma]) {
+                Some(KaramelOperatorType::CurveBracketEnd) => break,
+                Some(KaramelOperatorType::Comma) => (),
+                _ => return Err(KaramelErrorType::PatternNotValid)
+            };
+        }
+
+        Ok(KaramelPattern::Dict(items))
+    }
+
+    fn parse_arm(parser: &SyntaxParser, indentation: usize) -> Result<KaramelMatchArm, KaramelErrorType> {
+        let pattern = Self::parse_pattern(parser)?;
+        parser.cleanup_whitespaces();
+
+        let guard = match parser.match_keyword(KaramelKeywordType::When) {
+            true => {
+                parser.cleanup_whitespaces();
+                match with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser))? {
+                    KaramelAstType::None => return Err(KaramelErrorType::MatchStatementNotValid),
+                    guard => Some(Rc::new(guard))
+                }
+            },
+            false => None
+        };
+
+        parser.cleanup_whitespaces();
+        if !parser.match_keyword(KaramelKeywordType::If) {
+            return Err(KaramelErrorType::MissingIf);
+        }
+
+        Ok(KaramelMatchArm {
+            pattern,
+            guard,
+            body: Rc::new(Self::parse_body(parser, indentation)?)
+        })
+    }
+}
+
+impl SyntaxParserTrait for MatchParser {
+    /*
+    Example:
+        eşleştir değer:
+            0 ise: gç::satıryaz('sıfır')
+            1'den 9'a kadar ise: gç::satıryaz('rakam')
+            [ilk, ...kalan] ise: gç::satıryaz(ilk)
+            {'ad': ad} ise: gç::satıryaz(ad)
+            yazı ise: gç::satıryaz('yazı')
+            sayı eğer değer < 0 ise: gç::satıryaz('negatif')
+            yoksa: gç::satıryaz('bilinmiyor')
+    */
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        let index_backup = parser.get_index();
+        parser.indentation_check()?;
+
+        let indentation = parser.get_indentation();
+        let id = parser.get_index();
+        let (line, column) = match parser.peek_token() {
+            Ok(token) => (token.line, token.start),
+            Err(_) => (0, 0)
+        };
+
+        if !parser.match_keyword(KaramelKeywordType::Match) {
+            parser.set_index(index_backup);
+            parser.set_indentation(indentation);
+            return Ok(KaramelAstType::None);
+        }
+
+        parser.cleanup_whitespaces();
+        let value = match with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser))? {
+            KaramelAstType::None => return Err(KaramelErrorType::MatchStatementNotValid),
+            value => Rc::new(value)
+        };
+
+        parser.cleanup_whitespaces();
+        if parser.match_operator(&[KaramelOperatorType::ColonMark]).is_none() {
+            return Err(KaramelErrorType::ColonMarkMissing);
+        }
+
+        /* Arms should be written at new lines */
+        parser.cleanup_whitespaces();
+        if !parser.get_newline().0 {
+            return Err(KaramelErrorType::MatchStatementNotValid);
+        }
+
+        parser.in_indication()?;
+        let arm_indentation = parser.get_indentation();
+
+        let mut arms = Vec::new();
+        let mut default = None;
+        loop {
+            /* 'yoksa' should be the last arm */
+            if default.is_some() {
+                return Err(KaramelErrorType::MatchStatementNotValid);
+            }
+
+            match parser.match_keyword(KaramelKeywordType::Else) {
+                true => default = Some(Rc::new(Self::parse_body(parser, arm_indentation)?)),
+                false => arms.push(Rc::new(Self::parse_arm(parser, arm_indentation)?))
+            };
+
+            if !parser.is_same_indentation(arm_indentation) {
+                break;
+            }
+        }
+
+        parser.set_indentation(indentation);
+        Ok(KaramelAstType::Match {
+            id,
+            line,
+            column,
+            value,
+            arms,
+            default
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{KaramelError, KaramelErrorType};
+
+    use crate::parser::*;
+    use crate::syntax::*;
+    use crate::compiler::ast::{KaramelAstType, KaramelMatchArm, KaramelPattern};
+    use crate::compiler::value::KaramelPrimative;
+    use std::rc::Rc;
+
+    #[warn(unused_macros)]
+    macro_rules! test_compare {
+        ($name:ident, $text:expr, $result:expr) => {
+            #[test]
+            fn $name () {
+                let mut parser = Parser::new($text);
+                match parser.parse() {
+                    Err(_) => assert_eq!(true, false),
+                    _ => ()
+                };
+
+                let syntax = SyntaxParser::new(parser.tokens().to_vec());
+                assert_eq!(syntax.parse(), $result);
+            }
+        };
+    }
+
+    fn number(value: f64) -> Rc<KaramelAstType> {
+        Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(value))))
+    }
+
+    fn arm(pattern: KaramelPattern, guard: Option<Rc<KaramelAstType>>, body: f64) -> Rc<KaramelMatchArm> {
+        Rc::new(KaramelMatchArm { pattern, guard, body: number(body) })
+    }
+
+    test_compare!(match_1, "eşleştir a:\n    1 ise: 1\n    2'den 5'e kadar ise: 2\n    yoksa: 3", Ok(Rc::new(KaramelAstType::Match {
+        id: 0,
+        line: 0,
+        column: 0,
+        value: Rc::new(KaramelAstType::Symbol("a".to_string())),
+        arms: vec![
+            arm(KaramelPattern::Value(number(1.0)), None, 1.0),
+            arm(KaramelPattern::Range { start: number(2.0), end: number(5.0) }, None, 2.0)
+        ],
+        default: Some(number(3.0))
+    })));
+    test_compare!(match_2, "eşleştir a:\n    [b, _, ...c] ise: 1\n    {'ad': sayı} ise: 2", Ok(Rc::new(KaramelAstType::Match {
+        id: 0,
+        line: 0,
+        column: 0,
+        value: Rc::new(KaramelAstType::Symbol("a".to_string())),
+        arms: vec![
+            arm(KaramelPattern::List { items: vec![KaramelPattern::Binding("b".to_string()), KaramelPattern::Any], rest: Some("c".to_string()) }, None, 1.0),
+            arm(KaramelPattern::Dict(vec![(Rc::new(KaramelPrimative::Text(Rc::new("ad".to_string()))), KaramelPattern::Type("sayı".to_string()))]), None, 2.0)
+        ],
+        default: None
+    })));
+    test_compare!(match_3, "eşleştir a:\n    b eğer b ise: 1", Ok(Rc::new(KaramelAstType::Match {
+        id: 0,
+        line: 0,
+        column: 0,
+        value: Rc::new(KaramelAstType::Symbol("a".to_string())),
+        arms: vec![arm(KaramelPattern::Binding("b".to_string()), Some(Rc::new(KaramelAstType::Symbol("b".to_string()))), 1.0)],
+        default: None
+    })));
+    test_compare!(match_4, "eşleştir a: 1 ise: 1", Err(KaramelError::new(0, 13, KaramelErrorType::MatchStatementNotValid)));
+    test_compare!(match_5, "eşleştir a:\n    yoksa: 1\n    1 ise: 1", Err(KaramelError::new(2, 5, KaramelErrorType::MatchStatementNotValid)));
+    test_compare!(match_6, "eşleştir a:\n    [...b, c] ise: 1", Err(KaramelError::new(1, 10, KaramelErrorType::PatternNotValid)));
+}
@@ -2,9 +2,11 @@ use std::rc::Rc;
 
 use crate::types::*;
 use crate::syntax::util::*;
-use crate::syntax::{SyntaxParser, SyntaxParserTrait};
+use crate::syntax::{SyntaxParser, SyntaxParserTrait, SyntaxFlag};
 use crate::syntax::expression::ExpressionParser;
+use crate::syntax::comprehension::ComprehensionParser;
 use crate::compiler::value::KaramelPrimative;
+use crate::compiler::bigint::BigInt;
 use crate::compiler::ast::{KaramelAstType, KaramelDictItem};
 use crate::error::KaramelErrorType;
 
@@ -21,9 +23,11 @@ impl PrimativeParser {
         }
 
         let result = match &token.unwrap().token_type {
-            KaramelTokenType::Integer(int)      => Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(*int as f64)))),
-            KaramelTokenType::Double(double)    => Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(*double)))),
+            KaramelTokenType::Integer(int)      => Ok(KaramelAstType::Primative(Rc::new(BigInt::from_i64(*int).to_primative()))),
+            KaramelTokenType::BigInteger(int)   => Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::BigInt(int.clone())))),
+            KaramelTokenType::Double(double)    => Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::from_float(*double)))),
             KaramelTokenType::Text(text)        => Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::clone(text))))),
+            KaramelTokenType::TextTemplate(parts) => PrimativeParser::parse_text_template(parts),
             KaramelTokenType::Keyword(keyword)  => {
                 match keyword {
                     KaramelKeywordType::True  => Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Bool(true)))),
@@ -48,6 +52,32 @@ impl PrimativeParser {
         }
     }
 
+    /* Each expression in text has own tokens, they are parsed with new parser */
+    fn parse_text_template(parts: &[KaramelTextPart]) -> AstResult {
+        let mut asts = Vec::with_capacity(parts.len());
+        for part in parts {
+            match part {
+                KaramelTextPart::Text(text) => asts.push(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(text.clone()))))),
+                KaramelTextPart::Expression { tokens, format } => {
+                    let parser = SyntaxParser::new(tokens.to_vec());
+                    let expression = with_flag(SyntaxFlag::IN_EXPRESSION, &parser, || ExpressionParser::parse(&parser))?;
+                    parser.cleanup_whitespaces();
+
+                    if expression == KaramelAstType::None || parser.peek_token().is_ok() {
+                        return Err(KaramelErrorType::TextInterpolationNotValid);
+                    }
+
+                    asts.push(Rc::new(KaramelAstType::TextFormat {
+                        expression: Rc::new(expression),
+                        format: format.clone()
+                    }));
+                }
+            };
+        }
+
+        Ok(KaramelAstType::TextTemplate(asts))
+    }
+
     pub fn parse_list(parser: &SyntaxParser) -> AstResult {
         let index_backup = parser.get_index();
         if parser.match_operator(&[KaramelOperatorType::SquareBracketStart]).is_some() {
@@ -69,6 +99,17 @@ impl PrimativeParser {
                 ast_vec.push(Rc::new(ast.unwrap()));
 
                 parser.cleanup_whitespaces();
+
+                /* '[x * 2 her x liste]' */
+                if ast_vec.len() == 1 {
+                    let comprehension = ComprehensionParser::parse(parser, None, ast_vec[0].clone())?;
+                    if KaramelAstType::None != comprehension {
+                        if parser.match_operator(&[KaramelOperatorType::SquareBracketEnd]).is_none() {
+                            return Err(KaramelErrorType::ArrayNotClosed);
+                        }
+                        return Ok(comprehension);
+                    }
+                }
                 if parser.match_operator(&[KaramelOperatorType::Comma]).is_none()  {
                     break;
                 }
@@ -85,12 +126,97 @@ impl PrimativeParser {
         return Ok(KaramelAstType::None);
     }
 
+    /* '{anahtar: değer her anahtar, değer sözlük}', opening bracket already consumed */
+    fn parse_dict_comprehension(parser: &SyntaxParser) -> AstResult {
+        let index_backup = parser.get_index();
+
+        let key = ExpressionParser::parse(parser)?;
+        parser.cleanup();
+
+        if KaramelAstType::None == key || parser.match_operator(&[KaramelOperatorType::ColonMark]).is_none() {
+            parser.set_index(index_backup);
+            return Ok(KaramelAstType::None);
+        }
+
+        parser.cleanup();
+        let value = ExpressionParser::parse(parser)?;
+        parser.cleanup();
+
+        let comprehension = match value {
+            KaramelAstType::None => KaramelAstType::None,
+            value => ComprehensionParser::parse(parser, Some(Rc::new(key)), Rc::new(value))?
+        };
+
+        if KaramelAstType::None == comprehension {
+            parser.set_index(index_backup);
+            return Ok(KaramelAstType::None);
+        }
+
+        if parser.match_operator(&[KaramelOperatorType::CurveBracketEnd]).is_none() {
+            return Err(KaramelErrorType::DictNotClosed);
+        }
+        Ok(comprehension)
+    }
+
+    /* '{1, 2, 3}', opening bracket already consumed. First item without colon means set */
+    fn parse_set(parser: &SyntaxParser) -> AstResult {
+        let index_backup = parser.get_index();
+        let mut items = Vec::new();
+
+        loop {
+            parser.cleanup();
+            if parser.check_operator(&KaramelOperatorType::CurveBracketEnd) {
+                break;
+            }
+
+            let item = ExpressionParser::parse(parser);
+            if is_ast_empty(&item) {
+                if items.is_empty() {
+                    parser.set_index(index_backup);
+                    return Ok(KaramelAstType::None);
+                }
+                return err_or_message(item, KaramelErrorType::InvalidListItem);
+            }
+
+            parser.cleanup();
+            if items.is_empty() && parser.check_operator(&KaramelOperatorType::ColonMark) {
+                parser.set_index(index_backup);
+                return Ok(KaramelAstType::None);
+            }
+
+            items.push(Rc::new(item.unwrap()));
+            if parser.match_operator(&[KaramelOperatorType::Comma]).is_none() {
+                break;
+            }
+        }
+
+        if items.is_empty() {
+            parser.set_index(index_backup);
+            return Ok(KaramelAstType::None);
+        }
+
+        if parser.match_operator(&[KaramelOperatorType::CurveBracketEnd]).is_none() {
+            return Err(KaramelErrorType::SetNotClosed);
+        }
+        Ok(KaramelAstType::Set(items))
+    }
+
     pub fn parse_dict(parser: &SyntaxParser) -> AstResult {
         let index_backup = parser.get_index();
         if parser.match_operator(&[KaramelOperatorType::CurveBracketStart]).is_some() {
             let mut dict_items   = Vec::new();
             parser.cleanup();
 
+            let comprehension = Self::parse_dict_comprehension(parser)?;
+            if KaramelAstType::None != comprehension {
+                return Ok(comprehension);
+            }
+
+            let set = Self::parse_set(parser)?;
+            if KaramelAstType::None != set {
+                return Ok(set);
+            }
+
             loop {
                 if parser.check_operator(&KaramelOperatorType::CurveBracketEnd) {
                     break;
@@ -107,7 +233,7 @@ impl PrimativeParser {
                 let key = match key_ast {
                     Ok(KaramelAstType::Primative(primative)) => {
                         match &*primative {
-                            KaramelPrimative::Text(_) => primative.clone(),
+                            KaramelPrimative::Text(_) | KaramelPrimative::Number(_) | KaramelPrimative::Float(_) | KaramelPrimative::Bool(_) => primative.clone(),
                             _ =>  {
                                 return Err(KaramelErrorType::DictionaryKeyNotValid);
                             }
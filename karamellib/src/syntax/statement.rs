@@ -3,15 +3,17 @@ use crate::syntax::{SyntaxParser, SyntaxParserTrait};
 use crate::syntax::util::map_parser;
 use crate::syntax::if_condition::IfConditiontParser;
 use crate::syntax::assignment::AssignmentParser;
-use crate::syntax::load_module::LoadModuleParser;
-use crate::syntax::function_return::FunctionReturnParser;
+use crate::syntax::load_module::{LoadModuleParser, UseModuleParser};
+use crate::syntax::function_return::{FunctionReturnParser, YieldParser};
 use crate::syntax::loop_item::LoopItemParser;
 use crate::syntax::loops::WhileLoopParser;
+use crate::syntax::try_catch::{TryParser, ThrowParser};
+use crate::syntax::pattern_match::MatchParser;
 
 pub struct StatementParser;
 
 impl SyntaxParserTrait for StatementParser {
     fn parse(parser: &SyntaxParser) -> AstResult {
-        return map_parser(parser, &[LoadModuleParser::parse, LoopItemParser::parse, WhileLoopParser::parse, FunctionReturnParser::parse, AssignmentParser::parse, IfConditiontParser::parse]);
+        return map_parser(parser, &[UseModuleParser::parse, LoadModuleParser::parse, LoopItemParser::parse, WhileLoopParser::parse, TryParser::parse, MatchParser::parse, ThrowParser::parse, FunctionReturnParser::parse, YieldParser::parse, AssignmentParser::parse, IfConditiontParser::parse]);
     }
 }
@@ -0,0 +1,191 @@
+use std::rc::Rc;
+
+use crate::types::*;
+use crate::syntax::{SyntaxParser, SyntaxParserTrait};
+use crate::syntax::primative::PrimativeParser;
+use crate::syntax::expression::ExpressionParser;
+use crate::syntax::block::{SingleLineBlockParser, MultiLineBlockParser};
+use crate::compiler::ast::KaramelAstType;
+use crate::error::KaramelErrorType;
+
+pub struct TryParser;
+pub struct ThrowParser;
+
+impl TryParser {
+    /// Hidden variable that keeps catched error when variable name is not given.
+    pub fn get_error_variable(id: usize) -> String {
+        format!("#hata{}", id)
+    }
+
+    fn parse_body(parser: &SyntaxParser, indentation: usize) -> AstResult {
+        parser.cleanup_whitespaces();
+        if let None = parser.match_operator(&[KaramelOperatorType::ColonMark]) {
+            return Err(KaramelErrorType::ColonMarkMissing);
+        }
+
+        parser.cleanup_whitespaces();
+        let body = match parser.get_newline() {
+            (true, _) => {
+                parser.in_indication()?;
+                MultiLineBlockParser::parse(parser)
+            },
+            (false, _) => SingleLineBlockParser::parse(parser)
+        }?;
+        parser.set_indentation(indentation);
+
+        if body == KaramelAstType::None {
+            return Err(KaramelErrorType::TryStatementNotValid);
+        }
+
+        Ok(body)
+    }
+
+    /* Next block should be at the same indentation with 'dene' */
+    fn match_block_keyword(parser: &SyntaxParser, indentation: usize, keyword: KaramelKeywordType) -> bool {
+        let index_backup = parser.get_index();
+        if parser.is_same_indentation(indentation) && parser.match_keyword(keyword) {
+            return true;
+        }
+
+        parser.set_index(index_backup);
+        parser.set_indentation(indentation);
+        false
+    }
+}
+
+impl SyntaxParserTrait for TryParser {
+    /*
+    Example:
+        dene:
+            hataayıklama::doğrula(1, 2)
+        yakala hata:
+            gç::satıryaz(hata.mesaj)
+        sonunda:
+            gç::satıryaz('bitti')
+    */
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        let index_backup = parser.get_index();
+        parser.indentation_check()?;
+
+        let indentation = parser.get_indentation();
+        let id = parser.get_index();
+        if !parser.match_keyword(KaramelKeywordType::Try) {
+            parser.set_index(index_backup);
+            parser.set_indentation(indentation);
+            return Ok(KaramelAstType::None);
+        }
+
+        let body = TryParser::parse_body(parser, indentation)?;
+
+        let mut catch_variable = None;
+        let mut catch_body = None;
+        if TryParser::match_block_keyword(parser, indentation, KaramelKeywordType::Catch) {
+            parser.cleanup_whitespaces();
+            if let KaramelAstType::Symbol(variable) = PrimativeParser::parse_symbol(parser)? {
+                catch_variable = Some(variable);
+            }
+
+            catch_body = Some(Rc::new(TryParser::parse_body(parser, indentation)?));
+        }
+
+        let mut finally_body = None;
+        if TryParser::match_block_keyword(parser, indentation, KaramelKeywordType::Finally) {
+            finally_body = Some(Rc::new(TryParser::parse_body(parser, indentation)?));
+        }
+
+        if catch_body.is_none() && finally_body.is_none() {
+            return Err(KaramelErrorType::TryStatementNotValid);
+        }
+
+        Ok(KaramelAstType::Try {
+            id,
+            body: Rc::new(body),
+            catch_variable,
+            catch_body,
+            finally_body
+        })
+    }
+}
+
+impl SyntaxParserTrait for ThrowParser {
+    fn parse(parser: &SyntaxParser) -> AstResult {
+        let index_backup = parser.get_index();
+        parser.cleanup_whitespaces();
+
+        if parser.match_keyword(KaramelKeywordType::Throw) {
+            parser.cleanup_whitespaces();
+
+            let ast = ExpressionParser::parse(parser)?;
+            if ast == KaramelAstType::None {
+                return Err(KaramelErrorType::ThrowValueNotFound);
+            }
+
+            return Ok(KaramelAstType::Throw(Rc::new(ast)));
+        }
+
+        parser.set_index(index_backup);
+        return Ok(KaramelAstType::None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{KaramelError, KaramelErrorType};
+
+    use crate::parser::*;
+    use crate::syntax::*;
+    use crate::compiler::ast::KaramelAstType;
+    use crate::compiler::value::KaramelPrimative;
+    use std::rc::Rc;
+
+    #[warn(unused_macros)]
+    macro_rules! test_compare {
+        ($name:ident, $text:expr, $result:expr) => {
+            #[test]
+            fn $name () {
+                let mut parser = Parser::new($text);
+                match parser.parse() {
+                    Err(_) => assert_eq!(true, false),
+                    _ => ()
+                };
+
+                let syntax = SyntaxParser::new(parser.tokens().to_vec());
+                assert_eq!(syntax.parse(), $result);
+            }
+        };
+    }
+
+    fn assignment(value: f64) -> Rc<KaramelAstType> {
+        Rc::new(KaramelAstType::Assignment {
+            variable: Rc::new(KaramelAstType::Symbol("a".to_string())),
+            operator: KaramelOperatorType::Assign,
+            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(value))))
+        })
+    }
+
+    test_compare!(try_1, "dene:\n    a = 1\nyakala hata:\n    a = 2", Ok(Rc::new(KaramelAstType::Try {
+        id: 0,
+        body: assignment(1.0),
+        catch_variable: Some("hata".to_string()),
+        catch_body: Some(assignment(2.0)),
+        finally_body: None
+    })));
+    test_compare!(try_2, "dene:\n    a = 1\nsonunda:\n    a = 2", Ok(Rc::new(KaramelAstType::Try {
+        id: 0,
+        body: assignment(1.0),
+        catch_variable: None,
+        catch_body: None,
+        finally_body: Some(assignment(2.0))
+    })));
+    test_compare!(try_3, "dene: a = 1\nyakala: a = 2\nsonunda: a = 3", Ok(Rc::new(KaramelAstType::Try {
+        id: 0,
+        body: assignment(1.0),
+        catch_variable: None,
+        catch_body: Some(assignment(2.0)),
+        finally_body: Some(assignment(3.0))
+    })));
+    test_compare!(try_4, "dene:\n    a = 1\na = 2", Err(KaramelError::new(2, 1, KaramelErrorType::TryStatementNotValid)));
+    test_compare!(try_5, "dene:\nyakala:\n    a = 2", Err(KaramelError::new(0, 5, KaramelErrorType::IndentationIssue)));
+    test_compare!(throw_1, "fırlat 'hata'", Ok(Rc::new(KaramelAstType::Throw(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("hata".to_string())))))))));
+    test_compare!(throw_2, "fırlat", Err(KaramelError::new(0, 6, KaramelErrorType::ThrowValueNotFound)));
+}
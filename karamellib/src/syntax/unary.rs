@@ -1,11 +1,11 @@
 use crate::types::*;
 use crate::syntax::{SyntaxParser, SyntaxParserTrait};
-use crate::syntax::util::map_parser;
+use crate::syntax::util::{map_parser, with_flag};
 use crate::syntax::primative::PrimativeParser;
 use crate::syntax::func_call::FuncCallParser;
-use crate::syntax::util::is_ast_empty;
 use crate::compiler::ast::KaramelAstType;
 use crate::compiler::value::KaramelPrimative;
+use crate::compiler::bigint::BigInt;
 use crate::syntax::expression::ExpressionParser;
 use crate::error::KaramelErrorType;
 use crate::syntax::SyntaxFlag;
@@ -18,18 +18,15 @@ pub struct UnaryParser;
 impl SyntaxParserTrait for UnaryParser {
     fn parse(parser: &SyntaxParser) -> AstResult {
         let ast = map_parser(parser, &[Self::parse_prefix_unary, Self::parse_suffix_unary, FuncCallParser::parse, PrimativeParser::parse])?;
+        let ast = Self::parse_field_access(ast, parser)?;
         
         let index_backup = parser.get_index();
         parser.cleanup_whitespaces();
         
         if parser.match_operator(&[KaramelOperatorType::SquareBracketStart]).is_some() {
-            parser.cleanup_whitespaces();
-
-            let indexer_ast = ExpressionParser::parse(parser)?;
-            parser.cleanup_whitespaces();
-
-            if parser.match_operator(&[KaramelOperatorType::SquareBracketEnd]).is_some() {
-                return Ok(KaramelAstType::Indexer { body: Rc::new(ast), indexer: Rc::new(indexer_ast) });   
+            let indexer_ast = Self::parse_indexer_body(Rc::new(ast.clone()), parser)?;
+            if KaramelAstType::None != indexer_ast {
+                return Ok(indexer_ast);
             }
         }
 
@@ -61,16 +58,51 @@ impl UnaryParser {
         return Ok(KaramelAstType::None);
     }
 
-    pub fn parse_indexer(ast: Rc<KaramelAstType>, parser: &SyntaxParser) -> AstResult {
-        let index_backup = parser.get_index();
-        if parser.match_operator(&[KaramelOperatorType::SquareBracketStart]).is_some() {
-            parser.cleanup_whitespaces();
+    /* parse for 'object.field', method calls are handled by expression parser */
+    fn parse_field_access(mut ast: KaramelAstType, parser: &SyntaxParser) -> AstResult {
+        if let KaramelAstType::None = ast {
+            return Ok(ast);
+        }
+
+        loop {
+            let index_backup = parser.get_index();
+            if parser.match_operator(&[KaramelOperatorType::Dot]).is_none() {
+                break;
+            }
+
+            let field = match PrimativeParser::parse_symbol(parser)? {
+                KaramelAstType::Symbol(symbol) => symbol,
+                _ => {
+                    parser.set_index(index_backup);
+                    break;
+                }
+            };
 
-            let indexer_ast = ExpressionParser::parse(parser);
+            let field_backup = parser.get_index();
             parser.cleanup_whitespaces();
+            let is_method_call = parser.check_operator(&KaramelOperatorType::LeftParentheses);
+            parser.set_index(field_backup);
+
+            if is_method_call {
+                parser.set_index(index_backup);
+                break;
+            }
+
+            ast = KaramelAstType::Indexer {
+                body: Rc::new(ast),
+                indexer: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new(field)))))
+            };
+        }
 
-            if parser.match_operator(&[KaramelOperatorType::SquareBracketEnd]).is_some() && !is_ast_empty(&indexer_ast) {
-                return Ok(KaramelAstType::Indexer { body: ast, indexer: Rc::new(indexer_ast.unwrap()) });   
+        Ok(ast)
+    }
+
+    pub fn parse_indexer(ast: Rc<KaramelAstType>, parser: &SyntaxParser) -> AstResult {
+        let index_backup = parser.get_index();
+        if parser.match_operator(&[KaramelOperatorType::SquareBracketStart]).is_some() {
+            let indexer_ast = Self::parse_indexer_body(ast, parser)?;
+            if KaramelAstType::None != indexer_ast {
+                return Ok(indexer_ast);
             }
         }
 
@@ -78,6 +110,42 @@ impl UnaryParser {
         return Ok(KaramelAstType::None);
     }
 
+    /* Rest of the 'kaynak[sıra]' or 'kaynak[baş:son:adım]', all parts of the slice are optional */
+    fn parse_indexer_body(body: Rc<KaramelAstType>, parser: &SyntaxParser) -> AstResult {
+        let start = Self::parse_slice_part(parser)?;
+
+        if parser.match_operator(&[KaramelOperatorType::ColonMark]).is_none() {
+            return match (parser.match_operator(&[KaramelOperatorType::SquareBracketEnd]), start) {
+                (Some(_), Some(indexer)) => Ok(KaramelAstType::Indexer { body, indexer }),
+                _ => Ok(KaramelAstType::None)
+            };
+        }
+
+        let end = Self::parse_slice_part(parser)?;
+        let step = match parser.match_operator(&[KaramelOperatorType::ColonMark]) {
+            Some(_) => Self::parse_slice_part(parser)?,
+            None => None
+        };
+
+        match parser.match_operator(&[KaramelOperatorType::SquareBracketEnd]) {
+            Some(_) => Ok(KaramelAstType::Slice { body, start, end, step }),
+            None => Err(KaramelErrorType::SliceNotValid)
+        }
+    }
+
+    fn parse_slice_part(parser: &SyntaxParser) -> Result<Option<Rc<KaramelAstType>>, KaramelErrorType> {
+        parser.cleanup_whitespaces();
+
+        /* Function call inside of the indexer should return its value even if the indexer is an assignment target */
+        let part = with_flag(SyntaxFlag::IN_EXPRESSION, parser, || ExpressionParser::parse(parser))?;
+        parser.cleanup_whitespaces();
+
+        Ok(match part {
+            KaramelAstType::None => None,
+            part => Some(Rc::new(part))
+        })
+    }
+
     fn parse_prefix_unary(parser: &SyntaxParser) -> AstResult {
         let index_backup = parser.get_index();
 
@@ -85,7 +153,8 @@ impl UnaryParser {
             KaramelOperatorType::Subtraction,
             KaramelOperatorType::Increment,
             KaramelOperatorType::Deccrement,
-            KaramelOperatorType::Not]) {
+            KaramelOperatorType::Not,
+            KaramelOperatorType::BitwiseNot]) {
             parser.cleanup_whitespaces();
 
             let mut unary_ast = KaramelAstType::None;
@@ -102,8 +171,9 @@ impl UnaryParser {
 
                     parser.consume_token();
                     match token.token_type {
-                        KaramelTokenType::Integer(integer) => return Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(integer as f64 * opt)))),
-                        KaramelTokenType::Double(double) => return Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(double * opt)))),
+                        KaramelTokenType::Integer(integer) => return Ok(KaramelAstType::Primative(Rc::new(BigInt::from_i64(integer * opt as i64).to_primative()))),
+                        KaramelTokenType::BigInteger(ref integer) => return Ok(KaramelAstType::Primative(Rc::new(BigInt::from_i64(opt as i64).mul(integer).to_primative()))),
+                        KaramelTokenType::Double(double) => return Ok(KaramelAstType::Primative(Rc::new(KaramelPrimative::from_float(double * opt)))),
                         _ => {
                             parser.set_index(index_backup);
                             return Err(KaramelErrorType::UnaryWorksWithNumber);
@@ -119,7 +189,7 @@ impl UnaryParser {
                     }
                 },
 
-                KaramelOperatorType::Not => {
+                KaramelOperatorType::Not | KaramelOperatorType::BitwiseNot => {
                     let expression = UnaryParser::parse(parser);
                     unary_ast = match expression {
                         Ok(KaramelAstType::None) => {
@@ -70,6 +70,19 @@ pub fn update_functions_for_temp_return(ast: &KaramelAstType) {
     };
 }
 
+/* Type name after ':' or '->', 'yok' is used for empty value */
+pub fn parse_type_name(parser: &SyntaxParser) -> Option<String> {
+    parser.cleanup_whitespaces();
+    let type_name = match &parser.peek_token().ok()?.token_type {
+        KaramelTokenType::Symbol(name) => name.to_string(),
+        KaramelTokenType::Keyword(KaramelKeywordType::Empty) => "yok".to_string(),
+        _ => return None
+    };
+
+    parser.consume_token();
+    Some(type_name)
+}
+
 pub fn with_flag<F: Fn() -> AstResult>(flag: SyntaxFlag, parser: &SyntaxParser, func: F) -> AstResult {
     let parser_flags  = parser.flags.get();
     parser.flags.set(parser_flags | flag);
@@ -7,6 +7,7 @@ use std::result::Result;
 use std::hash::Hash;
 use std::rc::Rc;
 use crate::{compiler::ast::KaramelAstType, error::KaramelError};
+use crate::compiler::bigint::BigInt;
 use crate::error::KaramelErrorType;
 
 pub type ParseResult        = Result<(), KaramelError>;
@@ -44,7 +45,9 @@ pub enum KaramelKeywordType {
     Or,
     Empty,
     Modulo,
+    IntegerDivision,
     Not,
+    In,
     GreaterThan,
     LessThan,
     GreaterEqualThan,
@@ -57,7 +60,16 @@ pub enum KaramelKeywordType {
     Break,
     Continue,
     While,
-    Load
+    Load,
+    Class,
+    Each,
+    Try,
+    Catch,
+    Finally,
+    Throw,
+    When,
+    Match,
+    Yield
 }
 
 impl KaramelKeywordType {
@@ -66,7 +78,9 @@ impl KaramelKeywordType {
             KaramelKeywordType::And              => KaramelOperatorType::And,
             KaramelKeywordType::Or               => KaramelOperatorType::Or,
             KaramelKeywordType::Modulo           => KaramelOperatorType::Modulo,
+            KaramelKeywordType::IntegerDivision  => KaramelOperatorType::IntegerDivision,
             KaramelKeywordType::Not              => KaramelOperatorType::Not,
+            KaramelKeywordType::In               => KaramelOperatorType::In,
             KaramelKeywordType::Equal            => KaramelOperatorType::Equal,
             KaramelKeywordType::NotEqual         => KaramelOperatorType::NotEqual,
             KaramelKeywordType::GreaterThan      => KaramelOperatorType::GreaterThan,
@@ -92,6 +106,8 @@ pub static KEYWORDS: &[(&str, KaramelKeywordType)] = &[
     ("veya",   KaramelKeywordType::Or),
     ("yok",    KaramelKeywordType::Empty),
     ("mod",    KaramelKeywordType::Modulo),
+    ("tambölüm",      KaramelKeywordType::IntegerDivision),
+    ("tambolum",      KaramelKeywordType::IntegerDivision),
     ("eşittir",       KaramelKeywordType::Equal),
     ("esittir",       KaramelKeywordType::Equal),
     ("eşitdeğildir",  KaramelKeywordType::NotEqual),
@@ -106,6 +122,8 @@ pub static KEYWORDS: &[(&str, KaramelKeywordType)] = &[
     ("kucukesittir",  KaramelKeywordType::LessEqualThan),
     ("değil",         KaramelKeywordType::Not),
     ("degil",         KaramelKeywordType::Not),
+    ("içinde",        KaramelKeywordType::In),
+    ("icinde",        KaramelKeywordType::In),
     ("fonk",            KaramelKeywordType::Fn),
     ("döndür",        KaramelKeywordType::Return),
     ("dondur",        KaramelKeywordType::Return),
@@ -115,7 +133,21 @@ pub static KEYWORDS: &[(&str, KaramelKeywordType)] = &[
     ("döngü",         KaramelKeywordType::While),
     ("dongu",         KaramelKeywordType::While),
     ("yükle",          KaramelKeywordType::Load),
-    ("yukle",          KaramelKeywordType::Load)
+    ("yukle",          KaramelKeywordType::Load),
+    ("sınıf",         KaramelKeywordType::Class),
+    ("sinif",         KaramelKeywordType::Class),
+    ("her",           KaramelKeywordType::Each),
+    ("dene",          KaramelKeywordType::Try),
+    ("yakala",        KaramelKeywordType::Catch),
+    ("sonunda",       KaramelKeywordType::Finally),
+    ("fırlat",        KaramelKeywordType::Throw),
+    ("firlat",        KaramelKeywordType::Throw),
+    ("eğer",          KaramelKeywordType::When),
+    ("eger",          KaramelKeywordType::When),
+    ("eşleştir",      KaramelKeywordType::Match),
+    ("eslestir",      KaramelKeywordType::Match),
+    ("üret",          KaramelKeywordType::Yield),
+    ("uret",          KaramelKeywordType::Yield)
 ];
 
 #[derive(Clone, Copy)]
@@ -128,6 +160,14 @@ pub enum KaramelOperatorType {
     Multiplication,
     Division,
     Modulo,
+    Power,
+    IntegerDivision,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    BitwiseNot,
+    LeftShift,
+    RightShift,
     Increment,
     Deccrement,
     Assign,
@@ -135,9 +175,18 @@ pub enum KaramelOperatorType {
     AssignSubtraction,
     AssignMultiplication,
     AssignDivision,
+    AssignPower,
+    AssignIntegerDivision,
+    AssignBitwiseAnd,
+    AssignBitwiseOr,
+    AssignBitwiseXor,
+    AssignLeftShift,
+    AssignRightShift,
     Equal,
     NotEqual,
     Not,
+    In,
+    NotIn,
     And,
     Or,
     GreaterThan,
@@ -146,6 +195,7 @@ pub enum KaramelOperatorType {
     LessEqualThan,
     QuestionMark,
     ColonMark,
+    Arrow,
     LeftParentheses,
     RightParentheses,
     SquareBracketStart,
@@ -172,10 +222,12 @@ pub enum KaramelOperatorType {
 #[derive(PartialEq)]
 pub enum KaramelTokenType {
     Integer(i64),
+    BigInteger(Rc<BigInt>),
     Double(f64),
     Symbol(Rc<String>),
     Operator(KaramelOperatorType),
     Text(Rc<String>),
+    TextTemplate(Rc<Vec<KaramelTextPart>>),
     Keyword(KaramelKeywordType),
     WhiteSpace(u8),
     NewLine(u8)
@@ -192,7 +244,19 @@ pub enum KaramelNumberSystem {
     Hexadecimal = 3
 }
 
-#[derive(Debug, Clone)]
+/* Interpolated text parts. Expressions are tokenized with their optional format */
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum KaramelTextPart {
+    Text(Rc<String>),
+    Expression {
+        tokens: Vec<Token>,
+        format: Rc<String>
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Token {
     pub line      : u32,
     pub start    : u32,
@@ -6,6 +6,7 @@ use crate::file::read_module_or_script;
 use crate::{types::Token, vm::interpreter::run_vm};
 use crate::parser::*;
 use crate::compiler::*;
+use crate::compiler::type_checker::TypeChecker;
 use crate::syntax::SyntaxParser;
 use crate::logger::{CONSOLE_LOGGER};
 use crate::error::generate_error_message;
@@ -91,7 +92,7 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
         _ => ()
     };
 
-    let syntax = SyntaxParser::new(parser.tokens().to_vec());
+    let syntax = SyntaxParser::with_locations(parser.tokens().to_vec());
     let ast = match syntax.parse() {
         Ok(ast) => ast,
         Err(error) => {
@@ -100,6 +101,11 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
         }
     };
 
+    if let Err(error) = TypeChecker::check(&ast, &context) {
+        log::error!("{}", generate_error_message(&data, &error));
+        return status;
+    }
+
     let opcode_compiler = InterpreterCompiler {};
     if parameters.return_output {
         context.stdout = Some(RefCell::new(String::new()));
@@ -107,7 +113,12 @@ pub fn code_executer(parameters: ExecutionParameters) -> ExecutionStatus {
     }
 
     let execution_status = match opcode_compiler.compile(ast.clone(), &mut context) {
-        Ok(_) => unsafe { run_vm(&mut context) },
+        Ok(_) => {
+            for warning in context.warnings.iter() {
+                log::warn!("{}", generate_error_message(&data, warning));
+            }
+            unsafe { run_vm(&mut context) }
+        },
         Err(message) => {
             log::error!("Program hata ile sonlandırıldı: {}", message);
             return status;
@@ -1,18 +1,26 @@
 use crate::compiler::context::KaramelCompilerContext;
-use crate::compiler::scope::Scope;
+use crate::compiler::scope::{Scope, ErrorHandler};
 use crate::error::KaramelErrorType;
 use crate::{pop, inc_memory_index, dec_memory_index, get_memory_index, karamel_dbg};
 use crate::types::{VmObject};
 use crate::compiler::*;
 use std::rc::Rc;
 use std::mem;
-use std::collections::HashMap;
 use std::io::stdout;
 use log_update::LogUpdate;
 use std::io::{self, Write};
 use std::ptr;
 use colored::*;
-use crate::buildin::ClassProperty;
+use crate::buildin::{Class, ClassProperty};
+use crate::buildin::class::error::ErrorClass;
+use crate::buildin::format::TextFormat;
+use crate::compiler::function::{FunctionReference, NativeCallResult, resume_generator};
+use crate::vm::scheduler::{finish_task, fail_task};
+use crate::compiler::hash::{HashKey, KaramelDict, KaramelSet};
+use crate::compiler::bigint::{self, BigInt, is_exact_result, is_safe_integer, number_result};
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use crate::compiler::class::CONSTRUCTOR_NAME;
 
 #[cfg(all(feature = "dumpOpcodes"))]
 pub unsafe fn dump_opcode<W: Write>(index: usize, context: &mut KaramelCompilerContext, log_update: &mut LogUpdate<W>) {
@@ -48,22 +56,36 @@ pub unsafe fn dump_opcode<W: Write>(index: usize, context: &mut KaramelCompilerC
             VmOpCode::Decrement | 
             VmOpCode::Addition | 
             VmOpCode::Module |
-            VmOpCode::And | 
-            VmOpCode::Or |
+            VmOpCode::Power |
+            VmOpCode::IntegerDivision |
+            VmOpCode::BitwiseAnd |
+            VmOpCode::BitwiseOr |
+            VmOpCode::BitwiseXor |
+            VmOpCode::BitwiseNot |
+            VmOpCode::LeftShift |
+            VmOpCode::RightShift |
+            VmOpCode::In |
             VmOpCode::Subraction | 
             VmOpCode::GreaterEqualThan |
             VmOpCode::GreaterThan | 
             VmOpCode::LessEqualThan | 
             VmOpCode::LessThan | 
             VmOpCode::GetItem | 
+            VmOpCode::GetSlice |
+            VmOpCode::Append |
+            VmOpCode::Yield |
             VmOpCode::SetItem |
+            VmOpCode::EndTry |
+            VmOpCode::Throw |
             VmOpCode::Multiply => {
                 let data = format!("║ {:4} ║ {:15} ║ {:^5} ║ {:^5} ║", opcode_index, format!("{:?}", opcode), "", "").to_string();
                 build_arrow(index, opcode_index, 0, &mut buffer, &data);
             },
 
             
-            VmOpCode::Compare => {
+            VmOpCode::Compare |
+            VmOpCode::JumpIfFalse |
+            VmOpCode::JumpIfTrue => {
                 let location = opcode_index + ((context.opcodes[opcode_index+2] as u16 * 256) + context.opcodes[opcode_index+1] as u16) as usize;
                 let data = format!("║ {:4} ║ {:15} ║ {:^5?} ║ {:^5} ║", opcode_index, format!("{:?}", opcode), location, "");
                 build_arrow(index, opcode_index, 0, &mut buffer, &data);
@@ -77,6 +99,27 @@ pub unsafe fn dump_opcode<W: Write>(index: usize, context: &mut KaramelCompilerC
                 opcode_index += 2;
             },
 
+            VmOpCode::Try => {
+                let location = ((context.opcodes[opcode_index+2] as u16 * 256) + context.opcodes[opcode_index+1] as u16) as usize;
+                let data = format!("║ {:4} ║ {:15} ║ {:^5?} ║ {:^5} ║", opcode_index, format!("{:?}", opcode), location, "");
+                build_arrow(index, opcode_index, 0, &mut buffer, &data);
+                opcode_index += 2;
+            },
+
+            VmOpCode::InitDefault => {
+                let location = ((context.opcodes[opcode_index+3] as u16 * 256) + context.opcodes[opcode_index+2] as u16) as usize;
+                let data = format!("║ {:4} ║ {:15} ║ {:^5?} ║ {:^5} ║", opcode_index, format!("{:?}", opcode), context.opcodes[opcode_index + 1], location);
+                build_arrow(index, opcode_index, 3, &mut buffer, &data);
+                opcode_index += 3;
+            },
+
+            VmOpCode::KeywordArguments => {
+                let size = context.opcodes[opcode_index + 1] as usize;
+                let data = format!("║ {:4} ║ {:15} ║ {:^5?} ║ {:^5} ║", opcode_index, format!("{:?}", opcode), size, "");
+                build_arrow(index, opcode_index, 1 + size, &mut buffer, &data);
+                opcode_index += 1 + size;
+            },
+
             VmOpCode::Func => {
                 let data = format!("║ {:4} ║ {:15} ║ {:^5?} ║ {:^5} ║", opcode_index, format!("{:?}", opcode), opcode_index + 1, "");
                 build_arrow(index, opcode_index, 1, &mut buffer, &data);
@@ -85,9 +128,15 @@ pub unsafe fn dump_opcode<W: Write>(index: usize, context: &mut KaramelCompilerC
 
             VmOpCode::InitArguments |
             VmOpCode::CopyToStore |
+            VmOpCode::LoadCell |
+            VmOpCode::StoreCell |
             VmOpCode::Load |
             VmOpCode::InitList |
+            VmOpCode::InitTuple |
             VmOpCode::InitDict |
+            VmOpCode::InitSet |
+            VmOpCode::Unpack |
+            VmOpCode::Format |
             VmOpCode::Store => {
                 let data = format!("║ {:4} ║ {:15} ║ {:^5?} ║ {:^5} ║", opcode_index, format!("{:?}", opcode), context.opcodes[opcode_index + 1], "");
                 build_arrow(index, opcode_index, 1, &mut buffer, &data);
@@ -119,6 +168,27 @@ pub unsafe fn dump_opcode<W: Write>(index: usize, context: &mut KaramelCompilerC
                 let data = format!("║ {:4} ║ {:15} ║ {:^5?} ║ {:^5} ║", opcode_index, format!("{:?}", opcode), context.opcodes[opcode_index + 1], context.opcodes[opcode_index + 2]);
                 build_arrow(index, opcode_index, 2, &mut buffer, &data);
                 opcode_index += 2;
+            },
+
+            VmOpCode::Iterate => {
+                let location = opcode_index + 3 + ((context.opcodes[opcode_index+5] as u16 * 256) + context.opcodes[opcode_index+4] as u16) as usize;
+                let data = format!("║ {:4} ║ {:15} ║ {:^5?} ║ {:^5} ║", opcode_index, format!("{:?}", opcode), location, context.opcodes[opcode_index + 3]);
+                build_arrow(index, opcode_index, 5, &mut buffer, &data);
+                opcode_index += 5;
+            },
+
+            VmOpCode::RangeIterate => {
+                let location = opcode_index + 4 + ((context.opcodes[opcode_index+6] as u16 * 256) + context.opcodes[opcode_index+5] as u16) as usize;
+                let data = format!("║ {:4} ║ {:15} ║ {:^5?} ║ {:^5} ║", opcode_index, format!("{:?}", opcode), location, context.opcodes[opcode_index + 1]);
+                build_arrow(index, opcode_index, 6, &mut buffer, &data);
+                opcode_index += 6;
+            },
+
+            VmOpCode::Closure => {
+                let cell_size = context.opcodes[opcode_index + 2] as usize;
+                let data = format!("║ {:4} ║ {:15} ║ {:^5?} ║ {:^5} ║", opcode_index, format!("{:?}", opcode), context.opcodes[opcode_index + 1], cell_size);
+                build_arrow(index, opcode_index, 2 + cell_size, &mut buffer, &data);
+                opcode_index += 2 + cell_size;
             }
         }
 
@@ -134,413 +204,1010 @@ pub unsafe fn dump_opcode<W: Write>(index: usize, context: &mut KaramelCompilerC
     }
 }
 
-pub unsafe fn run_vm(context: &mut KaramelCompilerContext) -> Result<Vec<VmObject>, KaramelErrorType>
-{
-    #[cfg(any(feature = "liveOpcodeView", feature = "dumpOpcodes"))]
-    let mut log_update = LogUpdate::new(stdout()).unwrap();
-    
-    #[cfg(feature = "dumpMemory")] {
-        context.storages[0].dump();
+/* Operand is not number or text, ask to class for operator implementation */
+fn class_operator(left: VmObject, right: VmObject, operator: fn(&dyn Class, VmObject, VmObject) -> Option<NativeCallResult>) -> NativeCallResult {
+    for operand in [left, right].iter() {
+        if let KaramelPrimative::Class(class) = &*operand.deref() {
+            if let Some(result) = operator(&**class, left, right) {
+                return result;
+            }
+        }
     }
-    
-    #[cfg(all(feature = "dumpOpcodes"))] {
-        dump_opcode(0, context, &mut log_update);
-        return Ok(Vec::new());
+
+    Ok(EMPTY_OBJECT)
+}
+
+/* Bit operators work only with integers, floats and other values are not valid */
+fn integer_operator(left: VmObject, right: VmObject, exact: fn(&BigInt, &BigInt) -> Option<BigInt>) -> NativeCallResult {
+    for operand in [left, right].iter() {
+        if !operand.is_integer() {
+            return Err(KaramelErrorType::BitwiseWorksWithInteger(operand.deref()));
+        }
     }
-    {
-        let mut stack = context.storages[0].get_stack();
-        let stack_ptr = stack.as_mut_ptr();
 
-        let mut memory = context.storages[0].get_memory();
-        let memory_ptr = memory.as_mut_ptr();
+    Ok(bigint::calculate_integer(left, right, exact).unwrap_or(EMPTY_OBJECT))
+}
 
-        context.scopes[context.scope_index] = Scope {
-            memory: memory,
-            stack: stack,
-            location: ptr::null_mut(),
-            const_size: 0,
-            call_return_assign_to_temp: false,
-            stack_ptr: stack_ptr,
-            memory_ptr: memory_ptr,
-            storage_index: 0
-        };
+/* Exact integers are calculated with big integers when f64 result is not exact */
+fn number_operator(left: VmObject, right: VmObject, float: fn(f64, f64) -> f64, exact: fn(&BigInt, &BigInt) -> Option<BigInt>, operator: fn(&dyn Class, VmObject, VmObject) -> Option<NativeCallResult>) -> NativeCallResult {
+    match bigint::calculate(left, right, float, exact) {
+        Some(result) => Ok(result),
+        None => class_operator(left, right, operator)
+    }
+}
 
-        loop {
-            let opcode = mem::transmute::<u8, VmOpCode>(*context.opcodes_ptr);
-            #[cfg(all(feature = "liveOpcodeView"))] {
-                dump_opcode(context.opcode_index, context, &mut log_update);
+fn class_compare(left: VmObject, right: VmObject) -> Option<Ordering> {
+    if let Some(ordering) = bigint::compare(&left.deref(), &right.deref()) {
+        return Some(ordering);
+    }
+
+    for operand in [left, right].iter() {
+        if let KaramelPrimative::Class(class) = &*operand.deref() {
+            if let Some(result) = class.compare(left, right) {
+                return Some(result);
             }
-            
-            match karamel_dbg!(opcode) {
-                VmOpCode::Subraction => {
-                    let right = pop_raw!(context);
-                    let left  = pop_raw!(context);
+        }
+    }
 
-                    *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
-                        (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) - karamel_dbg!(r_value)),
-                        _ => EMPTY_OBJECT
-                    };
-                    inc_memory_index!(context, 1);
-                },
-
-                VmOpCode::Addition => {
-                    let right = pop_raw!(context);
-                    let left  = pop_raw!(context);
-                    *(*context.current_scope).stack_ptr = match (&left.deref_clean(), &right.deref_clean()) {
-                        (KaramelPrimative::Number(l_value),  KaramelPrimative::Number(r_value)) => VmObject::from(karamel_dbg!(l_value) + karamel_dbg!(r_value)),
-                        (KaramelPrimative::Text(l_value),    KaramelPrimative::Text(r_value))   => VmObject::from(Rc::new((&**l_value).to_owned() + &**r_value)),
-                        _ => EMPTY_OBJECT
-                    };
-                    inc_memory_index!(context, 1);
-                },
+    None
+}
 
-                VmOpCode::Load => {
-                    let tmp   = *context.opcodes_ptr.offset(1) as usize;
-                    let scope = &mut *context.current_scope;
-                    *scope.stack_ptr = karamel_dbg!(*scope.memory_ptr.offset(tmp as isize));
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                    inc_memory_index!(context, 1);
-                },
+/* Membership check for 'içinde' operator, value is searched inside of the source */
+fn contains(value: &KaramelPrimative, source: &Rc<KaramelPrimative>) -> Result<bool, KaramelErrorType> {
+    match &**source {
+        KaramelPrimative::List(items) => Ok(items.borrow().iter().any(|item| *item.deref() == *value)),
+        KaramelPrimative::Tuple(items) => Ok(items.iter().any(|item| *item.deref() == *value)),
+        KaramelPrimative::Dict(dict) => Ok(HashKey::from_primative(value).map_or(false, |key| dict.borrow().contains_key(&key))),
+        KaramelPrimative::Set(set) => Ok(HashKey::from_primative(value).map_or(false, |key| set.borrow().contains(&key))),
+        KaramelPrimative::Text(text) => match value {
+            KaramelPrimative::Text(search) => Ok(text.contains(search.as_str())),
+            _ => Ok(false)
+        },
+        KaramelPrimative::Class(class) => match class.contains(value) {
+            Some(result) => Ok(result),
+            None => match class.get_length() {
+                Some(length) => Ok((0..length).filter_map(|position| class.get_item(position)).any(|item| *item.deref() == *value)),
+                None => Err(KaramelErrorType::ContainsNotSupported(source.clone()))
+            }
+        },
+        _ => Err(KaramelErrorType::ContainsNotSupported(source.clone()))
+    }
+}
 
-                VmOpCode::Store => {
-                    let tmp = *context.opcodes_ptr.offset(1) as usize;
-                    dec_memory_index!(context, 1);
-                    *(*context.current_scope).memory_ptr.offset(tmp as isize) = karamel_dbg!(*(*context.current_scope).stack_ptr);
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                },
-
-                VmOpCode::CopyToStore => {
-                    let tmp = *context.opcodes_ptr.offset(1) as usize;
-                    *(*context.current_scope).memory_ptr.offset(tmp as isize) = karamel_dbg!(*(*context.current_scope).stack_ptr.sub(1));
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                },
-
-                VmOpCode::FastStore => {
-                    let destination = *context.opcodes_ptr.offset(1) as usize;
-                    let source      = *context.opcodes_ptr.offset(2) as usize;
-                    *(*context.current_scope).memory_ptr.offset(destination as isize) = karamel_dbg!(*(*context.current_scope).memory_ptr.offset(source as isize));
-                    context.opcodes_ptr = context.opcodes_ptr.offset(2);
-                },
+unsafe fn execute(context: &mut KaramelCompilerContext) -> Result<(), KaramelErrorType> {
+    #[cfg(all(feature = "liveOpcodeView"))]
+    let mut log_update = LogUpdate::new(stdout()).unwrap();
 
-                VmOpCode::Not => {
-                    *(*context.current_scope).stack_ptr.sub(1) = VmObject::from(!(*(*context.current_scope).stack_ptr.sub(1)).deref().is_true());
-                },
+    loop {
+        let opcode = mem::transmute::<u8, VmOpCode>(*context.opcodes_ptr);
+        #[cfg(all(feature = "liveOpcodeView"))] {
+            dump_opcode(context.opcode_index, context, &mut log_update);
+        }
+        
+        match karamel_dbg!(opcode) {
+            VmOpCode::Subraction => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
+
+                *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
+                    (Some(l_value),  Some(r_value)) if is_exact_result(l_value, r_value, l_value - r_value) => number_result(left, right, karamel_dbg!(l_value) - karamel_dbg!(r_value)),
+                    _ => number_operator(left, right, |left, right| left - right, |left, right| Some(left.sub(right)), |class, left, right| class.subtraction(left, right))?
+                };
+                inc_memory_index!(context, 1);
+            },
 
-                VmOpCode::Dublicate => {
-                    *(*context.current_scope).stack_ptr = karamel_dbg!(*(*context.current_scope).stack_ptr.sub(1));
-                    inc_memory_index!(context, 1);
-                },
+            VmOpCode::Addition => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
+                *(*context.current_scope).stack_ptr = match (&left.deref_clean(), &right.deref_clean()) {
+                    (KaramelPrimative::Number(l_value),  KaramelPrimative::Number(r_value)) if is_exact_result(*l_value, *r_value, l_value + r_value) => number_result(left, right, karamel_dbg!(l_value) + karamel_dbg!(r_value)),
+                    (KaramelPrimative::Text(l_value),    KaramelPrimative::Text(r_value))   => VmObject::from(Rc::new((&**l_value).to_owned() + &**r_value)),
+                    (KaramelPrimative::Text(l_value),    KaramelPrimative::Class(r_value))  => match r_value.addition(left, right) {
+                        Some(result) => result?,
+                        None => match r_value.to_text() {
+                            Some(r_text) => VmObject::from(Rc::new((&**l_value).to_owned() + &r_text)),
+                            None => EMPTY_OBJECT
+                        }
+                    },
+                    (KaramelPrimative::Class(l_value),   KaramelPrimative::Text(r_value))   => match l_value.addition(left, right) {
+                        Some(result) => result?,
+                        None => match l_value.to_text() {
+                            Some(l_text) => VmObject::from(Rc::new(l_text + &**r_value)),
+                            None => EMPTY_OBJECT
+                        }
+                    },
+                    _ => number_operator(left, right, |left, right| left + right, |left, right| Some(left.add(right)), |class, left, right| class.addition(left, right))?
+                };
+                inc_memory_index!(context, 1);
+            },
 
-                VmOpCode::And => {
-                    let left = pop!(context);
-                    let right = pop!(context);
+            VmOpCode::Load => {
+                let tmp   = *context.opcodes_ptr.offset(1) as usize;
+                let scope = &mut *context.current_scope;
+                *scope.stack_ptr = karamel_dbg!(*scope.memory_ptr.offset(tmp as isize));
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+                inc_memory_index!(context, 1);
+            },
 
-                    *(*context.current_scope).stack_ptr = VmObject::from(karamel_dbg!(left.is_true()) && karamel_dbg!(right.is_true()));
-                    inc_memory_index!(context, 1);
-                },
+            VmOpCode::Store => {
+                let tmp = *context.opcodes_ptr.offset(1) as usize;
+                dec_memory_index!(context, 1);
+                *(*context.current_scope).memory_ptr.offset(tmp as isize) = karamel_dbg!(*(*context.current_scope).stack_ptr);
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+            },
 
-                VmOpCode::Or => {
-                    let left = pop!(context);
-                    let right = pop!(context);
-                    *(*context.current_scope).stack_ptr = VmObject::from(karamel_dbg!(left.is_true()) || karamel_dbg!(right.is_true()));
-                    inc_memory_index!(context, 1);
-                },
-
-                VmOpCode::Multiply => {
-                    let right = pop!(context);
-                    let left  = pop!(context);
-                    *(*context.current_scope).stack_ptr = match (&*left, &*right) {
-                        (KaramelPrimative::Number(l_value),  KaramelPrimative::Number(r_value))   => VmObject::from(*l_value * *r_value),
-                        (KaramelPrimative::Text(l_value),    KaramelPrimative::Number(r_value))   => VmObject::from((*l_value).repeat((*r_value) as usize)),
-                        _ => EMPTY_OBJECT
-                    };
-                    inc_memory_index!(context, 1);
-                },
+            VmOpCode::CopyToStore => {
+                let tmp = *context.opcodes_ptr.offset(1) as usize;
+                *(*context.current_scope).memory_ptr.offset(tmp as isize) = karamel_dbg!(*(*context.current_scope).stack_ptr.sub(1));
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+            },
 
-                VmOpCode::Division => {
-                    let right = pop_raw!(context);
-                    let left  = pop_raw!(context);
+            VmOpCode::LoadCell => {
+                let tmp   = *context.opcodes_ptr.offset(1) as usize;
+                let scope = &mut *context.current_scope;
+                *scope.stack_ptr = karamel_dbg!(scope.cells[tmp].get());
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+                inc_memory_index!(context, 1);
+            },
 
-                    let calculation = match (left.as_number(), right.as_number()) {
-                        (Some(l_value),  Some(r_value))   => (l_value / r_value),
-                        _ => std::f64::NAN
-                    };
+            VmOpCode::StoreCell => {
+                let tmp = *context.opcodes_ptr.offset(1) as usize;
+                dec_memory_index!(context, 1);
+                let scope = &mut *context.current_scope;
+                scope.cells[tmp].set(karamel_dbg!(*scope.stack_ptr));
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+            },
 
-                    *(*context.current_scope).stack_ptr = if calculation.is_nan() {
-                        EMPTY_OBJECT
-                    }
-                    else {
-                        VmObject::from(calculation)
-                    };
+            VmOpCode::Closure => {
+                let func_location = *context.opcodes_ptr.offset(1) as usize;
+                let cell_size     = *context.opcodes_ptr.offset(2) as usize;
+                let scope         = &mut *context.current_scope;
+
+                /* Each closure has own copy of function reference with shared cells */
+                let value = (*scope.memory_ptr.offset(func_location as isize)).deref();
+                let mut closure = match &*value {
+                    KaramelPrimative::Function(reference, _) => (**reference).clone(),
+                    _ => return Err(KaramelErrorType::NotCallable(value.clone()))
+                };
+
+                closure.captures = (0..cell_size).map(|index| scope.cells[*context.opcodes_ptr.offset(3 + index as isize) as usize].clone()).collect();
+                *scope.stack_ptr = VmObject::from(Rc::new(KaramelPrimative::Function(Rc::new(closure), None)));
+                context.opcodes_ptr = context.opcodes_ptr.offset(2 + cell_size as isize);
+                inc_memory_index!(context, 1);
+            },
 
-                    inc_memory_index!(context, 1);
-                },
+            VmOpCode::FastStore => {
+                let destination = *context.opcodes_ptr.offset(1) as usize;
+                let source      = *context.opcodes_ptr.offset(2) as usize;
+                *(*context.current_scope).memory_ptr.offset(destination as isize) = karamel_dbg!(*(*context.current_scope).memory_ptr.offset(source as isize));
+                context.opcodes_ptr = context.opcodes_ptr.offset(2);
+            },
 
-                VmOpCode::Module => {
-                    let right = pop_raw!(context);
-                    let left  = pop_raw!(context);
+            VmOpCode::Not => {
+                *(*context.current_scope).stack_ptr.sub(1) = VmObject::from(!(*(*context.current_scope).stack_ptr.sub(1)).deref().is_true());
+            },
 
-                    *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
-                        (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) % karamel_dbg!(r_value)),
-                        _ => EMPTY_OBJECT
-                    };
+            VmOpCode::Dublicate => {
+                *(*context.current_scope).stack_ptr = karamel_dbg!(*(*context.current_scope).stack_ptr.sub(1));
+                inc_memory_index!(context, 1);
+            },
 
-                    inc_memory_index!(context, 1);
-                },
+            VmOpCode::Multiply => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
+                *(*context.current_scope).stack_ptr = match (&left.deref_clean(), &right.deref_clean()) {
+                    (KaramelPrimative::Number(l_value),  KaramelPrimative::Number(r_value)) if is_exact_result(*l_value, *r_value, l_value * r_value) => number_result(left, right, *l_value * *r_value),
+                    (KaramelPrimative::Text(l_value),    KaramelPrimative::Number(r_value))   => VmObject::from((*l_value).repeat((*r_value) as usize)),
+                    _ => number_operator(left, right, |left, right| left * right, |left, right| Some(left.mul(right)), |class, left, right| class.multiply(left, right))?
+                };
+                inc_memory_index!(context, 1);
+            },
 
-                VmOpCode::Equal => {                    
-                    let right = pop!(context);
-                    let left  = pop!(context);
-                    
-                    *(*context.current_scope).stack_ptr = VmObject::from(karamel_dbg!(left) == karamel_dbg!(right));
-                    inc_memory_index!(context, 1);
-                },
+            VmOpCode::Division => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
 
+                /* Big integers are divided exactly if there is no remainder */
+                *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
+                    (Some(l_value),  Some(r_value)) if !(l_value / r_value).is_nan() => number_result(left, right, l_value / r_value),
+                    (Some(_),  Some(_)) => class_operator(left, right, |class, left, right| class.division(left, right))?,
+                    _ => number_operator(left, right, |left, right| left / right, |left, right| left.div_rem(right).filter(|(_, remainder)| remainder.is_zero()).map(|(quotient, _)| quotient), |class, left, right| class.division(left, right))?
+                };
 
-                VmOpCode::NotEqual => {
-                    let right = pop!(context);
-                    let left  = pop!(context);
-                    
-                    *(*context.current_scope).stack_ptr = VmObject::from(karamel_dbg!(left) != karamel_dbg!(right));
-                    inc_memory_index!(context, 1);
-                },
+                inc_memory_index!(context, 1);
+            },
 
-                VmOpCode::GreaterThan => {
-                    let right = pop_raw!(context);
-                    let left  = pop_raw!(context);
-                    
-                    *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
-                        (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) > karamel_dbg!(r_value)),
-                        _ => EMPTY_OBJECT
-                    };
-                    inc_memory_index!(context, 1);
-                },
+            VmOpCode::Module => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
 
-                VmOpCode::GreaterEqualThan => {
-                    let right = pop_raw!(context);
-                    let left  = pop_raw!(context);
-                    
-                    *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
-                        (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) >= karamel_dbg!(r_value)),
-                        _ => EMPTY_OBJECT
-                    };
-                    inc_memory_index!(context, 1);
-                },
+                *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
+                    (Some(l_value),  Some(r_value))   => number_result(left, right, karamel_dbg!(l_value) % karamel_dbg!(r_value)),
+                    _ => number_operator(left, right, |left, right| left % right, |left, right| left.div_rem(right).map(|(_, remainder)| remainder), |class, left, right| class.modulo(left, right))?
+                };
 
-                VmOpCode::LessThan => {
-                    let right = pop_raw!(context);
-                    let left  = pop_raw!(context);
-                    
-                    *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
-                        (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) < karamel_dbg!(r_value)),
-                        _ => EMPTY_OBJECT
-                    };
-                    inc_memory_index!(context, 1);
-                },
+                inc_memory_index!(context, 1);
+            },
 
-                VmOpCode::LessEqualThan => {
-                    let right = pop_raw!(context);
-                    let left  = pop_raw!(context);
-                    
-                    *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
-                        (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) <= karamel_dbg!(r_value)),
-                        _ => EMPTY_OBJECT
-                    };
-                    inc_memory_index!(context, 1);
-                },
+            VmOpCode::Power => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
+                *(*context.current_scope).stack_ptr = number_operator(left, right, f64::powf, bigint::power, |_, _, _| None)?;
+                inc_memory_index!(context, 1);
+            },
 
-                VmOpCode::Call => {
-                    let func_location   = *context.opcodes_ptr.offset(1) as usize;
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                    
-                    let value = (*(*context.current_scope).memory_ptr.offset(func_location as isize)).deref();
-                    if let KaramelPrimative::Function(reference, _) = karamel_dbg!(&*value) {
-                        reference.execute(context, None)?;
+            VmOpCode::IntegerDivision => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
+                *(*context.current_scope).stack_ptr = number_operator(left, right, |left, right| (left / right).floor(), bigint::floor_division, |_, _, _| None)?;
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::BitwiseAnd => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
+                *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
+                    (Some(l_value), Some(r_value)) if is_safe_integer(l_value) && is_safe_integer(r_value) => VmObject::from(((l_value as i64) & (r_value as i64)) as f64),
+                    _ => integer_operator(left, right, |left, right| Some(left.bitand(right)))?
+                };
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::BitwiseOr => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
+                *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
+                    (Some(l_value), Some(r_value)) if is_safe_integer(l_value) && is_safe_integer(r_value) => VmObject::from(((l_value as i64) | (r_value as i64)) as f64),
+                    _ => integer_operator(left, right, |left, right| Some(left.bitor(right)))?
+                };
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::BitwiseXor => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
+                *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
+                    (Some(l_value), Some(r_value)) if is_safe_integer(l_value) && is_safe_integer(r_value) => VmObject::from(((l_value as i64) ^ (r_value as i64)) as f64),
+                    _ => integer_operator(left, right, |left, right| Some(left.bitxor(right)))?
+                };
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::BitwiseNot => {
+                let value = *(*context.current_scope).stack_ptr.sub(1);
+                *(*context.current_scope).stack_ptr.sub(1) = match bigint::exact_integer(value) {
+                    Some(integer) => integer.not().to_object(),
+                    None => return Err(KaramelErrorType::BitwiseWorksWithInteger(value.deref()))
+                };
+            },
+
+            VmOpCode::LeftShift => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
+                *(*context.current_scope).stack_ptr = integer_operator(left, right, bigint::shift_left)?;
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::RightShift => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
+                *(*context.current_scope).stack_ptr = integer_operator(left, right, bigint::shift_right)?;
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::In => {
+                let source = pop_raw!(context);
+                let value  = pop_raw!(context);
+
+                /* User defined method returns the result, execution continues from the next opcode */
+                let method = match &*source.deref() {
+                    KaramelPrimative::Class(class) => class.get_contains_method(),
+                    _ => None
+                };
+
+                match method {
+                    Some(method) => {
+                        *(*context.current_scope).stack_ptr = value;
+                        inc_memory_index!(context, 1);
+                        FunctionReference::create_call_scope(&method, context, Some(source), 1, true, context.opcodes_ptr)?;
+                    },
+                    None => {
+                        *(*context.current_scope).stack_ptr = VmObject::from(contains(&value.deref(), &source.deref())?);
+                        inc_memory_index!(context, 1);
                     }
-                    else {
-                        return Err(KaramelErrorType::NotCallable(value.clone()));
+                };
+            },
+
+            VmOpCode::Equal => {                    
+                let right = pop!(context);
+                let left  = pop!(context);
+                
+                *(*context.current_scope).stack_ptr = VmObject::from(karamel_dbg!(left) == karamel_dbg!(right));
+                inc_memory_index!(context, 1);
+            },
+
+
+            VmOpCode::NotEqual => {
+                let right = pop!(context);
+                let left  = pop!(context);
+                
+                *(*context.current_scope).stack_ptr = VmObject::from(karamel_dbg!(left) != karamel_dbg!(right));
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::GreaterThan => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
+                
+                *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
+                    (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) > karamel_dbg!(r_value)),
+                    _ => match class_compare(left, right) {
+                        Some(ordering) => VmObject::from(matches!(ordering, Ordering::Greater)),
+                        None => EMPTY_OBJECT
                     }
-                },
+                };
+                inc_memory_index!(context, 1);
+            },
 
-                VmOpCode::CallStack => {
-                    let function = pop_raw!(context);
-                    let value =  function.deref();
-                    match &*value {
-                        KaramelPrimative::Function(reference, base) => reference.execute(context, *base)?,
-                        _ => {
-                            log::debug!("{:?} not callable", &*function.deref());
-                        return Err(KaramelErrorType::NotCallable(value.clone()));
-                        }
-                    };
-                },
+            VmOpCode::GreaterEqualThan => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
+                
+                *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
+                    (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) >= karamel_dbg!(r_value)),
+                    _ => match class_compare(left, right) {
+                        Some(ordering) => VmObject::from(matches!(ordering, Ordering::Greater | Ordering::Equal)),
+                        None => EMPTY_OBJECT
+                    }
+                };
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::LessThan => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
+                
+                *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
+                    (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) < karamel_dbg!(r_value)),
+                    _ => match class_compare(left, right) {
+                        Some(ordering) => VmObject::from(matches!(ordering, Ordering::Less)),
+                        None => EMPTY_OBJECT
+                    }
+                };
+                inc_memory_index!(context, 1);
+            },
 
-                VmOpCode::Return => {
-                    let return_value               = *(*context.current_scope).stack_ptr.sub(1);
-                    context.opcodes_ptr            = (*context.current_scope).location;
-                    let call_return_assign_to_temp = (*context.current_scope).call_return_assign_to_temp;
-                    context.scope_index           -= 1;
-                    context.current_scope          = &mut context.scopes[context.scope_index] as *mut Scope;
+            VmOpCode::LessEqualThan => {
+                let right = pop_raw!(context);
+                let left  = pop_raw!(context);
+                
+                *(*context.current_scope).stack_ptr = match (left.as_number(), right.as_number()) {
+                    (Some(l_value),  Some(r_value))   => VmObject::from(karamel_dbg!(l_value) <= karamel_dbg!(r_value)),
+                    _ => match class_compare(left, right) {
+                        Some(ordering) => VmObject::from(matches!(ordering, Ordering::Less | Ordering::Equal)),
+                        None => EMPTY_OBJECT
+                    }
+                };
+                inc_memory_index!(context, 1);
+            },
 
-                    if call_return_assign_to_temp {
+            VmOpCode::Call => {
+                let func_location   = *context.opcodes_ptr.offset(1) as usize;
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+                
+                let value = (*(*context.current_scope).memory_ptr.offset(func_location as isize)).deref();
+                if let KaramelPrimative::Function(reference, _) = karamel_dbg!(&*value) {
+                    reference.execute(context, None)?;
+                }
+                else {
+                    return Err(KaramelErrorType::NotCallable(value.clone()));
+                }
+            },
+
+            VmOpCode::CallStack => {
+                let function = pop_raw!(context);
+                let value =  function.deref();
+                match &*value {
+                    KaramelPrimative::Function(reference, base) => reference.execute(context, *base)?,
+                    KaramelPrimative::Class(class) => match class.create_instance() {
+                        Some(instance) => {
+                            let object = VmObject::from(Rc::new(KaramelPrimative::Class(instance.clone())));
+                            match instance.get_element(Some(object), Rc::new(CONSTRUCTOR_NAME.to_string())) {
+                                Some(ClassProperty::Function(constructor)) => constructor.execute(context, Some(object))?,
+                                _ => return Err(KaramelErrorType::NotCallable(value.clone()))
+                            };
+                        },
+                        None => return Err(KaramelErrorType::NotCallable(value.clone()))
+                    },
+                    _ => {
+                        log::debug!("{:?} not callable", &*function.deref());
+                    return Err(KaramelErrorType::NotCallable(value.clone()));
+                    }
+                };
+            },
+
+            VmOpCode::Return => {
+                let return_value               = *(*context.current_scope).stack_ptr.sub(1);
+                context.opcodes_ptr            = (*context.current_scope).location;
+                let call_return_assign_to_temp = (*context.current_scope).call_return_assign_to_temp;
+                let generator                  = (*context.current_scope).generator.take();
+                context.scope_index           -= 1;
+                context.current_scope          = &mut context.scopes[context.scope_index] as *mut Scope;
+
+                match generator {
+                    /* Generator finished, returned value is not produced */
+                    Some(generator) => {
+                        let mut state  = generator.borrow_mut();
+                        state.finished = true;
+                        if !state.iterating {
+                            return Err(KaramelErrorType::GeneratorFinished);
+                        }
+                    },
+                    None => if call_return_assign_to_temp {
                         *(*context.current_scope).stack_ptr = return_value;
                         inc_memory_index!(context, 1);
                     }
-                },
+                };
+            },
 
-                VmOpCode::Increment => {
-                    *(*context.current_scope).stack_ptr.sub(1) = match (*(*context.current_scope).stack_ptr.sub(1)).as_number() {
-                        Some(value) => VmObject::from(karamel_dbg!(value + 1 as f64)),
-                        _ => EMPTY_OBJECT
+            /* Scope is moved to generator and the value is given to caller */
+            VmOpCode::Yield => {
+                let value     = pop_raw!(context);
+                let mut scope = mem::replace(&mut context.scopes[context.scope_index], Scope::empty());
+                let generator = match scope.generator.take() {
+                    Some(generator) => generator,
+                    None => return Err(KaramelErrorType::YieldMustBeUsedInFunction)
+                };
+
+                let return_location            = scope.location;
+                let call_return_assign_to_temp = scope.call_return_assign_to_temp;
+
+                {
+                    let mut state  = generator.borrow_mut();
+                    state.location = context.opcodes_ptr;
+                    state.scope    = Some(scope);
+
+                    /* 'dene' blocks of the generator are activated again at next resume */
+                    let owned_handlers = context.error_handlers.iter().position(|handler| handler.scope_index == context.scope_index).unwrap_or(context.error_handlers.len());
+                    state.error_handlers = context.error_handlers.split_off(owned_handlers);
+
+                    if !call_return_assign_to_temp && state.iterating {
+                        state.value = Some(value);
+                    }
+                }
+
+                context.scope_index  -= 1;
+                context.current_scope = &mut context.scopes[context.scope_index] as *mut Scope;
+                context.opcodes_ptr   = return_location;
+
+                if call_return_assign_to_temp {
+                    *(*context.current_scope).stack_ptr = value;
+                    inc_memory_index!(context, 1);
+                }
+            },
+
+            VmOpCode::Increment => {
+                let object = *(*context.current_scope).stack_ptr.sub(1);
+                *(*context.current_scope).stack_ptr.sub(1) = match object.as_number() {
+                    Some(value) if is_exact_result(value, 1.0, value + 1.0) => number_result(object, VmObject::from(1.0), karamel_dbg!(value + 1 as f64)),
+                    _ => bigint::calculate(object, VmObject::from(1.0), |left, right| left + right, |left, right| Some(left.add(right))).unwrap_or(EMPTY_OBJECT)
+                };
+            },
+
+            VmOpCode::Decrement => {
+                let object = *(*context.current_scope).stack_ptr.sub(1);
+                *(*context.current_scope).stack_ptr.sub(1) = match object.as_number() {
+                    Some(value) if is_exact_result(value, 1.0, value - 1.0) => number_result(object, VmObject::from(1.0), value - 1 as f64),
+                    _ => bigint::calculate(object, VmObject::from(1.0), |left, right| left - right, |left, right| Some(left.sub(right))).unwrap_or(EMPTY_OBJECT)
+                };
+            },
+
+            VmOpCode::InitList => {
+                let total_item = *context.opcodes_ptr.offset(1);
+                let mut list = Vec::with_capacity(total_item.into());
+
+                for _ in 0..total_item {
+                    list.push(pop_raw!(context));
+                }
+                
+                *(*context.current_scope).stack_ptr = VmObject::from(list);
+                inc_memory_index!(context, 1);
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+            },
+
+            VmOpCode::InitTuple => {
+                let total_item = *context.opcodes_ptr.offset(1);
+                let mut items = Vec::with_capacity(total_item.into());
+
+                for _ in 0..total_item {
+                    items.push(pop_raw!(context));
+                }
+
+                *(*context.current_scope).stack_ptr = VmObject::native_convert(KaramelPrimative::Tuple(Rc::new(items)));
+                inc_memory_index!(context, 1);
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+            },
+
+            /* Items are pushed in reverse order, first target is stored first */
+            VmOpCode::Unpack => {
+                let total_item = *context.opcodes_ptr.offset(1) as usize;
+                let value = pop!(context);
+
+                let items = match &*value {
+                    KaramelPrimative::List(items) => items.borrow().to_vec(),
+                    KaramelPrimative::Tuple(items) => items.to_vec(),
+                    _ => return Err(KaramelErrorType::DestructuringValueNotValid(value.clone()))
+                };
+
+                if items.len() != total_item {
+                    return Err(KaramelErrorType::DestructuringCountNotMatching {
+                        expected: total_item,
+                        found: items.len()
+                    });
+                }
+
+                for item in items.into_iter().rev() {
+                    *(*context.current_scope).stack_ptr = item;
+                    inc_memory_index!(context, 1);
+                }
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+            },
+
+            VmOpCode::InitDict => {
+                let total_item = *context.opcodes_ptr.offset(1) as usize;
+                let mut dict   = KaramelDict::new();
+
+                for _ in 0..total_item {
+                    let value = pop_raw!(context);
+                    let key   = pop!(context);
+
+                    match HashKey::from_primative(&key) {
+                        Some(key) => dict.insert(key, value),
+                        None => return Err(KaramelErrorType::ValueNotHashable(key.clone()))
                     };
-                },
+                }
+                
+                *(*context.current_scope).stack_ptr = VmObject::from(dict);
+                inc_memory_index!(context, 1);
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+            },
 
-                VmOpCode::Decrement => {
-                    *(*context.current_scope).stack_ptr.sub(1) = match (*(*context.current_scope).stack_ptr.sub(1)).as_number() {
-                        Some(value) => VmObject::from(value - 1 as f64),
-                        _ => EMPTY_OBJECT
+            VmOpCode::InitSet => {
+                let total_item = *context.opcodes_ptr.offset(1) as usize;
+                let mut set    = KaramelSet::new();
+
+                for _ in 0..total_item {
+                    let item = pop!(context);
+                    match HashKey::from_primative(&item) {
+                        Some(key) => set.insert(key),
+                        None => return Err(KaramelErrorType::ValueNotHashable(item.clone()))
                     };
-                },
+                }
 
-                VmOpCode::InitList => {
-                    let total_item = *context.opcodes_ptr.offset(1);
-                    let mut list = Vec::with_capacity(total_item.into());
+                *(*context.current_scope).stack_ptr = VmObject::native_convert(KaramelPrimative::Set(RefCell::new(set)));
+                inc_memory_index!(context, 1);
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+            },
 
-                    for _ in 0..total_item {
-                        list.push(pop_raw!(context));
-                    }
-                    
-                    *(*context.current_scope).stack_ptr = VmObject::from(list);
-                    inc_memory_index!(context, 1);
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                },
-
-                VmOpCode::InitDict => {
-                    let total_item = *context.opcodes_ptr.offset(1) as usize;
-                    let mut dict   = HashMap::new();
-
-                    for _ in 0..total_item {
-                        let value = pop_raw!(context);
-                        let key   = pop!(context);
-                        
-                        dict.insert(key.get_text(), value);
+            VmOpCode::Compare => {
+                let condition = pop_raw!(context);
+
+                let status = match &condition.deref_clean() {
+                    KaramelPrimative::Empty => false,
+                    KaramelPrimative::Bool(l_value) => *l_value,
+                    KaramelPrimative::Number(l_value) => *l_value > 0.0,
+                    KaramelPrimative::Text(l_value) => !(*l_value).is_empty(),
+                    _ => false
+                };
+
+                if status {
+                    context.opcodes_ptr = context.opcodes_ptr.offset(2);
+                }
+                else {
+                    let location = ((*context.opcodes_ptr.offset(2) as u16 * 256) + *context.opcodes_ptr.offset(1) as u16) as usize;
+                    context.opcodes_ptr = context.opcodes_ptr.offset(location as isize);
+                }
+            },
+
+            VmOpCode::Iterate => {
+                let source_location   = *context.opcodes_ptr.offset(1) as isize;
+                let position_location = *context.opcodes_ptr.offset(2) as isize;
+                let variable_size     = *context.opcodes_ptr.offset(3);
+                let scope             = &mut *context.current_scope;
+
+                let position = (*scope.memory_ptr.offset(position_location)).as_number().unwrap_or_default() as usize;
+                let source   = (*scope.memory_ptr.offset(source_location)).deref();
+
+                /* List and text give position and item, dict gives key and value */
+                let item = match &*source {
+                    KaramelPrimative::List(items) => items.borrow().get(position).map(|item| (VmObject::from(position as f64), *item, false)),
+                    KaramelPrimative::Tuple(items) => items.get(position).map(|item| (VmObject::from(position as f64), *item, false)),
+                    KaramelPrimative::Set(items) => items.borrow().get(position).map(|item| (VmObject::from(position as f64), item.to_object(), false)),
+                    KaramelPrimative::Generator(generator) => generator.borrow_mut().value.take().map(|item| (VmObject::from(position as f64), item, false)),
+                    KaramelPrimative::Text(text)  => {
+                        /* Characters are collected once and source is replaced with them, next iterations read characters by position */
+                        let characters: Vec<VmObject> = text.chars().map(|ch| VmObject::from(Rc::new(ch.to_string()))).collect();
+                        let item = characters.get(position).map(|item| (VmObject::from(position as f64), *item, false));
+                        *scope.memory_ptr.offset(source_location) = VmObject::native_convert(KaramelPrimative::Tuple(Rc::new(characters)));
+                        item
+                    },
+                    KaramelPrimative::Dict(items) => items.borrow().get_index(position).map(|(key, value)| (key.to_object(), *value, true)),
+                    KaramelPrimative::Class(class) => match class.get_length() {
+                        Some(length) => match position < length {
+                            true => class.get_item(position).map(|item| (VmObject::from(position as f64), item, false)),
+                            false => None
+                        },
+                        None => return Err(KaramelErrorType::NotIterable(source.clone()))
+                    },
+                    _ => return Err(KaramelErrorType::NotIterable(source.clone()))
+                };
+
+                match item {
+                    Some((key, value, is_dict)) => {
+                        *scope.memory_ptr.offset(position_location) = VmObject::from((position + 1) as f64);
+
+                        match (variable_size, is_dict) {
+                            (1, true) => *scope.stack_ptr = key,
+                            (1, false) => *scope.stack_ptr = value,
+                            _ => {
+                                *scope.stack_ptr = key;
+                                inc_memory_index!(context, 1);
+                                *(*context.current_scope).stack_ptr = value;
+                            }
+                        };
+
+                        inc_memory_index!(context, 1);
+                        context.opcodes_ptr = context.opcodes_ptr.offset(5);
+                    },
+                    None => match &*source {
+                        /* Generator runs until the next 'üret', then loop continues from here */
+                        KaramelPrimative::Generator(generator) if !generator.borrow().finished => resume_generator(context, generator.clone(), context.opcodes_ptr.sub(1), false, true)?,
+                        _ => {
+                            let location = ((*context.opcodes_ptr.offset(5) as u16 * 256) + *context.opcodes_ptr.offset(4) as u16) as isize;
+                            context.opcodes_ptr = context.opcodes_ptr.offset(location + 3);
+                        }
                     }
-                    
-                    *(*context.current_scope).stack_ptr = VmObject::from(dict);
-                    inc_memory_index!(context, 1);
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                },
-
-                VmOpCode::Compare => {
-                    let condition = pop_raw!(context);
-
-                    let status = match &condition.deref_clean() {
-                        KaramelPrimative::Empty => false,
-                        KaramelPrimative::Bool(l_value) => *l_value,
-                        KaramelPrimative::Number(l_value) => *l_value > 0.0,
-                        KaramelPrimative::Text(l_value) => !(*l_value).is_empty(),
-                        _ => false
+                };
+            },
+
+            VmOpCode::RangeIterate => {
+                let next_location = *context.opcodes_ptr.offset(1) as isize;
+                let last_location = *context.opcodes_ptr.offset(2) as isize;
+                let step_location = *context.opcodes_ptr.offset(3) as isize;
+                let has_step      = *context.opcodes_ptr.offset(4) == 1;
+                let scope         = &mut *context.current_scope;
+
+                let mut values = [0.0; 3];
+                for (value, location) in values.iter_mut().zip([next_location, last_location, step_location].iter()) {
+                    let object = *scope.memory_ptr.offset(*location);
+                    *value = match object.as_number().or_else(|| bigint::to_f64(&object.deref())) {
+                        Some(number) => number,
+                        None => return Err(KaramelErrorType::RangeValueMustBeNumber(object.deref()))
                     };
+                }
 
-                    if status {
-                        context.opcodes_ptr = context.opcodes_ptr.offset(2);
+                let [next, last, mut step] = values;
+                if step == 0.0 {
+                    if has_step {
+                        return Err(KaramelErrorType::RangeStepCannotBeZero);
                     }
-                    else {
-                        let location = ((*context.opcodes_ptr.offset(2) as u16 * 256) + *context.opcodes_ptr.offset(1) as u16) as usize;
-                        context.opcodes_ptr = context.opcodes_ptr.offset(location as isize);
+
+                    /* Step is not defined, range direction decides it */
+                    step = if next <= last { 1.0 } else { -1.0 };
+                    *scope.memory_ptr.offset(step_location) = VmObject::from(step);
+                }
+
+                let in_range = match step > 0.0 {
+                    true => next <= last,
+                    false => next >= last
+                };
+
+                match in_range {
+                    true => {
+                        *scope.memory_ptr.offset(next_location) = VmObject::from(next + step);
+                        *scope.stack_ptr = VmObject::from(next);
+                        inc_memory_index!(context, 1);
+                        context.opcodes_ptr = context.opcodes_ptr.offset(6);
+                    },
+                    false => {
+                        let location = ((*context.opcodes_ptr.offset(6) as u16 * 256) + *context.opcodes_ptr.offset(5) as u16) as isize;
+                        context.opcodes_ptr = context.opcodes_ptr.offset(location + 4);
                     }
-                },
+                };
+            },
 
-                VmOpCode::Jump => {
-                    let location = ((*context.opcodes_ptr.offset(2)  as u16 * 256) + *context.opcodes_ptr.offset(1)  as u16) as usize;
-                    context.opcodes_ptr = context.opcodes.as_mut_ptr().offset(location as isize);
-                    continue;
-                },
-                
-                VmOpCode::SetItem => {
-                    let assign_item  = pop_raw!(context);
-                    let indexer = pop!(context);
-                    let raw_object = pop_raw!(context);
-                    let object  = raw_object.deref();
-
-                    // todo: change all those codes with setter implementation
-
-                    match &*object {
-                        KaramelPrimative::List(value) => {
-                            let indexer_value = match &*indexer {
-                                KaramelPrimative::Number(number) => *number as usize,
-                                _ => return Err(KaramelErrorType::IndexerMustBeNumber(indexer.clone()))
-                            };
+            /* 've' and 'veya', deciding left operand stays as result, otherwise right operand will be calculated */
+            VmOpCode::JumpIfFalse | VmOpCode::JumpIfTrue => {
+                let value = (*(*context.current_scope).stack_ptr.sub(1)).deref();
 
-                            value.borrow_mut()[indexer_value] = assign_item;
-                        },
-                        KaramelPrimative::Dict(value) => {
-                            let indexer_value = match &*indexer {
-                                KaramelPrimative::Text(text) => &*text,
-                                _ => return Err(KaramelErrorType::IndexerMustBeString(indexer.clone()))
-                            };
+                if value.is_true() == (opcode == VmOpCode::JumpIfTrue) {
+                    let location = ((*context.opcodes_ptr.offset(2) as u16 * 256) + *context.opcodes_ptr.offset(1) as u16) as usize;
+                    context.opcodes_ptr = context.opcodes_ptr.offset(location as isize);
+                }
+                else {
+                    dec_memory_index!(context, 1);
+                    context.opcodes_ptr = context.opcodes_ptr.offset(2);
+                }
+            },
 
-                            value.borrow_mut().insert(indexer_value.to_string(), assign_item);
-                        },
-                        KaramelPrimative::Text(_) => {
-                            let indexer_value = match &*indexer {
-                                KaramelPrimative::Number(number) => *number,
-                                _ => return Err(KaramelErrorType::IndexerMustBeNumber(indexer.clone()))
-                            };
+            VmOpCode::Jump => {
+                let location = ((*context.opcodes_ptr.offset(2)  as u16 * 256) + *context.opcodes_ptr.offset(1)  as u16) as usize;
+                context.opcodes_ptr = context.opcodes.as_mut_ptr().offset(location as isize);
+                continue;
+            },
+            
+            VmOpCode::SetItem => {
+                let assign_item  = pop_raw!(context);
+                let indexer = pop!(context);
+                let raw_object = pop_raw!(context);
+                let object  = raw_object.deref();
+
+                // todo: change all those codes with setter implementation
+
+                match &*object {
+                    KaramelPrimative::Dict(value) => {
+                        let key = match HashKey::from_primative(&indexer) {
+                            Some(key) => key,
+                            None => return Err(KaramelErrorType::ValueNotHashable(indexer.clone()))
+                        };
+
+                        value.borrow_mut().insert(key, assign_item);
+                    },
+                    KaramelPrimative::List(_) | KaramelPrimative::Text(_) => {
+                        let indexer_value = match &*indexer {
+                            KaramelPrimative::Number(number) => *number,
+                            _ => return Err(KaramelErrorType::IndexerMustBeNumber(indexer.clone()))
+                        };
+
+                        match context.get_class(&object).get_setter() {
+                            Some(function) => function(raw_object, indexer_value, assign_item)?,
+                            _ => EMPTY_OBJECT
+                        };
+                    },
+                    KaramelPrimative::Class(class) => {
+                        let indexer_value = match &*indexer {
+                            KaramelPrimative::Text(text) => text.clone(),
+                            _ => return Err(KaramelErrorType::IndexerMustBeString(indexer.clone()))
+                        };
+
+                        class.set_element(Some(raw_object), indexer_value, assign_item);
+                    },
+                    KaramelPrimative::Tuple(_) => return Err(KaramelErrorType::TupleIsImmutable),
+                    
+                    _ => ()
+                };
+            },
 
-                            match context.get_class(&object).get_setter() {
-                                Some(function) => function(raw_object, indexer_value, assign_item)?,
-                                _ => EMPTY_OBJECT
-                            };
-                        },
-                        
-                        _ => ()
-                    };
-                },
-
-                VmOpCode::GetItem => {
-                    let indexer = pop!(context);
-                    let raw_object  = pop_raw!(context);
-                    let object = &*raw_object.deref();
-
-                    *(*context.current_scope).stack_ptr = match &*indexer {
-                        KaramelPrimative::Text(text) => {
-                             match context.get_class(object).get_element(Some(raw_object), text.clone()) {
-                                Some(element) => match element {
-                                    ClassProperty::Function(function) => VmObject::from(Rc::new(KaramelPrimative::Function(function.clone(), Some(raw_object)))),
-                                    ClassProperty::Field(field) => VmObject::from(field.clone())
-                                },
-                                _ => EMPTY_OBJECT
-                            }
-                        },
-                        KaramelPrimative::Number(index) => match context.get_class(object).get_getter() {
-                            Some(function) => function(raw_object, *index)?,
+            VmOpCode::GetItem => {
+                let indexer = pop!(context);
+                let raw_object  = pop_raw!(context);
+                let object = &*raw_object.deref();
+
+                *(*context.current_scope).stack_ptr = match (object, &*indexer) {
+                    (_, KaramelPrimative::Text(text)) => {
+                         match context.get_class(object).get_element(Some(raw_object), text.clone()) {
+                            Some(element) => match element {
+                                ClassProperty::Function(function) => VmObject::from(Rc::new(KaramelPrimative::Function(function.clone(), Some(raw_object)))),
+                                ClassProperty::Field(field) => VmObject::from(field.clone())
+                            },
                             _ => EMPTY_OBJECT
                         }
+                    },
+                    (KaramelPrimative::Dict(dict), _) => match HashKey::from_primative(&indexer) {
+                        Some(key) => dict.borrow().get(&key).copied().unwrap_or(EMPTY_OBJECT),
+                        None => return Err(KaramelErrorType::ValueNotHashable(indexer.clone()))
+                    },
+                    (_, KaramelPrimative::Number(index)) => match context.get_class(object).get_getter() {
+                        Some(function) => function(raw_object, *index)?,
                         _ => EMPTY_OBJECT
+                    }
+                    _ => EMPTY_OBJECT
+                };
+
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::Append => {
+                let item = pop_raw!(context);
+                let raw_object = pop_raw!(context);
+
+                if let KaramelPrimative::List(list) = &*raw_object.deref() {
+                    list.borrow_mut().push(item);
+                }
+            },
+
+            VmOpCode::GetSlice => {
+                let step   = pop!(context);
+                let end    = pop!(context);
+                let start  = pop!(context);
+                let raw_object = pop_raw!(context);
+
+                let mut positions = Vec::with_capacity(3);
+                for position in [start, end, step] {
+                    positions.push(match &*position {
+                        KaramelPrimative::Empty => None,
+                        KaramelPrimative::Number(number) => Some(*number),
+                        _ => return Err(KaramelErrorType::IndexerMustBeNumber(position.clone()))
+                    });
+                }
+
+                *(*context.current_scope).stack_ptr = match context.get_class(&*raw_object.deref()).get_slicer() {
+                    Some(function) => function(raw_object, positions[0], positions[1], positions[2])?,
+                    _ => EMPTY_OBJECT
+                };
+
+                inc_memory_index!(context, 1);
+            },
+
+            VmOpCode::InitArguments => {
+                let size = *context.opcodes_ptr.offset(1) as usize;
+                let const_size = (*context.current_scope).const_size as usize;
+                for i in 0..size {
+                    dec_memory_index!(context, 1);
+                    *(*context.current_scope).memory_ptr.offset((i + const_size) as isize) = *(*context.current_scope).stack_ptr;
+                }
+
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+            },
+
+            VmOpCode::InitDefault => {
+                let index    = *context.opcodes_ptr.offset(1) as usize;
+                let location = ((*context.opcodes_ptr.offset(3) as u16 * 256) + *context.opcodes_ptr.offset(2) as u16) as usize;
+                let missing_arguments = &(*context.current_scope).missing_arguments;
+
+                /* Argument passed, default value not needed */
+                if !missing_arguments.get(index).copied().unwrap_or_default() {
+                    context.opcodes_ptr = context.opcodes.as_mut_ptr().offset(location as isize);
+                    continue;
+                }
+                context.opcodes_ptr = context.opcodes_ptr.offset(3);
+            },
+
+            VmOpCode::KeywordArguments => {
+                let size = *context.opcodes_ptr.offset(1) as usize;
+                context.keyword_arguments.clear();
+
+                for index in 0..size {
+                    let location = *context.opcodes_ptr.offset(2 + index as isize) as isize;
+                    match &*(*(*context.current_scope).memory_ptr.offset(location)).deref() {
+                        KaramelPrimative::Text(name) => context.keyword_arguments.push(name.clone()),
+                        _ => return Err(KaramelErrorType::ValueNotFoundInStorage)
                     };
+                }
+                context.opcodes_ptr = context.opcodes_ptr.offset(1 + size as isize);
+            },
 
-                    inc_memory_index!(context, 1);
-                },
-
-                VmOpCode::InitArguments => {
-                    let size = *context.opcodes_ptr.offset(1) as usize;
-                    let const_size = (*context.current_scope).const_size as usize;
-                    for i in 0..size {
-                        dec_memory_index!(context, 1);
-                        *(*context.current_scope).memory_ptr.offset((i + const_size) as isize) = *(*context.current_scope).stack_ptr;
-                    }
+            VmOpCode::Try => {
+                let location = ((*context.opcodes_ptr.offset(2) as u16 * 256) + *context.opcodes_ptr.offset(1) as u16) as usize;
+                context.error_handlers.push(ErrorHandler {
+                    scope_index: context.scope_index,
+                    stack_ptr: (*context.current_scope).stack_ptr,
+                    location
+                });
+                context.opcodes_ptr = context.opcodes_ptr.offset(2);
+            },
 
-                    context.opcodes_ptr = context.opcodes_ptr.offset(1);
-                },
-                VmOpCode::Func => (),
-                VmOpCode::None => (),
-                VmOpCode::Halt => {
-                    break;
-                },
-            }
+            VmOpCode::EndTry => {
+                context.error_handlers.pop();
+            },
+
+            VmOpCode::Throw => {
+                let value = pop!(context);
+                return Err(KaramelErrorType::ThrownError(value));
+            },
+
+            VmOpCode::Format => {
+                let format_location = *context.opcodes_ptr.offset(1) as usize;
+                let value           = pop!(context);
+                let format          = (*(*context.current_scope).memory_ptr.offset(format_location as isize)).deref();
+                let text_format     = match &*format {
+                    KaramelPrimative::Text(format) => TextFormat::parse(format).unwrap_or_default(),
+                    _ => TextFormat::default()
+                };
+
+                *(*context.current_scope).stack_ptr = VmObject::from(Rc::new(text_format.apply(&value)));
+                inc_memory_index!(context, 1);
+                context.opcodes_ptr = context.opcodes_ptr.offset(1);
+            },
+
+            VmOpCode::Func => (),
+            VmOpCode::None => (),
+            VmOpCode::Halt => {
+                return Ok(());
+            },
+        }
 
-            let after = get_memory_index!(context);
-            context.opcodes_ptr = context.opcodes_ptr.offset(1);
+        let after = get_memory_index!(context);
+        context.opcodes_ptr = context.opcodes_ptr.offset(1);
+    }
+}
+
+/* Generators that the error passes through could not be resumed again */
+fn finish_generators(context: &mut KaramelCompilerContext, scope_index: usize) {
+    for scope in context.scopes[scope_index + 1..=context.scope_index].iter_mut() {
+        if let Some(generator) = scope.generator.take() {
+            generator.borrow_mut().finished = true;
+        }
+    }
+}
+
+pub unsafe fn run_vm(context: &mut KaramelCompilerContext) -> Result<Vec<VmObject>, KaramelErrorType>
+{
+    #[cfg(all(feature = "dumpOpcodes"))]
+    let mut log_update = LogUpdate::new(stdout()).unwrap();
+    
+    #[cfg(feature = "dumpMemory")] {
+        context.storages[0].dump();
+    }
+    
+    #[cfg(all(feature = "dumpOpcodes"))] {
+        dump_opcode(0, context, &mut log_update);
+        return Ok(Vec::new());
+    }
+    {
+        let mut stack = context.storages[0].get_stack();
+        let stack_ptr = stack.as_mut_ptr();
+
+        let mut memory = context.storages[0].get_memory();
+        let memory_ptr = memory.as_mut_ptr();
+
+        context.scopes[context.scope_index] = Scope {
+            memory: memory,
+            stack: stack,
+            location: ptr::null_mut(),
+            const_size: 0,
+            call_return_assign_to_temp: false,
+            stack_ptr: stack_ptr,
+            memory_ptr: memory_ptr,
+            storage_index: 0,
+            cells: Vec::new(),
+            missing_arguments: Vec::new(),
+            generator: None
+        };
+
+        /* Runtime errors are passed to the last 'dene' block, otherwise execution stops. Program ends when all tasks are finished */
+        let mut pending_error = None;
+        loop {
+            let result = match pending_error.take() {
+                Some(error) => Err(error),
+                None => execute(context).and_then(|_| finish_task(context))
+            };
+
+            let error = match result {
+                Ok(true) => continue,
+                Ok(false) => break,
+                Err(error) => error
+            };
+
+            /* Task without 'dene' block finishes, error is raised again at 'bekle' of the next task. Deadlock stops the program */
+            let handler = match context.error_handlers.pop() {
+                Some(handler) => handler,
+                None => {
+                    finish_generators(context, 0);
+                    if context.scheduler.is_main_running() || error == KaramelErrorType::TaskDeadlock {
+                        return Err(error);
+                    }
+
+                    match fail_task(context, error) {
+                        Ok(true) => continue,
+                        Ok(false) => break,
+                        Err(error) => {
+                            pending_error = Some(error);
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            finish_generators(context, handler.scope_index);
+            context.keyword_arguments.clear();
+            context.scope_index   = handler.scope_index;
+            context.current_scope = &mut context.scopes[context.scope_index] as *mut Scope;
+            (*context.current_scope).stack_ptr = handler.stack_ptr;
+
+            *(*context.current_scope).stack_ptr = ErrorClass::create_object(error);
+            inc_memory_index!(context, 1);
+            context.opcodes_ptr = context.opcodes.as_mut_ptr().offset(handler.location as isize);
         }
 
         
@@ -1,2 +1,3 @@
 pub mod interpreter;
-pub mod executer;
\ No newline at end of file
+pub mod executer;
+pub mod scheduler;
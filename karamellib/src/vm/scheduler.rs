@@ -0,0 +1,264 @@
+use std::collections::VecDeque;
+use std::mem;
+use std::ptr;
+use std::rc::Rc;
+
+use crate::compiler::context::KaramelCompilerContext;
+use crate::compiler::function::FunctionReference;
+use crate::compiler::scope::{Scope, ErrorHandler};
+use crate::compiler::value::EMPTY_OBJECT;
+use crate::error::KaramelErrorType;
+use crate::types::VmObject;
+
+/* Main program is the first task */
+pub const MAIN_TASK: usize = 0;
+
+#[derive(Clone, Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum TaskState {
+    Ready,
+    Waiting,
+    Finished
+}
+
+/* Call stack of a task. Scopes of the running task are in context */
+pub struct Task {
+    pub scopes: Vec<Scope>,
+    pub scope_index: usize,
+    pub error_handlers: Vec<ErrorHandler>,
+    pub location: *mut u8,
+    pub state: TaskState,
+
+    /* Error that is not caught in the task is raised again at 'bekle' */
+    pub result: Result<VmObject, KaramelErrorType>,
+
+    /* Tasks that wait this task with 'bekle' */
+    pub waiters: Vec<usize>,
+
+    /* Waiting call needs a value when task continues, result of 'bekle' or 'al' */
+    pub assign_to_temp: bool,
+    pub resume_value: Option<VmObject>,
+    pub resume_error: Option<KaramelErrorType>
+}
+
+impl Task {
+    fn new() -> Task {
+        Task {
+            scopes: Vec::new(),
+            scope_index: 0,
+            error_handlers: Vec::new(),
+            location: ptr::null_mut(),
+            state: TaskState::Ready,
+            result: Ok(EMPTY_OBJECT),
+            waiters: Vec::new(),
+            assign_to_temp: false,
+            resume_value: None,
+            resume_error: None
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Channel {
+    pub values: VecDeque<VmObject>,
+    pub receivers: VecDeque<usize>
+}
+
+/* Tasks run in order, running task continues until it waits or gives the turn with 'bırak' */
+#[derive(Default)]
+pub struct Scheduler {
+    pub tasks: Vec<Task>,
+    pub channels: Vec<Channel>,
+    pub ready: VecDeque<usize>,
+    pub current: usize
+}
+
+impl Scheduler {
+    fn initialize(&mut self) {
+        if self.tasks.is_empty() {
+            self.tasks.push(Task::new());
+        }
+    }
+
+    /* Errors at main task stop the program */
+    pub fn is_main_running(&self) -> bool {
+        self.tasks.is_empty() || self.current == MAIN_TASK
+    }
+
+    pub fn create_channel(&mut self) -> usize {
+        self.channels.push(Channel::default());
+        self.channels.len() - 1
+    }
+
+    /* Waiting task is added to the end of the queue, error is raised when the task continues */
+    pub fn wake(&mut self, task_id: usize, result: Result<VmObject, KaramelErrorType>) {
+        let task = &mut self.tasks[task_id];
+        task.state = TaskState::Ready;
+        match result {
+            Ok(value) if task.assign_to_temp => task.resume_value = Some(value),
+            Ok(_) => (),
+            Err(error) => task.resume_error = Some(error)
+        };
+        self.ready.push_back(task_id);
+    }
+}
+
+/* Call stack of the running task is moved to the task list */
+unsafe fn save_task(context: &mut KaramelCompilerContext) {
+    let task = &mut context.scheduler.tasks[context.scheduler.current];
+    task.scopes         = mem::take(&mut context.scopes);
+    task.scope_index    = context.scope_index;
+    task.error_handlers = mem::take(&mut context.error_handlers);
+    task.location       = context.opcodes_ptr;
+}
+
+unsafe fn load_task(context: &mut KaramelCompilerContext, task_id: usize) -> Result<(), KaramelErrorType> {
+    context.scheduler.current = task_id;
+
+    let task = &mut context.scheduler.tasks[task_id];
+    let resume_value        = task.resume_value.take();
+    let resume_error        = task.resume_error.take();
+    context.scopes          = mem::take(&mut task.scopes);
+    context.scope_index     = task.scope_index;
+    context.error_handlers  = mem::take(&mut task.error_handlers);
+    context.opcodes_ptr     = task.location;
+    context.current_scope   = &mut context.scopes[context.scope_index] as *mut Scope;
+
+    if let Some(error) = resume_error {
+        return Err(error);
+    }
+
+    if let Some(value) = resume_value {
+        *(*context.current_scope).stack_ptr = value;
+        inc_memory_index!(context, 1);
+    }
+    Ok(())
+}
+
+/// New task calls the function from an empty scope. Function returns to 'Halt' and task finishes there.
+///
+/// # Safety
+/// Context should be executing compiled opcodes, running task's scope is replaced while the call scope is created.
+pub unsafe fn create_task(context: &mut KaramelCompilerContext, function: Rc<FunctionReference>, base: Option<VmObject>, arguments: &[VmObject]) -> Result<usize, KaramelErrorType> {
+    context.scheduler.initialize();
+    let current = context.scheduler.current;
+    let task_id = context.scheduler.tasks.len();
+    context.scheduler.tasks.push(Task::new());
+    save_task(context);
+
+    let mut scope = Scope::empty();
+    scope.stack      = vec![EMPTY_OBJECT; arguments.len() + 1];
+    scope.stack_ptr  = scope.stack.as_mut_ptr();
+    scope.memory_ptr = scope.memory.as_mut_ptr();
+
+    context.scopes = vec![Scope::empty(); 8];
+    context.scopes[0]      = scope;
+    context.scope_index    = 0;
+    context.current_scope  = &mut context.scopes[0] as *mut Scope;
+
+    for argument in arguments.iter() {
+        *(*context.current_scope).stack_ptr = *argument;
+        inc_memory_index!(context, 1);
+    }
+
+    /* Execution continues from the next opcode after return */
+    let halt_location = context.opcodes.as_mut_ptr().add(context.halt_location - 1);
+    let result = FunctionReference::create_call_scope(&function, context, base, arguments.len() as u8, true, halt_location);
+
+    context.scheduler.current = task_id;
+    save_task(context);
+    load_task(context, current)?;
+
+    result?;
+    context.scheduler.ready.push_back(task_id);
+    Ok(task_id)
+}
+
+/// Running task waits and the next ready task continues. 'location' is the last opcode of the waiting call.
+///
+/// # Safety
+/// 'location' should point into the compiled opcodes, it is used when the task continues.
+pub unsafe fn wait_task(context: &mut KaramelCompilerContext, location: *mut u8, state: TaskState, assign_to_temp: bool) -> Result<(), KaramelErrorType> {
+    let next = match context.scheduler.ready.pop_front() {
+        Some(next) => next,
+        None => return Err(KaramelErrorType::TaskDeadlock)
+    };
+
+    let current = context.scheduler.current;
+    context.scheduler.tasks[current].state          = state;
+    context.scheduler.tasks[current].assign_to_temp = assign_to_temp;
+    if state == TaskState::Ready {
+        context.scheduler.ready.push_back(current);
+        context.scheduler.tasks[current].resume_value = if assign_to_temp { Some(EMPTY_OBJECT) } else { None };
+    }
+
+    context.opcodes_ptr = location;
+    save_task(context);
+    load_task(context, next)
+}
+
+/// Should running task wait for 'bekle' or 'al', ready queue needs to be checked before changing the state.
+pub fn can_wait(context: &KaramelCompilerContext) -> Result<(), KaramelErrorType> {
+    match context.scheduler.ready.is_empty() {
+        true => Err(KaramelErrorType::TaskDeadlock),
+        false => Ok(())
+    }
+}
+
+/// Called when running task reaches 'Halt'. Returns false when all tasks are finished, main task is loaded back at the end.
+///
+/// # Safety
+/// Running task's scope should be valid, its last stack value is the result of the task.
+pub unsafe fn finish_task(context: &mut KaramelCompilerContext) -> Result<bool, KaramelErrorType> {
+    if context.scheduler.tasks.is_empty() {
+        return Ok(false);
+    }
+
+    let result = match get_memory_index!(context) > 0 {
+        true => *(*context.current_scope).stack_ptr.sub(1),
+        false => EMPTY_OBJECT
+    };
+    end_task(context, Ok(result))
+}
+
+/// Called when an error is not caught in a task other than main task, the error is kept for 'bekle'.
+/// Returned error belongs to the next task, it is raised from its waiting call.
+///
+/// # Safety
+/// Running task's scopes are not used again, context should be executing compiled opcodes.
+pub unsafe fn fail_task(context: &mut KaramelCompilerContext, error: KaramelErrorType) -> Result<bool, KaramelErrorType> {
+    end_task(context, Err(error))
+}
+
+unsafe fn end_task(context: &mut KaramelCompilerContext, result: Result<VmObject, KaramelErrorType>) -> Result<bool, KaramelErrorType> {
+    let current = context.scheduler.current;
+    for waiter in mem::take(&mut context.scheduler.tasks[current].waiters) {
+        context.scheduler.wake(waiter, result.clone());
+    }
+
+    context.scheduler.tasks[current].state  = TaskState::Finished;
+    context.scheduler.tasks[current].result = result;
+
+    save_task(context);
+    if current != MAIN_TASK {
+        context.scheduler.tasks[current].scopes.clear();
+    }
+
+    match context.scheduler.ready.pop_front() {
+        Some(next) => {
+            load_task(context, next)?;
+
+            /* Execution starts from the next opcode, not from the waiting call */
+            context.opcodes_ptr = context.opcodes_ptr.offset(1);
+            Ok(true)
+        },
+        None => match context.scheduler.tasks[MAIN_TASK].state {
+            TaskState::Finished => {
+                load_task(context, MAIN_TASK)?;
+                Ok(false)
+            },
+            _ => Err(KaramelErrorType::TaskDeadlock)
+        }
+    }
+}
@@ -7,6 +7,7 @@ mod tests {
     use crate::karamellib::syntax::SyntaxParser;
     use crate::karamellib::compiler::value::KaramelPrimative;
     use crate::karamellib::compiler::ast::KaramelAstType;
+    use crate::karamellib::error::*;
     use std::rc::Rc;
 
     #[warn(unused_macros)]
@@ -38,7 +39,69 @@ mod tests {
         expression: Rc::new(KaramelAstType::Binary {
             left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("erhan".to_string()))))),
             operator: KaramelOperatorType::Multiplication, 
-            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            line: 0,
+            column: 0
         })
     })));
-}
\ No newline at end of file
+
+    test_compare!(assignment_3, "x, y = y, x", Ok(Rc::new(KaramelAstType::Assignment {
+        variable: Rc::new(KaramelAstType::Tuple(vec![Rc::new(KaramelAstType::Symbol("x".to_string())), Rc::new(KaramelAstType::Symbol("y".to_string()))])),
+        operator: KaramelOperatorType::Assign,
+        expression: Rc::new(KaramelAstType::Tuple(vec![Rc::new(KaramelAstType::Symbol("y".to_string())), Rc::new(KaramelAstType::Symbol("x".to_string()))]))
+    })));
+
+    test_compare!(assignment_4, "[ilk, ikinci] = liste", Ok(Rc::new(KaramelAstType::Assignment {
+        variable: Rc::new(KaramelAstType::List(vec![Rc::new(KaramelAstType::Symbol("ilk".to_string())), Rc::new(KaramelAstType::Symbol("ikinci".to_string()))])),
+        operator: KaramelOperatorType::Assign,
+        expression: Rc::new(KaramelAstType::Symbol("liste".to_string()))
+    })));
+
+    test_compare!(assignment_5, "x, y = 1, 2, 3", Err(KaramelError {
+        error_type: KaramelErrorType::DestructuringCountNotMatching {
+            expected: 2,
+            found: 3
+        },
+        column: 14,
+        line: 0
+    }));
+
+    test_compare!(assignment_6, "x, y += 1", Err(KaramelError {
+        error_type: KaramelErrorType::DestructuringNotValid,
+        column: 9,
+        line: 0
+    }));
+
+    test_compare!(assignment_7, "erhan **= 2", Ok(Rc::new(KaramelAstType::Assignment {
+        variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
+        operator: KaramelOperatorType::AssignPower,
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+    })));
+
+    test_compare!(assignment_8, "erhan <<= 2", Ok(Rc::new(KaramelAstType::Assignment {
+        variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
+        operator: KaramelOperatorType::AssignLeftShift,
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+    })));
+
+    test_compare!(assignment_9, "erhan ^= 2", Ok(Rc::new(KaramelAstType::Assignment {
+        variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
+        operator: KaramelOperatorType::AssignBitwiseXor,
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+    })));
+
+    test_compare!(assignment_10, "erhan: sayı = 2", Ok(Rc::new(KaramelAstType::TypedAssignment {
+        type_name: "sayı".to_string(),
+        assignment: Rc::new(KaramelAstType::Assignment {
+            variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
+            operator: KaramelOperatorType::Assign,
+            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+        })
+    })));
+
+    test_compare!(assignment_11, "erhan tambölüm= 2", Ok(Rc::new(KaramelAstType::Assignment {
+        variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
+        operator: KaramelOperatorType::AssignIntegerDivision,
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+    })));
+}
@@ -31,13 +31,17 @@ mod tests {
     test_compare!(add_subtract_1, "10 + 10", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))), 
         operator: KaramelOperatorType::Addition, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+        line: 0,
+        column: 0
     })));
 
     test_compare!(add_subtract_2, "10 - 10", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))), 
         operator: KaramelOperatorType::Subtraction, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+        line: 0,
+        column: 0
     })));
 
     test_compare!(add_subtract_3, "5 * 2 mod 2 - 10", Ok(Rc::new(KaramelAstType::Binary {
@@ -45,13 +49,19 @@ mod tests {
             left: Rc::new(KaramelAstType::Binary {
                 left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(5.0)))),
                 operator: KaramelOperatorType::Multiplication, 
-                right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+                right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+                line: 0,
+                column: 0
             }),
             operator: KaramelOperatorType::Modulo, 
-            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            line: 0,
+            column: 0
         }), 
         operator: KaramelOperatorType::Subtraction, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+        line: 0,
+        column: 0
     })));
 
     test_compare!(add_subtract_4, "22 + 5 * 2 mod 2", Ok(Rc::new(KaramelAstType::Binary {
@@ -61,63 +71,87 @@ mod tests {
             left: Rc::new(KaramelAstType::Binary {
                 left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(5.0)))),
                 operator: KaramelOperatorType::Multiplication, 
-                right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+                right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+                line: 0,
+                column: 0
             }),
             operator: KaramelOperatorType::Modulo, 
-            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
-        })
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            line: 0,
+            column: 0
+        }),
+        line: 0,
+        column: 0
     })));
 
     test_compare!(add_subtract_5, "11 + 12 + 13", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Binary {
             left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(11.0)))), 
             operator: KaramelOperatorType::Addition, 
-            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(12.0))))
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(12.0)))),
+            line: 0,
+            column: 0
         }), 
         operator: KaramelOperatorType::Addition, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(13.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(13.0)))),
+        line: 0,
+        column: 0
     })));
 
     test_compare!(multiply_divide_1, "10*10", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))), 
         operator: KaramelOperatorType::Multiplication, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+        line: 0,
+        column: 0
     })));
 
     test_compare!(multiply_divide_2, "10*-10", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))), 
         operator: KaramelOperatorType::Multiplication, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(-10.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(-10.0)))),
+        line: 0,
+        column: 0
     })));
 
     test_compare!(multiply_divide_3, "-10*-10", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(-10.0)))), 
         operator: KaramelOperatorType::Multiplication, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(-10.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(-10.0)))),
+        line: 0,
+        column: 0
     })));
 
     test_compare!(multiply_divide_4, "-10/-10", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(-10.0)))), 
         operator: KaramelOperatorType::Division, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(-10.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(-10.0)))),
+        line: 0,
+        column: 0
     })));
 
     test_compare!(multiply_divide_5, "10/10", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))), 
         operator: KaramelOperatorType::Division, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+        line: 0,
+        column: 0
     })));
 
     test_compare!(multiply_divide_6, "doğru * doğru", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Bool(true)))),
         operator: KaramelOperatorType::Multiplication, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Bool(true)))), 
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Bool(true)))),
+        line: 0,
+        column: 0 
     })));
 
     test_compare!(multiply_divide_7, "doğru / doğru", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Bool(true)))),
         operator: KaramelOperatorType::Division, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Bool(true)))), 
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Bool(true)))),
+        line: 0,
+        column: 0 
     })));
 
     test_compare!(multiply_divide_8, "1/", Err(KaramelError {
@@ -134,13 +168,17 @@ mod tests {
     test_compare!(modulo_1, "10 mod 10", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))), 
         operator: KaramelOperatorType::Modulo, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+        line: 0,
+        column: 0
     })));
 
     test_compare!(modulo_2, "10 mod 10", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))), 
         operator: KaramelOperatorType::Modulo, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+        line: 0,
+        column: 0
     })));
 
     test_compare!(modulo_3, "10 mod 5*2", Ok(Rc::new(KaramelAstType::Binary {
@@ -149,17 +187,101 @@ mod tests {
         right: Rc::new(KaramelAstType::Binary {
             left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(5.0)))),
             operator: KaramelOperatorType::Multiplication, 
-            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
-        })
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            line: 0,
+            column: 0
+        }),
+        line: 0,
+        column: 0
     })));
 
     test_compare!(modulo_4, "5*2 mod 2", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Binary {
             left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(5.0)))),
             operator: KaramelOperatorType::Multiplication, 
-            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            line: 0,
+            column: 0
         }),
         operator: KaramelOperatorType::Modulo, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+        line: 0,
+        column: 0
+    })));
+
+    test_compare!(power_1, "2 ** 3 ** 2", Ok(Rc::new(KaramelAstType::Binary {
+        left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+        operator: KaramelOperatorType::Power, 
+        right: Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0)))),
+            operator: KaramelOperatorType::Power, 
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            line: 0,
+            column: 0
+        }),
+        line: 0,
+        column: 0
+    })));
+
+    test_compare!(power_2, "3 * 2 ** 2", Ok(Rc::new(KaramelAstType::Binary {
+        left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0)))),
+        operator: KaramelOperatorType::Multiplication, 
+        right: Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            operator: KaramelOperatorType::Power, 
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            line: 0,
+            column: 0
+        }),
+        line: 0,
+        column: 0
+    })));
+
+    test_compare!(integer_division_1, "7 tambölüm 2 * 3", Ok(Rc::new(KaramelAstType::Binary {
+        left: Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(7.0)))),
+            operator: KaramelOperatorType::IntegerDivision, 
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            line: 0,
+            column: 0
+        }),
+        operator: KaramelOperatorType::Multiplication, 
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0)))),
+        line: 0,
+        column: 0
+    })));
+
+    test_compare!(bitwise_1, "1 | 2 ^ 3 & 4", Ok(Rc::new(KaramelAstType::Binary {
+        left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+        operator: KaramelOperatorType::BitwiseOr, 
+        right: Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            operator: KaramelOperatorType::BitwiseXor, 
+            right: Rc::new(KaramelAstType::Binary {
+                left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0)))),
+                operator: KaramelOperatorType::BitwiseAnd, 
+                right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(4.0)))),
+                line: 0,
+                column: 0
+            }),
+            line: 0,
+            column: 0
+        }),
+        line: 0,
+        column: 0
+    })));
+
+    test_compare!(shift_1, "1 + 2 << 3", Ok(Rc::new(KaramelAstType::Binary {
+        left: Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+            operator: KaramelOperatorType::Addition, 
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            line: 0,
+            column: 0
+        }),
+        operator: KaramelOperatorType::LeftShift, 
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0)))),
+        line: 0,
+        column: 0
     })));
-}
\ No newline at end of file
+}
@@ -0,0 +1,91 @@
+extern crate karamellib;
+
+#[cfg(test)]
+mod tests {
+    use karamellib::error::{KaramelError, KaramelErrorType};
+
+    use crate::karamellib::parser::*;
+    use crate::karamellib::types::*;
+    use crate::karamellib::syntax::*;
+    use crate::karamellib::compiler::value::KaramelPrimative;
+    use crate::karamellib::compiler::ast::{KaramelAstType, KaramelFunctionType};
+    use std::rc::Rc;
+
+    #[warn(unused_macros)]
+    macro_rules! test_compare {
+        ($name:ident, $text:expr, $result:expr) => {
+            #[test]
+            fn $name () {
+                let mut parser = Parser::new($text);
+                match parser.parse() {
+                    Err(_) => assert_eq!(true, false),
+                    _ => ()
+                };
+
+                let syntax = SyntaxParser::new(parser.tokens().to_vec());
+                assert_eq!(syntax.parse(), $result);
+            }
+        };
+    }
+
+    test_compare!(class_def_1, r#"
+sınıf Nokta:
+    x = 1"#, Ok(Rc::new(KaramelAstType::ClassDefination {
+        name: "Nokta".to_string(),
+        fields: [Rc::new(KaramelAstType::Assignment {
+            variable: Rc::new(KaramelAstType::Symbol("x".to_string())),
+            operator: KaramelOperatorType::Assign,
+            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+        })].to_vec(),
+        methods: Vec::new()
+    })));
+
+    test_compare!(class_def_2, r#"
+sinif Nokta:
+    x = 1
+    fonk al():
+        döndür bu.x"#, Ok(Rc::new(KaramelAstType::ClassDefination {
+        name: "Nokta".to_string(),
+        fields: [Rc::new(KaramelAstType::Assignment {
+            variable: Rc::new(KaramelAstType::Symbol("x".to_string())),
+            operator: KaramelOperatorType::Assign,
+            expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+        })].to_vec(),
+        methods: [Rc::new(KaramelAstType::FunctionDefination {
+            name: "al".to_string(),
+            arguments: Vec::new(),
+            defaults: Vec::new(),
+            variadic: false,
+            types: KaramelFunctionType::default(),
+            body: Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::Indexer {
+                body: Rc::new(KaramelAstType::Symbol("bu".to_string())),
+                indexer: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("x".to_string())))))
+            })))
+        })].to_vec()
+    })));
+
+    test_compare!(class_def_3, r#"
+sınıf Nokta
+    x = 1"#, Err(KaramelError {
+        error_type: KaramelErrorType::ColonMarkMissing,
+        column: 11,
+        line: 1
+    }));
+
+    test_compare!(class_def_4, r#"
+sınıf Nokta:
+"#, Err(KaramelError {
+        error_type: KaramelErrorType::ClassConditionBodyNotFound,
+        column: 12,
+        line: 1
+    }));
+
+    test_compare!(class_def_5, r#"
+sınıf Nokta:
+    döngü doğru:
+        kır"#, Err(KaramelError {
+        error_type: KaramelErrorType::ClassDefinationNotValid,
+        column: 11,
+        line: 3
+    }));
+}
@@ -0,0 +1,165 @@
+extern crate karamellib;
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::cmp::Ordering;
+    use std::rc::Rc;
+
+    use crate::karamellib::parser::*;
+    use crate::karamellib::compiler::*;
+    use crate::karamellib::vm::*;
+    use crate::karamellib::syntax::*;
+    use crate::karamellib::types::VmObject;
+    use crate::karamellib::buildin::{Class, ClassConfig, ClassProperty};
+    use crate::karamellib::compiler::function::{FunctionFlag, FunctionParameter, FunctionReference, IndexerGetCall, IndexerSetCall, NativeCall, NativeCallResult};
+
+    /* Host defined money type */
+    struct Para {
+        amount: Cell<f64>
+    }
+
+    impl Para {
+        fn new(amount: f64) -> Para {
+            Para { amount: Cell::new(amount) }
+        }
+
+        fn object(amount: f64) -> VmObject {
+            VmObject::from(Rc::new(KaramelPrimative::Class(Rc::new(Para::new(amount)))))
+        }
+
+        fn amount(object: VmObject) -> Option<f64> {
+            match &*object.deref() {
+                KaramelPrimative::Class(class) => match class.get_element(None, Rc::new("miktar".to_string())) {
+                    Some(ClassProperty::Field(field)) => match &*field {
+                        KaramelPrimative::Number(number) => Some(*number),
+                        _ => None
+                    },
+                    _ => None
+                },
+                KaramelPrimative::Number(number) => Some(*number),
+                _ => None
+            }
+        }
+
+        fn init(parameter: FunctionParameter) -> NativeCallResult {
+            let source = parameter.source().unwrap();
+            if let KaramelPrimative::Class(class) = &*source.deref() {
+                class.set_element(Some(source), Rc::new("miktar".to_string()), *parameter.iter().next().unwrap());
+            }
+            Ok(source)
+        }
+    }
+
+    impl GetType for Para {
+        fn get_type(&self) -> String {
+            "Para".to_string()
+        }
+    }
+
+    impl Class for Para {
+        fn set_class_config(&mut self, _: ClassConfig) {}
+        fn get_class_name(&self) -> String { "Para".to_string() }
+        fn has_element(&self, _: Option<VmObject>, _: Rc<String>) -> bool { true }
+        fn get_element(&self, _: Option<VmObject>, field: Rc<String>) -> Option<ClassProperty> {
+            match &field[..] {
+                "miktar" => Some(ClassProperty::Field(Rc::new(KaramelPrimative::Number(self.amount.get())))),
+                "başlat" => Some(ClassProperty::Function(FunctionReference::buildin_function(Para::init as NativeCall, "başlat".to_string(), FunctionFlag::IN_CLASS))),
+                _ => None
+            }
+        }
+        fn property_count(&self) -> usize { 0 }
+        fn properties(&self) -> std::collections::hash_map::Iter<'_, String, ClassProperty> { unimplemented!() }
+        fn add_method(&mut self, _: &str, _: NativeCall, _: FunctionFlag) {}
+        fn add_property(&mut self, _: &str, _: Rc<KaramelPrimative>) {}
+        fn set_getter(&mut self, _: IndexerGetCall) {}
+        fn get_getter(&self) -> Option<IndexerGetCall> { None }
+        fn set_setter(&mut self, _: IndexerSetCall) {}
+        fn get_setter(&self) -> Option<IndexerSetCall> { None }
+
+        fn set_element(&self, _: Option<VmObject>, _: Rc<String>, value: VmObject) -> bool {
+            match value.as_number() {
+                Some(number) => {
+                    self.amount.set(number);
+                    true
+                },
+                None => false
+            }
+        }
+
+        fn create_instance(&self) -> Option<Rc<dyn Class>> { Some(Rc::new(Para::new(0.0))) }
+
+        fn addition(&self, left: VmObject, right: VmObject) -> Option<NativeCallResult> {
+            Some(Ok(Para::object(Para::amount(left)? + Para::amount(right)?)))
+        }
+
+        fn subtraction(&self, left: VmObject, right: VmObject) -> Option<NativeCallResult> {
+            Some(Ok(Para::object(Para::amount(left)? - Para::amount(right)?)))
+        }
+
+        fn multiply(&self, left: VmObject, right: VmObject) -> Option<NativeCallResult> {
+            Some(Ok(Para::object(Para::amount(left)? * Para::amount(right)?)))
+        }
+
+        fn division(&self, left: VmObject, right: VmObject) -> Option<NativeCallResult> {
+            Some(Ok(Para::object(Para::amount(left)? / Para::amount(right)?)))
+        }
+
+        fn modulo(&self, left: VmObject, right: VmObject) -> Option<NativeCallResult> {
+            Some(Ok(Para::object(Para::amount(left)? % Para::amount(right)?)))
+        }
+
+        fn compare(&self, left: VmObject, right: VmObject) -> Option<Ordering> {
+            Para::amount(left)?.partial_cmp(&Para::amount(right)?)
+        }
+
+        fn equals(&self, left: &KaramelPrimative, right: &KaramelPrimative) -> Option<bool> {
+            match (left, right) {
+                (KaramelPrimative::Class(l_value), KaramelPrimative::Class(r_value)) => Some(l_value.get_type() == r_value.get_type() && Para::amount(VmObject::from(Rc::new(left.clone()))) == Para::amount(VmObject::from(Rc::new(right.clone())))),
+                _ => Some(false)
+            }
+        }
+
+        fn to_text(&self) -> Option<String> {
+            Some(format!("{} TL", self.amount.get()))
+        }
+    }
+
+    #[warn(unused_macros)]
+    macro_rules! test_success {
+        ($name:ident, $text:expr) => {
+            #[test]
+            fn $name () {
+                let mut parser = Parser::new($text);
+                assert!(parser.parse().is_ok());
+
+                let syntax = SyntaxParser::new(parser.tokens().to_vec());
+                let syntax_result = syntax.parse();
+                assert!(syntax_result.is_ok());
+
+                let opcode_compiler  = InterpreterCompiler {};
+                let mut context: KaramelCompilerContext = KaramelCompilerContext::new();
+                context.add_class(Rc::new(Para::new(0.0)));
+
+                assert!(opcode_compiler.compile(syntax_result.unwrap(), &mut context).is_ok());
+                let result = unsafe { interpreter::run_vm(&mut context) };
+                assert!(result.is_ok(), "{:?}", result.err());
+            }
+        };
+    }
+
+    test_success!(class_operator_1, r#"hataayıklama::doğrula(Para(10) + Para(5), Para(15))"#);
+    test_success!(class_operator_2, r#"hataayıklama::doğrula(Para(10) - Para(4), Para(6))"#);
+    test_success!(class_operator_3, r#"hataayıklama::doğrula(Para(10) * 3, Para(30))"#);
+    test_success!(class_operator_4, r#"hataayıklama::doğrula(3 * Para(10), Para(30))"#);
+    test_success!(class_operator_5, r#"hataayıklama::doğrula(Para(10) / 4, Para(2.5))"#);
+    test_success!(class_operator_6, r#"hataayıklama::doğrula(Para(10) mod 4, Para(2))"#);
+    test_success!(class_operator_7, r#"hataayıklama::doğrula(Para(1) < Para(2), doğru)"#);
+    test_success!(class_operator_8, r#"hataayıklama::doğrula(Para(2) <= Para(2), doğru)"#);
+    test_success!(class_operator_9, r#"hataayıklama::doğrula(Para(1) > Para(2), yanlış)"#);
+    test_success!(class_operator_10, r#"hataayıklama::doğrula(Para(3) >= 2, doğru)"#);
+    test_success!(class_operator_11, r#"hataayıklama::doğrula(Para(3) == Para(3), doğru)"#);
+    test_success!(class_operator_12, r#"hataayıklama::doğrula(Para(3) != Para(4), doğru)"#);
+    test_success!(class_operator_13, r#"hataayıklama::doğrula('Toplam: ' + Para(3), 'Toplam: 3 TL')"#);
+    test_success!(class_operator_14, r#"hataayıklama::doğrula(Para(3) + ' var', '3 TL var')"#);
+}
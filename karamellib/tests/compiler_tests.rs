@@ -5,6 +5,7 @@ mod tests {
     use crate::karamellib::parser::*;
     use crate::karamellib::syntax::*;
     use crate::karamellib::compiler::*;
+    use crate::karamellib::error::{KaramelError, KaramelErrorType};
     use std::rc::Rc;
 
     #[warn(unused_macros)]
@@ -50,4 +51,27 @@ mod tests {
     memory_check!(memory_5, "'erhan' + 'barış'", vec![KaramelPrimative::Text(Rc::new("erhan".to_string())), KaramelPrimative::Text(Rc::new("barış".to_string()))]);
     memory_check!(memory_6, "'erhan' + '-' + 'barış'", vec![KaramelPrimative::Text(Rc::new("erhan".to_string())), KaramelPrimative::Text(Rc::new("-".to_string())), KaramelPrimative::Text(Rc::new("barış".to_string()))]);
     memory_check!(memory_7, "doğru == yanlış", vec![KaramelPrimative::Bool(true), KaramelPrimative::Bool(false)]);
-}
\ No newline at end of file
+
+    fn compile_warnings(text: &str) -> Vec<KaramelError> {
+        let mut parser = Parser::new(text);
+        assert!(parser.parse().is_ok());
+
+        let syntax = SyntaxParser::new(parser.tokens().to_vec());
+        let opcode_compiler = InterpreterCompiler {};
+        let mut context = KaramelCompilerContext::new();
+        assert!(opcode_compiler.compile(syntax.parse().unwrap(), &mut context).is_ok());
+        context.warnings
+    }
+
+    #[test]
+    fn match_warning_1() {
+        assert_eq!(compile_warnings("eşleştir 1:\n    1 ise: a = 1"), vec![KaramelError::new(0, 1, KaramelErrorType::MatchCatchAllMissing)]);
+        assert_eq!(compile_warnings("a = 1\na == 1 ise:\n    eşleştir a:\n        1 ise: a = 2"), vec![KaramelError::new(2, 5, KaramelErrorType::MatchCatchAllMissing)]);
+    }
+
+    #[test]
+    fn match_warning_2() {
+        assert_eq!(compile_warnings("eşleştir 1:\n    1 ise: a = 1\n    yoksa: a = 2"), Vec::new());
+        assert_eq!(compile_warnings("eşleştir 1:\n    1 ise: a = 1\n    b ise: a = b"), Vec::new());
+    }
+}
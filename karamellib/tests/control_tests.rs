@@ -7,6 +7,7 @@ mod tests {
     use crate::karamellib::syntax::*;
     use crate::karamellib::compiler::value::KaramelPrimative;
     use crate::karamellib::compiler::ast::KaramelAstType;
+    use crate::karamellib::error::*;
     use std::rc::Rc;
 
     #[warn(unused_macros)]
@@ -42,7 +43,9 @@ mod tests {
         left: Rc::new(KaramelAstType::Binary {
             left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))), 
             operator: KaramelOperatorType::Addition, 
-            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            line: 0,
+            column: 0
         }), 
         operator: KaramelOperatorType::NotEqual, 
         right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0))))
@@ -54,7 +57,9 @@ mod tests {
         right: Rc::new(KaramelAstType::Binary {
             left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))), 
             operator: KaramelOperatorType::Addition, 
-            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            line: 0,
+            column: 0
         })
     })));
     
@@ -69,4 +74,60 @@ mod tests {
         operator: KaramelOperatorType::Or, 
         right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0))))
     })));
-}
\ No newline at end of file
+
+    test_compare!(in_1, "1 içinde liste", Ok(Rc::new(KaramelAstType::Control {
+        left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))), 
+        operator: KaramelOperatorType::In, 
+        right: Rc::new(KaramelAstType::Symbol("liste".to_string()))
+    })));
+
+    test_compare!(in_2, "1 içinde değil liste", Ok(Rc::new(KaramelAstType::Control {
+        left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))), 
+        operator: KaramelOperatorType::NotIn, 
+        right: Rc::new(KaramelAstType::Symbol("liste".to_string()))
+    })));
+
+    test_compare!(in_3, "1 icinde degil liste ve doğru", Ok(Rc::new(KaramelAstType::Control {
+        left: Rc::new(KaramelAstType::Control {
+            left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))), 
+            operator: KaramelOperatorType::NotIn, 
+            right: Rc::new(KaramelAstType::Symbol("liste".to_string()))
+        }), 
+        operator: KaramelOperatorType::And, 
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Bool(true))))
+    })));
+
+    test_compare!(conditional_1, "a ? 1 : 2", Ok(Rc::new(KaramelAstType::Conditional {
+        condition: Rc::new(KaramelAstType::Symbol("a".to_string())),
+        true_expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+        false_expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+    })));
+
+    test_compare!(conditional_2, "1 eğer a yoksa 2", Ok(Rc::new(KaramelAstType::Conditional {
+        condition: Rc::new(KaramelAstType::Symbol("a".to_string())),
+        true_expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+        false_expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+    })));
+
+    test_compare!(conditional_3, "a ? 1 : b ? 2 : 3", Ok(Rc::new(KaramelAstType::Conditional {
+        condition: Rc::new(KaramelAstType::Symbol("a".to_string())),
+        true_expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+        false_expression: Rc::new(KaramelAstType::Conditional {
+            condition: Rc::new(KaramelAstType::Symbol("b".to_string())),
+            true_expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            false_expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0))))
+        })
+    })));
+
+    test_compare!(conditional_4, "a ? 1", Err(KaramelError {
+        error_type: KaramelErrorType::ColonMarkMissing,
+        column: 5,
+        line: 0
+    }));
+
+    test_compare!(conditional_5, "1 eğer a", Err(KaramelError {
+        error_type: KaramelErrorType::ConditionalElseMissing,
+        column: 8,
+        line: 0
+    }));
+}
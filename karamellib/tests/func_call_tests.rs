@@ -96,7 +96,9 @@ mod tests {
             func_name_expression: Rc::new(KaramelAstType::Symbol("data2".to_string())),
             arguments: Vec::new(),
             assign_to_temp: Cell::new(true)
-        })
+        }),
+        line: 0,
+        column: 0
     })));
     test_compare!(func_call_11, "data1() > data2()", Ok(Rc::new(KaramelAstType::Control {
         left: Rc::new(KaramelAstType::FuncCall {
@@ -112,4 +114,26 @@ mod tests {
         })
     })));
     test_compare!(func_call_12, "gç::satıryaz", Ok(Rc::new(KaramelAstType::ModulePath(["gç".to_string(), "satıryaz".to_string()].to_vec()))));
+
+    test_compare!(func_call_13, "print(1, son = 'erhan')", Ok(Rc::new(KaramelAstType::FuncCall {
+        func_name_expression: Rc::new(KaramelAstType::Symbol("print".to_string())),
+        arguments: [Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+                    Rc::new(KaramelAstType::KeywordArgument {
+                        name: "son".to_string(),
+                        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("erhan".to_string())))))
+                    })].to_vec(),
+        assign_to_temp: Cell::new(false)
+    })));
+
+    test_compare!(func_call_14, "print(son=1, 2)", Err(KaramelError {
+        error_type: KaramelErrorType::PositionalArgumentAfterKeyword,
+        column: 15,
+        line: 0
+    }));
+
+    test_compare!(func_call_15, "print(son=)", Err(KaramelError {
+        error_type: KaramelErrorType::SyntaxError,
+        column: 11,
+        line: 0
+    }));
 }
\ No newline at end of file
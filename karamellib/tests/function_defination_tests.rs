@@ -8,7 +8,7 @@ mod tests {
     use crate::karamellib::types::*;
     use crate::karamellib::syntax::*;
     use crate::karamellib::compiler::value::KaramelPrimative;
-    use crate::karamellib::compiler::ast::KaramelAstType;
+    use crate::karamellib::compiler::ast::{KaramelAstType, KaramelFunctionType};
     use std::rc::Rc;
 
     #[warn(unused_macros)]
@@ -33,6 +33,9 @@ fonk test():
     erhan=123"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
         name: "test".to_string(),
         arguments: Vec::new(),
+        defaults: Vec::new(),
+        variadic: false,
+        types: KaramelFunctionType::default(),
         body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
             variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
             operator: KaramelOperatorType::Assign,
@@ -45,6 +48,9 @@ fonk test(a):
     erhan=123"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
         name: "test".to_string(),
         arguments: ["a".to_string()].to_vec(),
+        defaults: Vec::new(),
+        variadic: false,
+        types: KaramelFunctionType { arguments: vec![None; 1], result: None },
         body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
             variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
             operator: KaramelOperatorType::Assign,
@@ -57,6 +63,9 @@ fonk test(a, b    ,   c):
     erhan=123"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
         name: "test".to_string(),
         arguments: ["a".to_string(), "b".to_string(), "c".to_string()].to_vec(),
+        defaults: Vec::new(),
+        variadic: false,
+        types: KaramelFunctionType { arguments: vec![None; 3], result: None },
         body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
             variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
             operator: KaramelOperatorType::Assign,
@@ -69,6 +78,9 @@ fonk test:
     erhan=123"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
             name: "test".to_string(),
             arguments: Vec::new(),
+            defaults: Vec::new(),
+            variadic: false,
+            types: KaramelFunctionType::default(),
             body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
                 variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
                 operator: KaramelOperatorType::Assign,
@@ -84,6 +96,9 @@ fonk test   :
         erhan=123"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
                 name: "test".to_string(),
                 arguments: Vec::new(),
+                defaults: Vec::new(),
+                variadic: false,
+                types: KaramelFunctionType::default(),
                 body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
                     variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
                     operator: KaramelOperatorType::Assign,
@@ -108,7 +123,7 @@ fonk test(:
     test_compare!(func_def_9, r#"
 fonk test(a:
     erhan=123"#, Err(KaramelError {
-        error_type: KaramelErrorType::RightParanthesesMissing,
+        error_type: KaramelErrorType::TypeAnnotationNotValid,
         column: 12,
         line: 1
     }));
@@ -157,6 +172,9 @@ fonk test():
     döndür erhan"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
     name: "test".to_string(),
     arguments: Vec::new(),
+    defaults: Vec::new(),
+    variadic: false,
+    types: KaramelFunctionType::default(),
     body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
@@ -170,6 +188,9 @@ fonk test():
     döndür"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
     name: "test".to_string(),
     arguments: Vec::new(),
+    defaults: Vec::new(),
+    variadic: false,
+    types: KaramelFunctionType::default(),
     body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
         operator: KaramelOperatorType::Assign,
@@ -177,4 +198,153 @@ fonk test():
     }),
     Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec()))
 })));
-}
\ No newline at end of file
+test_compare!(func_def_17, r#"a = fonk(x): x * 2"#, Ok(Rc::new(KaramelAstType::Assignment {
+    variable: Rc::new(KaramelAstType::Symbol("a".to_string())),
+    operator: KaramelOperatorType::Assign,
+    expression: Rc::new(KaramelAstType::Lambda(Rc::new(KaramelAstType::FunctionDefination {
+        name: "isimsiz#4".to_string(),
+        arguments: ["x".to_string()].to_vec(),
+        defaults: Vec::new(),
+        variadic: false,
+        types: KaramelFunctionType { arguments: vec![None; 1], result: None },
+        body: Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Symbol("x".to_string())),
+            operator: KaramelOperatorType::Multiplication,
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0)))),
+            line: 0,
+            column: 0
+        })))
+    })))
+})));
+test_compare!(func_def_18, r#"a = fonk(): 1"#, Ok(Rc::new(KaramelAstType::Assignment {
+    variable: Rc::new(KaramelAstType::Symbol("a".to_string())),
+    operator: KaramelOperatorType::Assign,
+    expression: Rc::new(KaramelAstType::Lambda(Rc::new(KaramelAstType::FunctionDefination {
+        name: "isimsiz#4".to_string(),
+        arguments: Vec::new(),
+        defaults: Vec::new(),
+        variadic: false,
+        types: KaramelFunctionType::default(),
+        body: Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))))
+    })))
+})));
+test_compare!(func_def_19, r#"a = fonk(x):"#, Err(KaramelError {
+    error_type: KaramelErrorType::FunctionConditionBodyNotFound,
+    column: 12,
+    line: 0
+}));
+test_compare!(func_def_20, r#"a = fonk x: x"#, Err(KaramelError {
+    error_type: KaramelErrorType::FunctionNameNotDefined,
+    column: 10,
+    line: 0
+}));
+test_compare!(func_def_21, r#"
+fonk test(a, b=1, *c):
+    döndür a"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
+    name: "test".to_string(),
+    arguments: ["a".to_string(), "b".to_string(), "c".to_string()].to_vec(),
+    defaults: [Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))].to_vec(),
+    variadic: true,
+    types: KaramelFunctionType { arguments: vec![None; 3], result: None },
+    body: Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::Symbol("a".to_string()))))
+})));
+test_compare!(func_def_22, r#"a = fonk(x, y=x): x * y"#, Ok(Rc::new(KaramelAstType::Assignment {
+    variable: Rc::new(KaramelAstType::Symbol("a".to_string())),
+    operator: KaramelOperatorType::Assign,
+    expression: Rc::new(KaramelAstType::Lambda(Rc::new(KaramelAstType::FunctionDefination {
+        name: "isimsiz#4".to_string(),
+        arguments: ["x".to_string(), "y".to_string()].to_vec(),
+        defaults: [Rc::new(KaramelAstType::Symbol("x".to_string()))].to_vec(),
+        variadic: false,
+        types: KaramelFunctionType { arguments: vec![None; 2], result: None },
+        body: Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::Binary {
+            left: Rc::new(KaramelAstType::Symbol("x".to_string())),
+            operator: KaramelOperatorType::Multiplication,
+            right: Rc::new(KaramelAstType::Symbol("y".to_string())),
+            line: 0,
+            column: 0
+        })))
+    })))
+})));
+test_compare!(func_def_23, r#"
+fonk test(a=1, b):
+    döndür a"#, Err(KaramelError {
+    error_type: KaramelErrorType::ArgumentDefinationNotValid,
+    column: 17,
+    line: 1
+}));
+test_compare!(func_def_24, r#"
+fonk test(*a, b):
+    döndür a"#, Err(KaramelError {
+    error_type: KaramelErrorType::ArgumentDefinationNotValid,
+    column: 15,
+    line: 1
+}));
+test_compare!(func_def_25, r#"
+fonk test(a, a):
+    döndür a"#, Err(KaramelError {
+    error_type: KaramelErrorType::ArgumentDefinationNotValid,
+    column: 15,
+    line: 1
+}));
+
+test_compare!(func_def_26, r#"
+fonk test():
+    erhan=123
+    döndür erhan, 1"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
+    name: "test".to_string(),
+    arguments: Vec::new(),
+    defaults: Vec::new(),
+    variadic: false,
+    types: KaramelFunctionType::default(),
+    body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
+        variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
+        operator: KaramelOperatorType::Assign,
+        expression: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+    }),
+    Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::Tuple([
+        Rc::new(KaramelAstType::Symbol("erhan".to_string())),
+        Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))].to_vec()))))].to_vec()))
+})));
+test_compare!(func_def_27, r#"
+fonk test():
+    üret 1"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
+    name: "test".to_string(),
+    arguments: Vec::new(),
+    defaults: Vec::new(),
+    variadic: false,
+    types: KaramelFunctionType::default(),
+    body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Yield(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))))),
+    Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::None)))].to_vec()))
+})));
+test_compare!(func_def_28, r#"
+üret 1
+"#, Err(KaramelError {
+    error_type: KaramelErrorType::YieldMustBeUsedInFunction,
+    column: 4,
+    line: 1
+}));
+test_compare!(func_def_29, r#"
+fonk topla(a: sayı, b=1) -> sayı:
+    döndür a + b"#, Ok(Rc::new(KaramelAstType::FunctionDefination {
+    name: "topla".to_string(),
+    arguments: ["a".to_string(), "b".to_string()].to_vec(),
+    defaults: [Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))].to_vec(),
+    variadic: false,
+    types: KaramelFunctionType { arguments: [Some("sayı".to_string()), None].to_vec(), result: Some("sayı".to_string()) },
+    body: Rc::new(KaramelAstType::Return(Rc::new(KaramelAstType::Binary {
+        left: Rc::new(KaramelAstType::Symbol("a".to_string())),
+        operator: KaramelOperatorType::Addition,
+        right: Rc::new(KaramelAstType::Symbol("b".to_string())),
+        line: 0,
+        column: 0
+    })))
+})));
+test_compare!(func_def_30, r#"
+fonk test() -> :
+    döndür 1"#, Err(KaramelError {
+    error_type: KaramelErrorType::TypeAnnotationNotValid,
+    column: 16,
+    line: 1
+}));
+}
@@ -37,7 +37,9 @@ veya:
     condition: Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0)))), 
         operator: KaramelOperatorType::Multiplication, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        line: 0,
+        column: 0
     }),
     body: Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
@@ -57,7 +59,9 @@ veya:
     condition: Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0)))), 
         operator: KaramelOperatorType::Multiplication, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        line: 0,
+        column: 0
     }),
     body: Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
@@ -74,7 +78,9 @@ veya:
     condition: Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0)))), 
         operator: KaramelOperatorType::Multiplication, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        line: 0,
+        column: 0
     }),
     body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
@@ -100,7 +106,9 @@ veya :
     condition: Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0)))), 
         operator: KaramelOperatorType::Multiplication, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        line: 0,
+        column: 0
     }),
     body: Rc::new(KaramelAstType::Block([Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
@@ -137,7 +145,9 @@ veya:
     condition: Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0)))), 
         operator: KaramelOperatorType::Multiplication, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        line: 0,
+        column: 0
     }),
     body: Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
@@ -153,7 +163,9 @@ veya:
         left: Rc::new(KaramelAstType::Binary {
             left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0)))),
             operator: KaramelOperatorType::Multiplication,
-            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+            line: 0,
+            column: 0
         }),
         operator: KaramelOperatorType::GreaterThan,
         right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10000000.0)))),
@@ -176,7 +188,9 @@ veya:
     condition: Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0)))), 
         operator: KaramelOperatorType::Multiplication, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        line: 0,
+        column: 0
     }),
     body: Rc::new(KaramelAstType::Assignment {
         variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
@@ -192,7 +206,9 @@ veya:
         left: Rc::new(KaramelAstType::Binary {
             left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0)))),
             operator: KaramelOperatorType::Multiplication,
-            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+            line: 0,
+            column: 0
         }),
         operator: KaramelOperatorType::GreaterThan,
         right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10000000.0)))),
@@ -205,7 +221,9 @@ veya:
         left: Rc::new(KaramelAstType::Binary {
             left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0)))),
             operator: KaramelOperatorType::Multiplication,
-            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+            line: 0,
+            column: 0
         }),
         operator: KaramelOperatorType::LessThan,
         right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10000000.0)))),
@@ -256,7 +274,9 @@ veya 1024 * 123 > 10_000_000 ise:
 condition: Rc::new(KaramelAstType::Binary {
     left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0)))), 
     operator: KaramelOperatorType::Multiplication, 
-    right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+    right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+    line: 0,
+    column: 0
 }),
 body: Rc::new(KaramelAstType::Assignment {
     variable: Rc::new(KaramelAstType::Symbol("erhan".to_string())),
@@ -268,7 +288,9 @@ else_if: [Rc::new(KaramelIfStatementElseItem::new(Rc::new(KaramelAstType::Contro
     left: Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0)))),
         operator: KaramelOperatorType::Multiplication,
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0)))),
+        line: 0,
+        column: 0
     }),
     operator: KaramelOperatorType::GreaterThan,
     right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10000000.0)))),
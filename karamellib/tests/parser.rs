@@ -4,6 +4,8 @@ extern crate karamellib;
 mod tests {
     use crate::karamellib::parser::*;
     use crate::karamellib::types::*;
+    use crate::karamellib::compiler::bigint::BigInt;
+    use std::rc::Rc;
 
     #[warn(unused_macros)]
     macro_rules! test_number {
@@ -256,6 +258,8 @@ mod tests {
     test_number!(integer_6, Integer, "1_234_567", 1234567);
     test_number!(integer_7, Integer, "1_234_5_6_7", 1234567);
     test_number!(integer_8, Integer, "1_234_5_6_7_", 1234567);
+    test_number!(big_integer_1, BigInteger, "9223372036854775808", Rc::new(BigInt::from_i64(i64::MAX).add(&BigInt::from_i64(1))));
+    test_number!(big_integer_2, BigInteger, "100_000_000_000_000_000_000", Rc::new(BigInt::from_f64(1e20).unwrap()));
     parse_failed!(integer_9, "1024erhan");
 
     test_number!(hex_1, Integer, "0x12", 18);
@@ -295,4 +299,21 @@ mod tests {
 
     test_keyword!(keyword_2, "doğru", KaramelKeywordType::True);
     test_keyword!(keyword_4, "yanlış", KaramelKeywordType::False);
-}
\ No newline at end of file
+    test_keyword!(keyword_5, "her", KaramelKeywordType::Each);
+
+    test_number!(suffix_1, Integer, "10'a", 10);
+    test_number!(suffix_2, Integer, "1'den", 1);
+
+    #[test]
+    fn suffix_3 () {
+        let mut parser = Parser::new("yaz'dan'");
+        assert!(parser.parse().is_ok());
+        let tokens = parser.tokens();
+
+        assert_eq!(2, tokens.len());
+        match &tokens[1].token_type {
+            KaramelTokenType::Text(text) => assert_eq!(**text, "dan".to_string()),
+            _ => assert_eq!(true, false)
+        }
+    }
+}
@@ -6,6 +6,7 @@ mod tests {
     use crate::karamellib::types::*;
     use crate::karamellib::syntax::*;
     use crate::karamellib::compiler::value::KaramelPrimative;
+    use crate::karamellib::compiler::bigint::BigInt;
     use crate::karamellib::compiler::ast::*;
     use crate::karamellib::error::*;
     use std::rc::Rc;
@@ -35,11 +36,13 @@ mod tests {
     test_success!(integer_1, "1024", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024.0))))));
     test_success!(integer_2, "1024000", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1024000.0))))));
     test_success!(integer_3, "123", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(123.0))))));
-    test_success!(integer_4, "9223372036854775807", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(9223372036854775807.0))))));
+    test_success!(integer_4, "9223372036854775807", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::BigInt(Rc::new(BigInt::from_i64(9223372036854775807))))))));
     test_success!(integer_5, "0999999", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(999999.0))))));
     test_success!(integer_6, "1_234_567", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1234567.0))))));
     test_success!(integer_7, "1_234_5_6_7", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1234567.0))))));
     test_success!(integer_8, "1_234_5_6_7_", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1234567.0))))));
+    test_success!(integer_9, "9007199254740991", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(9007199254740991.0))))));
+    test_success!(integer_10, "-18446744073709551616", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::BigInt(Rc::new(BigInt::from_i64(-4294967296).mul(&BigInt::from_i64(4294967296)))))))));
 
     test_success!(hex_1, "0x12", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(18.0))))));
     test_success!(hex_2, "0xffffff", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(16777215.0))))));
@@ -108,6 +111,15 @@ mod tests {
     })].to_vec()))));
     
 
+    test_success!(dict_5, "{1:'bir', doğru:2}", Ok(Rc::new(KaramelAstType::Dict([Rc::new(KaramelDictItem {
+        key: Rc::new(KaramelPrimative::Number(1.0)),
+        value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("bir".to_string())))))
+    }),
+    Rc::new(KaramelDictItem {
+        key: Rc::new(KaramelPrimative::Bool(true)),
+        value: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(2.0))))
+    })].to_vec()))));
+
     test_success!(list_1, "[]", Ok(Rc::new(KaramelAstType::List(Vec::new()))));
     test_success!(list_2, "[1]", Ok(Rc::new(KaramelAstType::List([Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))].to_vec()))));
     test_success!(list_3, "[doğru]", Ok(Rc::new(KaramelAstType::List([Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Bool(true))))].to_vec()))));
@@ -122,6 +134,15 @@ mod tests {
     }));
     test_success!(list_8, "[data]", Ok(Rc::new(KaramelAstType::List([Rc::new(KaramelAstType::Symbol("data".to_string()))].to_vec()))));
 
+    test_success!(set_1, "{1}", Ok(Rc::new(KaramelAstType::Set([Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))].to_vec()))));
+    test_success!(set_2, "{1, 'a', doğru}", Ok(Rc::new(KaramelAstType::Set([Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))), Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Text(Rc::new("a".to_string()))))), Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Bool(true))))].to_vec()))));
+    test_success!(set_3, "{data}", Ok(Rc::new(KaramelAstType::Set([Rc::new(KaramelAstType::Symbol("data".to_string()))].to_vec()))));
+    test_success!(set_4, "{1, 2", Err(KaramelError {
+        error_type: KaramelErrorType::SetNotClosed,
+        column: 5,
+        line: 0
+    }));
+
     test_success!(empty_1, "yok", Ok(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Empty)))));
 
     test_success!(symbol_1, "data", Ok(Rc::new(KaramelAstType::Symbol("data".to_string()))));
@@ -130,15 +151,21 @@ mod tests {
     test_success!(parenthesis_1, "(10*10)", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))), 
         operator: KaramelOperatorType::Multiplication, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+        line: 0,
+        column: 0
     })));
     test_success!(parenthesis_2, "(10+10)-10", Ok(Rc::new(KaramelAstType::Binary {
         left: Rc::new(KaramelAstType::Binary {
             left: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))), 
             operator: KaramelOperatorType::Addition, 
-            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0))))
+            right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+            line: 0,
+            column: 0
         }), 
         operator: KaramelOperatorType::Subtraction, 
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(10.0)))),
+        line: 0,
+        column: 0
     })));
 }
\ No newline at end of file
@@ -103,7 +103,36 @@ mod tests {
                 assign_to_temp: Cell::new(false)
             }),
         operator: KaramelOperatorType::Subtraction,
-        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))
+        right: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0)))),
+        line: 0,
+        column: 0
     })));
+    test_compare!(slice_1, "data[1:4]", Ok(Rc::new(KaramelAstType::Slice {
+        body: Rc::new(KaramelAstType::Symbol("data".to_string())),
+        start: Some(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(1.0))))),
+        end: Some(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(4.0))))),
+        step: None
+    })));
+    test_compare!(slice_2, "data[:3]", Ok(Rc::new(KaramelAstType::Slice {
+        body: Rc::new(KaramelAstType::Symbol("data".to_string())),
+        start: None,
+        end: Some(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(3.0))))),
+        step: None
+    })));
+    test_compare!(slice_3, "data[::-1]", Ok(Rc::new(KaramelAstType::Slice {
+        body: Rc::new(KaramelAstType::Symbol("data".to_string())),
+        start: None,
+        end: None,
+        step: Some(Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(-1.0)))))
+    })));
+    test_compare!(slice_4, "data[-1]", Ok(Rc::new(KaramelAstType::Indexer {
+        body: Rc::new(KaramelAstType::Symbol("data".to_string())),
+        indexer: Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Number(-1.0))))
+    })));
+    test_compare!(slice_5, "data[1:2", Err(KaramelError {
+        error_type: KaramelErrorType::SliceNotValid,
+        column: 8,
+        line: 0
+    }));
     //test_compare!(unary_19, "doğru değil", Ok(Rc::new(KaramelAstType::SuffixUnary(KaramelOperatorType::Not, Rc::new(KaramelAstType::Primative(Rc::new(KaramelPrimative::Bool(true)))))));
 }
\ No newline at end of file